@@ -19,13 +19,13 @@ fn setup(
 ) {
     // chamfered_tetrahedron
     let polyhedron = p_ops::Polyhedron::tetrahedron() // D
-        .kis(None, None, None, true)
+        .kis(None, None, true)
         .normalize()
-        .bevel(None, None, None, None, true)
+        .bevel(None, None, None, true)
         .normalize()
-        .needle(None, None, None, true)
+        .needle(None, None, true)
         .normalize()
-        .gyro(None, None, true)
+        .gyro(None, None, None, true)
         .normalize()
         .finalize();
 
@@ -266,10 +266,8 @@ pub fn nsi_render(
 
     let name = polyhedron.to_nsi(
         &ctx,
-        Some(&(polyhedron.name().to_string() + "-mesh")),
-        None,
-        None,
-        None,
+        &(polyhedron.name().to_string() + "-mesh"),
+        &Default::default(),
     );
 
     nsi_material(&ctx, &name);
@@ -220,7 +220,7 @@ fn main() {
                             alter_last_op = false;
                             last_poly = poly.clone();
                             last_op_value = 0.;
-                            poly.bevel(None, None, None, None, true);
+                            poly.bevel(None, None, None, true);
                             poly.normalize();
                             last_op = 'b';
                         }
@@ -232,7 +232,7 @@ fn main() {
                                 poly.normalize();
                             } else {
                                 last_op_value = 0.5;
-                                poly.chamfer(None, true);
+                                poly.chamfer(None, None, None, true);
                                 poly.normalize();
                                 last_op = 'c';
                             }
@@ -254,7 +254,7 @@ fn main() {
                             alter_last_op = false;
                             last_poly = poly.clone();
                             last_op_value = 0.5;
-                            poly.expand(None, true);
+                            poly.expand(None, None, true);
                             poly.normalize();
                             last_op = 'e';
                         }
@@ -262,7 +262,7 @@ fn main() {
                             alter_last_op = false;
                             last_poly = poly.clone();
                             last_op_value = 0.;
-                            poly.gyro(None, None, true);
+                            poly.gyro(None, None, None, true);
                             poly.normalize();
                             last_op = 'g';
                         }
@@ -285,7 +285,7 @@ fn main() {
                             alter_last_op = false;
                             last_poly = poly.clone();
                             last_op_value = 0.5;
-                            poly.join(None, true);
+                            poly.join(None, None, true);
                             poly.normalize();
                             last_op = 'j';
                         }
@@ -293,7 +293,7 @@ fn main() {
                             alter_last_op = false;
                             last_poly = poly.clone();
                             last_op_value = 0.;
-                            poly.kis(None, None, None, true);
+                            poly.kis(None, None, true);
                             poly.normalize();
                             last_op = 'k';
                         }
@@ -302,10 +302,10 @@ fn main() {
                             last_poly = poly.clone();
                             last_op_value = 0.;
                             if modifiers.intersects(Modifiers::Shift) {
-                                poly.medial(None, None, None, None, true);
+                                poly.medial(None, None, None, true);
                                 last_op = 'M';
                             } else {
-                                poly.meta(None, None, None, None, true);
+                                poly.meta(None, None, None, true);
                                 last_op = 'm';
                             }
                             poly.normalize();
@@ -314,7 +314,7 @@ fn main() {
                             alter_last_op = false;
                             last_poly = poly.clone();
                             last_op_value = 0.;
-                            poly.needle(None, None, None, true);
+                            poly.needle(None, None, true);
                             poly.normalize();
                             last_op = 'n';
                         }
@@ -326,7 +326,7 @@ fn main() {
                                 poly.normalize();
                             } else {
                                 last_op_value = 0.5;
-                                poly.ortho(None, true);
+                                poly.ortho(None, None, None, None, true);
                                 poly.normalize();
                                 last_op = 'o';
                             }
@@ -341,7 +341,7 @@ fn main() {
                                 last_op = 'P';
                             } else {
                                 last_op_value = 1. / 3.;
-                                poly.propeller(None, true);
+                                poly.propeller(None, None, true);
                                 poly.normalize();
                                 last_op = 'p';
                             }
@@ -350,7 +350,7 @@ fn main() {
                             alter_last_op = false;
                             last_poly = poly.clone();
                             last_op_value = 0.5;
-                            poly.quinto(None, true);
+                            poly.quinto(None, None, true);
                             poly.normalize();
                             last_op = 'q';
                         }
@@ -386,8 +386,8 @@ fn main() {
                             } else {
                                 alter_last_op = false;
                                 last_poly = poly.clone();
-                                last_op_value = 0.;
-                                poly.truncate(None, None, None, true);
+                                last_op_value = 1. / 3.;
+                                poly.truncate(None, None, true);
                                 poly.normalize();
                                 last_op = 't';
                             }
@@ -404,7 +404,7 @@ fn main() {
                             alter_last_op = false;
                             last_poly = poly.clone();
                             last_op_value = 0.;
-                            poly.whirl(None, None, true);
+                            poly.whirl(None, None, None, true);
                             poly.normalize();
                             last_op = 'w';
                         }
@@ -414,13 +414,13 @@ fn main() {
                             last_op_value = 0.3;
                             poly.extrude(None, None, None, true);
                             poly.normalize();
-                            last_op = 'j';
+                            last_op = 'x';
                         }
                         Key::Z => {
                             alter_last_op = false;
                             last_poly = poly.clone();
                             last_op_value = 0.;
-                            poly.zip(None, None, None, true);
+                            poly.zip(None, None, true);
                             poly.normalize();
                             last_op = 'z';
                         }
@@ -507,65 +507,47 @@ fn main() {
                                 poly.ambo(Some(last_op_value), true);
                             }
                             'b' => {
-                                poly.bevel(
-                                    Some(last_op_value),
-                                    Some(last_op_value),
-                                    None,
-                                    None,
-                                    true,
-                                );
+                                poly.bevel(Some(last_op_value), Some(last_op_value), None, true);
                             }
                             'c' => {
-                                poly.chamfer(Some(last_op_value), true);
+                                poly.chamfer(Some(last_op_value), None, None, true);
                             }
                             'e' => {
-                                poly.expand(Some(last_op_value), true);
+                                poly.expand(Some(last_op_value), None, true);
                             }
                             'g' => {
-                                poly.gyro(None, Some(last_op_value), true);
+                                poly.gyro(None, Some(last_op_value), None, true);
                             }
                             'i' => {
                                 poly.inset(Some(last_op_value), None, true);
                             }
                             'j' => {
-                                poly.join(Some(last_op_value), true);
+                                poly.join(None, Some(last_op_value), true);
                             }
                             'k' => {
-                                poly.kis(Some(last_op_value), None, None, true);
+                                poly.kis(Some(last_op_value), None, true);
                             }
                             'm' => {
-                                poly.meta(
-                                    Some(last_op_value),
-                                    Some(last_op_value),
-                                    None,
-                                    None,
-                                    true,
-                                );
+                                poly.meta(Some(last_op_value), Some(last_op_value), None, true);
                             }
                             'o' => {
-                                poly.ortho(Some(last_op_value), true);
+                                poly.ortho(None, None, Some(last_op_value), None, true);
                             }
                             'p' => {
-                                poly.propeller(Some(last_op_value), true);
+                                poly.propeller(Some(last_op_value), None, true);
                             }
                             'P' => {
                                 poly = Polyhedron::prism((last_op_value * 100.) as _);
                                 poly.normalize();
                             }
                             'q' => {
-                                poly.quinto(Some(last_op_value), true);
+                                poly.quinto(None, Some(last_op_value), true);
                             }
                             'M' => {
-                                poly.medial(
-                                    Some(last_op_value),
-                                    Some(last_op_value),
-                                    None,
-                                    None,
-                                    true,
-                                );
+                                poly.medial(Some(last_op_value), Some(last_op_value), None, true);
                             }
                             'n' => {
-                                poly.needle(Some(last_op_value), None, None, true);
+                                poly.needle(Some(last_op_value), None, true);
                             }
                             's' => {
                                 poly.snub(None, Some(last_op_value), true);
@@ -574,16 +556,16 @@ fn main() {
                                 poly.spherize(Some(last_op_value), true);
                             }
                             't' => {
-                                poly.truncate(Some(last_op_value), None, None, true);
+                                poly.truncate(Some(last_op_value), None, true);
                             }
                             'w' => {
-                                poly.whirl(None, Some(last_op_value), true);
+                                poly.whirl(None, Some(last_op_value), None, true);
                             }
                             'x' => {
                                 poly.extrude(Some(last_op_value), None, None, true);
                             }
                             'z' => {
-                                poly.zip(Some(last_op_value), None, None, true);
+                                poly.zip(Some(last_op_value), None, true);
                             }
 
                             _ => (),
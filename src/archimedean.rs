@@ -0,0 +1,282 @@
+//! The [Archimedean solids](https://en.wikipedia.org/wiki/Archimedean_solid).
+//!
+//! The points are given by exact coordinates, computed at `f64` precision
+//! and rounded once, and the faces are their convex hull. All edges have
+//! unit length.
+use crate::*;
+use ultraviolet::DVec3;
+
+/// The tribonacci constant.
+const TRIBONACCI: f64 = 1.839_286_755_214_161_2;
+
+/// Returns the points made of the coordinates of `point` with the given
+/// `permutations` and the sign combinations with an even number of plus
+/// signs or, with `odd_plus`, an odd number of them.
+fn chiral_permutations(point: [f64; 3], permutations: &[[usize; 3]], odd_plus: bool) -> Vec<DVec3> {
+    (0..8)
+        .filter(|signs: &u32| odd_plus == (1 == (3 - signs.count_ones()) % 2))
+        .flat_map(|signs| {
+            let signed: Vec<f64> = (0..3)
+                .map(|i| {
+                    if 0 == signs & (1 << i) {
+                        point[i]
+                    } else {
+                        -point[i]
+                    }
+                })
+                .collect();
+            permutations.iter().map(move |permutation| {
+                DVec3::new(
+                    signed[permutation[0]],
+                    signed[permutation[1]],
+                    signed[permutation[2]],
+                )
+            })
+        })
+        .collect()
+}
+
+const EVEN_PERMUTATIONS: [[usize; 3]; 3] = [[0, 1, 2], [1, 2, 0], [2, 0, 1]];
+const ODD_PERMUTATIONS: [[usize; 3]; 3] = [[0, 2, 1], [2, 1, 0], [1, 0, 2]];
+
+/// Scales `points` so the distance of the first point to its nearest
+/// neighbor becomes one and rounds them.
+fn with_unit_edges(points: Vec<DVec3>) -> Points {
+    let edge = points[1..]
+        .iter()
+        .map(|point| (*point - points[0]).mag())
+        .fold(f64::MAX, f64::min);
+    points
+        .into_iter()
+        .map(|point| rounded_point(point.x / edge, point.y / edge, point.z / edge))
+        .collect()
+}
+
+pub(crate) fn truncated_tetrahedron_points() -> Points {
+    let s = 0.5 * f64::FRAC_1_SQRT_2();
+    signed_permutations([3.0 * s, s, s], true)
+        .into_iter()
+        // Even number of minus signs.
+        .filter(|point| 0.0 < point.x * point.y * point.z)
+        .collect()
+}
+
+fn cuboctahedron_points() -> Points {
+    signed_permutations([f64::FRAC_1_SQRT_2(), f64::FRAC_1_SQRT_2(), 0.0], true)
+}
+
+pub(crate) fn truncated_cube_points() -> Points {
+    let s = 0.5 / (f64::SQRT_2() - 1.0);
+    signed_permutations([0.5, s, s], true)
+}
+
+fn truncated_octahedron_points() -> Points {
+    signed_permutations([0.0, f64::FRAC_1_SQRT_2(), f64::SQRT_2()], true)
+}
+
+fn rhombicuboctahedron_points() -> Points {
+    signed_permutations([0.5, 0.5, 0.5 + f64::FRAC_1_SQRT_2()], true)
+}
+
+fn truncated_cuboctahedron_points() -> Points {
+    signed_permutations([0.5, 0.5 + f64::FRAC_1_SQRT_2(), 0.5 + f64::SQRT_2()], true)
+}
+
+fn snub_cube_points() -> Points {
+    let point = [1.0, 1.0 / TRIBONACCI, TRIBONACCI];
+    with_unit_edges(
+        chiral_permutations(point, &EVEN_PERMUTATIONS, true)
+            .into_iter()
+            .chain(chiral_permutations(point, &ODD_PERMUTATIONS, false))
+            .collect(),
+    )
+}
+
+pub(crate) fn icosidodecahedron_points() -> Points {
+    signed_permutations([0.0, 0.0, PHI], false)
+        .into_iter()
+        .chain(signed_permutations(
+            [0.5, 0.5 * PHI, 0.5 * PHI * PHI],
+            false,
+        ))
+        .collect()
+}
+
+pub(crate) fn truncated_dodecahedron_points() -> Points {
+    let s = 0.5 * PHI;
+    signed_permutations([0.0, s / PHI, s * (2.0 + PHI)], false)
+        .into_iter()
+        .chain(signed_permutations(
+            [s / PHI, s * PHI, s * 2.0 * PHI],
+            false,
+        ))
+        .chain(signed_permutations(
+            [s * PHI, s * 2.0, s * (PHI + 1.0)],
+            false,
+        ))
+        .collect()
+}
+
+fn truncated_icosahedron_points() -> Points {
+    signed_permutations([0.0, 0.5, 1.5 * PHI], false)
+        .into_iter()
+        .chain(signed_permutations([0.5, 1.0 + 0.5 * PHI, PHI], false))
+        .chain(signed_permutations(
+            [0.5 * PHI, 1.0, 0.5 * PHI.powi(3)],
+            false,
+        ))
+        .collect()
+}
+
+pub(crate) fn rhombicosidodecahedron_points() -> Points {
+    signed_permutations([0.5, 0.5, 0.5 * PHI.powi(3)], false)
+        .into_iter()
+        .chain(signed_permutations(
+            [0.5 * PHI * PHI, 0.5 * PHI, PHI],
+            false,
+        ))
+        .chain(signed_permutations(
+            [0.5 * (2.0 + PHI), 0.0, 0.5 * PHI * PHI],
+            false,
+        ))
+        .collect()
+}
+
+fn truncated_icosidodecahedron_points() -> Points {
+    let s = 0.5 * PHI;
+    signed_permutations([s / PHI, s / PHI, s * (3.0 + PHI)], false)
+        .into_iter()
+        .chain(signed_permutations(
+            [s * 2.0 / PHI, s * PHI, s * (1.0 + 2.0 * PHI)],
+            false,
+        ))
+        .chain(signed_permutations(
+            [s / PHI, s * PHI * PHI, s * (3.0 * PHI - 1.0)],
+            false,
+        ))
+        .chain(signed_permutations(
+            [s * (2.0 * PHI - 1.0), s * 2.0, s * (2.0 + PHI)],
+            false,
+        ))
+        .chain(signed_permutations(
+            [s * PHI, s * 3.0, s * 2.0 * PHI],
+            false,
+        ))
+        .collect()
+}
+
+fn snub_dodecahedron_points() -> Points {
+    // The real root of ξ³ - 2ξ = φ.
+    let xi: f64 = 1.715_561_499_697_367_8;
+    let a = xi - 1.0 / xi;
+    let b = xi * PHI + PHI * PHI + PHI / xi;
+
+    with_unit_edges(
+        [
+            [2.0 * a, 2.0, 2.0 * b],
+            [
+                a + b / PHI + PHI,
+                -a * PHI + b + 1.0 / PHI,
+                a / PHI + b * PHI - 1.0,
+            ],
+            [
+                a + b / PHI - PHI,
+                a * PHI - b + 1.0 / PHI,
+                a / PHI + b * PHI + 1.0,
+            ],
+            [
+                -a / PHI + b * PHI + 1.0,
+                -a + b / PHI - PHI,
+                a * PHI + b - 1.0 / PHI,
+            ],
+            [
+                -a / PHI + b * PHI - 1.0,
+                a - b / PHI - PHI,
+                a * PHI + b + 1.0 / PHI,
+            ],
+        ]
+        .iter()
+        .flat_map(|point| chiral_permutations(*point, &EVEN_PERMUTATIONS, false))
+        .collect(),
+    )
+}
+
+impl Polyhedron {
+    /// Creates a [truncated tetrahedron](https://en.wikipedia.org/wiki/Truncated_tetrahedron)
+    /// with unit edges.
+    pub fn truncated_tetrahedron() -> Self {
+        Self::from_hull("tT", truncated_tetrahedron_points())
+    }
+
+    /// Creates a [cuboctahedron](https://en.wikipedia.org/wiki/Cuboctahedron)
+    /// with unit edges.
+    pub fn cuboctahedron() -> Self {
+        Self::from_hull("aC", cuboctahedron_points())
+    }
+
+    /// Creates a [truncated cube](https://en.wikipedia.org/wiki/Truncated_cube)
+    /// with unit edges.
+    pub fn truncated_cube() -> Self {
+        Self::from_hull("tC", truncated_cube_points())
+    }
+
+    /// Creates a [truncated octahedron](https://en.wikipedia.org/wiki/Truncated_octahedron)
+    /// with unit edges.
+    pub fn truncated_octahedron() -> Self {
+        Self::from_hull("tO", truncated_octahedron_points())
+    }
+
+    /// Creates a [rhombicuboctahedron](https://en.wikipedia.org/wiki/Rhombicuboctahedron)
+    /// with unit edges.
+    pub fn rhombicuboctahedron() -> Self {
+        Self::from_hull("eC", rhombicuboctahedron_points())
+    }
+
+    /// Creates a [truncated cuboctahedron](https://en.wikipedia.org/wiki/Truncated_cuboctahedron)
+    /// with unit edges.
+    pub fn truncated_cuboctahedron() -> Self {
+        Self::from_hull("bC", truncated_cuboctahedron_points())
+    }
+
+    /// Creates a [snub cube](https://en.wikipedia.org/wiki/Snub_cube) with
+    /// unit edges.
+    pub fn snub_cube() -> Self {
+        Self::from_hull("sC", snub_cube_points())
+    }
+
+    /// Creates an [icosidodecahedron](https://en.wikipedia.org/wiki/Icosidodecahedron)
+    /// with unit edges.
+    pub fn icosidodecahedron() -> Self {
+        Self::from_hull("aD", icosidodecahedron_points())
+    }
+
+    /// Creates a [truncated dodecahedron](https://en.wikipedia.org/wiki/Truncated_dodecahedron)
+    /// with unit edges.
+    pub fn truncated_dodecahedron() -> Self {
+        Self::from_hull("tD", truncated_dodecahedron_points())
+    }
+
+    /// Creates a [truncated icosahedron](https://en.wikipedia.org/wiki/Truncated_icosahedron)
+    /// with unit edges.
+    pub fn truncated_icosahedron() -> Self {
+        Self::from_hull("tI", truncated_icosahedron_points())
+    }
+
+    /// Creates a [rhombicosidodecahedron](https://en.wikipedia.org/wiki/Rhombicosidodecahedron)
+    /// with unit edges.
+    pub fn rhombicosidodecahedron() -> Self {
+        Self::from_hull("eD", rhombicosidodecahedron_points())
+    }
+
+    /// Creates a [truncated icosidodecahedron](https://en.wikipedia.org/wiki/Truncated_icosidodecahedron)
+    /// with unit edges.
+    pub fn truncated_icosidodecahedron() -> Self {
+        Self::from_hull("bD", truncated_icosidodecahedron_points())
+    }
+
+    /// Creates a [snub dodecahedron](https://en.wikipedia.org/wiki/Snub_dodecahedron)
+    /// with unit edges.
+    pub fn snub_dodecahedron() -> Self {
+        Self::from_hull("sD", snub_dodecahedron_points())
+    }
+}
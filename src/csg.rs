@@ -0,0 +1,396 @@
+//! Constructive solid geometry using binary space partitioning trees.
+//!
+//! This follows the approach of Evan Wallace's
+//! [csg.js](https://github.com/evanw/csg.js). Each polyhedron is turned into a
+//! BSP tree and the trees are clipped against each other.
+//!
+//! Polygons are kept with counter-clockwise winding internally and
+//! converted from/to the clockwise winding of [`Polyhedron`] at the
+//! boundaries of this module.
+use crate::*;
+
+const CSG_EPSILON: Float = 1e-5;
+
+#[derive(Clone, Copy, Debug)]
+struct Plane {
+    normal: Normal,
+    w: Float,
+}
+
+impl Plane {
+    fn from_points(points: &PointsSlice) -> Self {
+        let point_refs: Vec<&Point> = points.iter().collect();
+        let normal = newell_normal(&point_refs).normalized();
+        Self {
+            normal,
+            w: normal.dot(centroid(points)),
+        }
+    }
+
+    fn flip(&mut self) {
+        self.normal = -self.normal;
+        self.w = -self.w;
+    }
+
+    fn distance(&self, point: &Point) -> Float {
+        self.normal.dot(*point) - self.w
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Polygon {
+    points: Points,
+    plane: Plane,
+    /// The index of the solid the polygon came from.
+    source: usize,
+}
+
+impl Polygon {
+    fn new(points: Points, source: usize) -> Self {
+        Self {
+            plane: Plane::from_points(&points),
+            points,
+            source,
+        }
+    }
+
+    fn flip(&mut self) {
+        self.points.reverse();
+        self.plane.flip();
+    }
+}
+
+/// Where a polygon ends up relative to a plane.
+enum Split {
+    CoplanarFront(Polygon),
+    CoplanarBack(Polygon),
+    Parts(Option<Polygon>, Option<Polygon>),
+}
+
+const COPLANAR: u8 = 0;
+const FRONT: u8 = 1;
+const BACK: u8 = 2;
+const SPANNING: u8 = 3;
+
+fn split_polygon(plane: &Plane, polygon: Polygon) -> Split {
+    let types: Vec<u8> = polygon
+        .points
+        .iter()
+        .map(|point| {
+            let distance = plane.distance(point);
+            if distance < -CSG_EPSILON {
+                BACK
+            } else if CSG_EPSILON < distance {
+                FRONT
+            } else {
+                COPLANAR
+            }
+        })
+        .collect();
+
+    match types
+        .iter()
+        .fold(COPLANAR, |polygon_type, t| polygon_type | t)
+    {
+        COPLANAR => {
+            if 0.0 < plane.normal.dot(polygon.plane.normal) {
+                Split::CoplanarFront(polygon)
+            } else {
+                Split::CoplanarBack(polygon)
+            }
+        }
+        FRONT => Split::Parts(Some(polygon), None),
+        BACK => Split::Parts(None, Some(polygon)),
+        _ => {
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            let len = polygon.points.len();
+            for i in 0..len {
+                let j = (i + 1) % len;
+                let (ti, tj) = (types[i], types[j]);
+                let (vi, vj) = (polygon.points[i], polygon.points[j]);
+                if BACK != ti {
+                    front.push(vi);
+                }
+                if FRONT != ti {
+                    back.push(vi);
+                }
+                if SPANNING == ti | tj {
+                    let t = (plane.w - plane.normal.dot(vi)) / plane.normal.dot(vj - vi);
+                    let v = vi + t * (vj - vi);
+                    front.push(v);
+                    back.push(v);
+                }
+            }
+            // The split parts keep the plane of the original polygon.
+            let part = |points: Points| {
+                if 3 <= points.len() {
+                    Some(Polygon {
+                        points,
+                        plane: polygon.plane,
+                        source: polygon.source,
+                    })
+                } else {
+                    None
+                }
+            };
+            Split::Parts(part(front), part(back))
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    plane: Option<Plane>,
+    front: Option<Box<Node>>,
+    back: Option<Box<Node>>,
+    polygons: Vec<Polygon>,
+}
+
+impl Node {
+    fn new(polygons: Vec<Polygon>) -> Self {
+        let mut node = Self::default();
+        node.build(polygons);
+        node
+    }
+
+    /// Converts solid space to empty space and vice versa.
+    fn invert(&mut self) {
+        self.polygons.iter_mut().for_each(|polygon| polygon.flip());
+        if let Some(plane) = &mut self.plane {
+            plane.flip();
+        }
+        if let Some(front) = &mut self.front {
+            front.invert();
+        }
+        if let Some(back) = &mut self.back {
+            back.invert();
+        }
+        std::mem::swap(&mut self.front, &mut self.back);
+    }
+
+    /// Removes all parts of `polygons` that are inside this tree.
+    fn clip_polygons(&self, polygons: Vec<Polygon>) -> Vec<Polygon> {
+        let plane = match &self.plane {
+            Some(plane) => plane,
+            None => return polygons,
+        };
+
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        for polygon in polygons {
+            match split_polygon(plane, polygon) {
+                Split::CoplanarFront(polygon) => front.push(polygon),
+                Split::CoplanarBack(polygon) => back.push(polygon),
+                Split::Parts(front_part, back_part) => {
+                    front.extend(front_part);
+                    back.extend(back_part);
+                }
+            }
+        }
+
+        let mut front = match &self.front {
+            Some(node) => node.clip_polygons(front),
+            None => front,
+        };
+        let back = match &self.back {
+            Some(node) => node.clip_polygons(back),
+            None => Vec::new(),
+        };
+        front.extend(back);
+        front
+    }
+
+    /// Removes all parts of the polygons in this tree that are inside
+    /// `other`.
+    fn clip_to(&mut self, other: &Node) {
+        self.polygons = other.clip_polygons(std::mem::take(&mut self.polygons));
+        if let Some(front) = &mut self.front {
+            front.clip_to(other);
+        }
+        if let Some(back) = &mut self.back {
+            back.clip_to(other);
+        }
+    }
+
+    fn all_polygons(&self) -> Vec<Polygon> {
+        let mut polygons = self.polygons.clone();
+        if let Some(front) = &self.front {
+            polygons.extend(front.all_polygons());
+        }
+        if let Some(back) = &self.back {
+            polygons.extend(back.all_polygons());
+        }
+        polygons
+    }
+
+    fn build(&mut self, polygons: Vec<Polygon>) {
+        if polygons.is_empty() {
+            return;
+        }
+        let plane = *self.plane.get_or_insert(polygons[0].plane);
+
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        for polygon in polygons {
+            match split_polygon(&plane, polygon) {
+                Split::CoplanarFront(polygon) | Split::CoplanarBack(polygon) => {
+                    self.polygons.push(polygon)
+                }
+                Split::Parts(front_part, back_part) => {
+                    front.extend(front_part);
+                    back.extend(back_part);
+                }
+            }
+        }
+
+        if !front.is_empty() {
+            self.front
+                .get_or_insert_with(|| Box::new(Node::default()))
+                .build(front);
+        }
+        if !back.is_empty() {
+            self.back
+                .get_or_insert_with(|| Box::new(Node::default()))
+                .build(back);
+        }
+    }
+}
+
+/// The boolean operations supported by [`boolean()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Operation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+fn to_polygons(polyhedron: &Polyhedron, source: usize) -> Vec<Polygon> {
+    polyhedron
+        .faces()
+        .iter()
+        .map(|face| {
+            Polygon::new(
+                face.iter()
+                    .rev()
+                    .map(|v| polyhedron.points()[*v as usize])
+                    .collect(),
+                source,
+            )
+        })
+        .collect()
+}
+
+/// Returns the faces, points and face sets of the boolean combination of
+/// `a` and `b`. Faces are put into two face sets, by the solid they came
+/// from.
+///
+/// Points lying on the edges of a face are inserted into it, so the result
+/// has no T-junctions.
+pub(crate) fn boolean(
+    a: &Polyhedron,
+    b: &Polyhedron,
+    operation: Operation,
+) -> (Faces, Points, Vec<FaceSet>) {
+    let mut a = Node::new(to_polygons(a, 0));
+    let mut b = Node::new(to_polygons(b, 1));
+
+    match operation {
+        Operation::Union => {
+            a.clip_to(&b);
+            b.clip_to(&a);
+            b.invert();
+            b.clip_to(&a);
+            b.invert();
+            a.build(b.all_polygons());
+        }
+        Operation::Intersection => {
+            a.invert();
+            b.clip_to(&a);
+            b.invert();
+            a.clip_to(&b);
+            b.clip_to(&a);
+            a.build(b.all_polygons());
+            a.invert();
+        }
+        Operation::Difference => {
+            a.invert();
+            a.clip_to(&b);
+            b.clip_to(&a);
+            b.invert();
+            b.clip_to(&a);
+            b.invert();
+            a.build(b.all_polygons());
+            a.invert();
+        }
+    }
+
+    // Weld points that ended up in the same place.
+    let mut points = Points::new();
+    let mut face_index = Faces::new();
+    let mut face_set_index = vec![FaceSet::new(), FaceSet::new()];
+
+    for polygon in a.all_polygons() {
+        let mut face: Face = polygon
+            .points
+            .iter()
+            .rev()
+            .map(|point| {
+                match points
+                    .iter()
+                    .position(|other| (*other - *point).mag() < 10.0 * CSG_EPSILON)
+                {
+                    Some(v) => v as VertexKey,
+                    None => {
+                        points.push(*point);
+                        (points.len() - 1) as VertexKey
+                    }
+                }
+            })
+            .dedup()
+            .collect();
+        while 1 < face.len() && face.first() == face.last() {
+            face.pop();
+        }
+
+        if 3 <= face.len() {
+            face_set_index[polygon.source].push(face_index.len() as VertexKey);
+            face_index.push(face);
+        }
+    }
+
+    // Faces cut on one side of an edge only leave T-junctions. Insert
+    // points lying on an edge into it so the mesh is closed again.
+    let face_index = face_index
+        .par_iter()
+        .map(|face| {
+            face.iter()
+                .circular_tuple_windows::<(_, _)>()
+                .flat_map(|(a, b)| {
+                    let start = points[*a as usize];
+                    let direction = points[*b as usize] - start;
+                    let length_sq = direction.mag_sq();
+                    let mut on_edge: Vec<(Float, VertexKey)> = points
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(v, point)| {
+                            let t = (*point - start).dot(direction) / length_sq;
+                            if CSG_EPSILON < t
+                                && t < 1.0 - CSG_EPSILON
+                                && (start + t * direction - *point).mag() < CSG_EPSILON
+                            {
+                                Some((t, v as VertexKey))
+                            } else {
+                                None
+                            }
+                        })
+                        .collect();
+                    on_edge.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+                    once(*a).chain(on_edge.into_iter().map(|(_, v)| v))
+                })
+                .collect()
+        })
+        .collect();
+
+    (face_index, points, face_set_index)
+}
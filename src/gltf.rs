@@ -0,0 +1,158 @@
+//! Support for the binary [glTF 2.0](https://www.khronos.org/gltf/) file
+//! format, GLB.
+use crate::*;
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+const TRIANGLES: u32 = 4;
+
+/// Appends `bytes` to `buffer`, padded to a multiple of four with `padding`.
+fn push_padded(buffer: &mut Vec<u8>, bytes: &[u8], padding: u8) {
+    buffer.extend_from_slice(bytes);
+    buffer.resize((buffer.len() + 3) & !3, padding);
+}
+
+impl Polyhedron {
+    /// Write the polyhedron to a binary [glTF 2.0](https://www.khronos.org/gltf/)
+    /// file.
+    ///
+    /// The [`name`](Polyhedron::name()) of the polyhedron is appended
+    /// to the given `destination` and postfixed with the extension
+    /// `.glb`.
+    ///
+    /// The mesh is triangulated and has one primitive per face set, so a
+    /// material can be assigned to each. See
+    /// [`to_triangle_mesh_buffers_by_face_set()`](Polyhedron::to_triangle_mesh_buffers_by_face_set())
+    /// for how faces are assigned to primitives. The winding order is
+    /// reversed to match glTF’s counter-clockwise front faces.
+    ///
+    /// The return value, on success, is the final, complete path of
+    /// the GLB file.
+    pub fn write_to_gltf(
+        &self,
+        destination: &Path,
+        normal_type: NormalType,
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let (index, points, normals) = self.to_triangle_mesh_buffers_by_face_set(normal_type);
+
+        let mut buffer = Vec::new();
+        let mut buffer_views = Vec::new();
+        let mut accessors = Vec::new();
+
+        // Positions and normals.
+        let (min, max) = points.iter().fold(
+            (Point::broadcast(Float::MAX), Point::broadcast(Float::MIN)),
+            |(min, max), point| (min.min_by_component(*point), max.max_by_component(*point)),
+        );
+        for (vectors, bounds) in [
+            (
+                &points,
+                format!(
+                    r#","min":[{},{},{}],"max":[{},{},{}]"#,
+                    min.x, min.y, min.z, max.x, max.y, max.z
+                ),
+            ),
+            (&normals, String::new()),
+        ]
+        .iter()
+        {
+            let bytes: Vec<u8> = vectors
+                .iter()
+                .flat_map(|v| [v.x, v.y, v.z])
+                .flat_map(|f| f.to_le_bytes())
+                .collect();
+            buffer_views.push(format!(
+                r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{}}}"#,
+                buffer.len(),
+                bytes.len(),
+                ARRAY_BUFFER
+            ));
+            accessors.push(format!(
+                r#"{{"bufferView":{},"componentType":{},"count":{},"type":"VEC3"{}}}"#,
+                buffer_views.len() - 1,
+                FLOAT,
+                vectors.len(),
+                bounds
+            ));
+            push_padded(&mut buffer, &bytes, 0);
+        }
+
+        // One index buffer per face set.
+        let mut primitives = Vec::new();
+        for face_set_index in index.iter().filter(|index| !index.is_empty()) {
+            let bytes: Vec<u8> = face_set_index
+                .chunks(3)
+                .flat_map(|triangle| [triangle[0], triangle[2], triangle[1]])
+                .flat_map(|i| i.to_le_bytes())
+                .collect();
+            buffer_views.push(format!(
+                r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{}}}"#,
+                buffer.len(),
+                bytes.len(),
+                ELEMENT_ARRAY_BUFFER
+            ));
+            accessors.push(format!(
+                r#"{{"bufferView":{},"componentType":{},"count":{},"type":"SCALAR"}}"#,
+                buffer_views.len() - 1,
+                UNSIGNED_INT,
+                face_set_index.len()
+            ));
+            primitives.push(format!(
+                r#"{{"attributes":{{"POSITION":0,"NORMAL":1}},"indices":{},"mode":{}}}"#,
+                accessors.len() - 1,
+                TRIANGLES
+            ));
+            push_padded(&mut buffer, &bytes, 0);
+        }
+
+        let name = json_escape(&self.name);
+        let json = format!(
+            concat!(
+                r#"{{"asset":{{"version":"2.0","generator":"polyhedron-ops"}},"#,
+                r#""scene":0,"scenes":[{{"nodes":[0]}}],"#,
+                r#""nodes":[{{"mesh":0,"name":"{}"}}],"#,
+                r#""meshes":[{{"name":"{}","primitives":[{}]}}],"#,
+                r#""buffers":[{{"byteLength":{}}}],"#,
+                r#""bufferViews":[{}],"#,
+                r#""accessors":[{}]}}"#
+            ),
+            name,
+            name,
+            primitives.join(","),
+            buffer.len(),
+            buffer_views.join(","),
+            accessors.join(",")
+        );
+        let mut json_chunk = Vec::new();
+        push_padded(&mut json_chunk, json.as_bytes(), b' ');
+
+        let path = destination.join(format!("polyhedron-{}.glb", self.name));
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        // Header.
+        file.write_all(b"glTF")?;
+        file.write_all(&2u32.to_le_bytes())?;
+        file.write_all(
+            &(12 + 8 + json_chunk.len() as u32 + 8 + buffer.len() as u32).to_le_bytes(),
+        )?;
+        // Chunks.
+        file.write_all(&(json_chunk.len() as u32).to_le_bytes())?;
+        file.write_all(b"JSON")?;
+        file.write_all(&json_chunk)?;
+        file.write_all(&(buffer.len() as u32).to_le_bytes())?;
+        file.write_all(b"BIN\0")?;
+        file.write_all(&buffer)?;
+
+        file.flush()?;
+
+        Ok(path)
+    }
+}
@@ -0,0 +1,41 @@
+//! Conversion of polyhedra to [petgraph](https://crates.io/crates/petgraph)
+//! graphs.
+use crate::*;
+use petgraph::graph::UnGraph;
+
+impl Polyhedron {
+    /// Returns the vertices and edges of the polyhedron as an undirected
+    /// graph.
+    ///
+    /// The weight of each node is the index of its vertex, which is also
+    /// its node index.
+    pub fn to_graph(&self) -> UnGraph<VertexKey, ()> {
+        let mut graph = UnGraph::with_capacity(self.points.len(), 0);
+        (0..self.points.len() as VertexKey).for_each(|vertex| {
+            graph.add_node(vertex);
+        });
+        graph.extend_with_edges(self.to_edges().iter().map(|edge| (edge[0], edge[1])));
+        graph
+    }
+
+    /// Returns the faces of the polyhedron as an undirected graph with an
+    /// edge between every two faces that share an edge of the polyhedron.
+    ///
+    /// This is the skeleton of the [`dual()`](Polyhedron::dual()).
+    ///
+    /// The weight of each node is the index of its face, which is also its
+    /// node index.
+    pub fn to_face_graph(&self) -> UnGraph<usize, ()> {
+        let mut graph = UnGraph::with_capacity(self.face_index.len(), 0);
+        (0..self.face_index.len()).for_each(|face| {
+            graph.add_node(face);
+        });
+        graph.extend_with_edges(
+            self.edge_faces()
+                .values()
+                .filter(|faces| 2 == faces.len())
+                .map(|faces| (faces[0] as u32, faces[1] as u32)),
+        );
+        graph
+    }
+}
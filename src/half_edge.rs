@@ -0,0 +1,62 @@
+//! Conversion of polyhedra to and from the half-edge meshes of the
+//! [`tri-mesh`](https://crates.io/crates/tri-mesh) crate.
+use crate::*;
+use std::convert::TryFrom;
+use tri_mesh::{
+    mesh_builder::Error,
+    prelude::{Mesh, MeshBuilder},
+};
+
+/// Builds a half-edge mesh from the polyhedron.
+///
+/// Faces are triangulated as fans and made counter-clockwise, as `tri-mesh`
+/// expects. Building fails for polyhedra that are not manifold.
+impl TryFrom<&Polyhedron> for Mesh {
+    type Error = Error;
+
+    fn try_from(polyhedron: &Polyhedron) -> Result<Self, Self::Error> {
+        MeshBuilder::new()
+            .with_positions(
+                polyhedron
+                    .points
+                    .iter()
+                    .flat_map(|point| vec![point.x as f64, point.y as f64, point.z as f64])
+                    .collect(),
+            )
+            .with_indices(
+                polyhedron
+                    .face_index
+                    .iter()
+                    .flat_map(|face| {
+                        (1..face.len() - 1).flat_map(move |i| vec![face[0], face[i + 1], face[i]])
+                    })
+                    .collect(),
+            )
+            .build()
+    }
+}
+
+/// Converts a half-edge mesh into a polyhedron with all faces in one face
+/// set.
+///
+/// Faces are made clockwise.
+impl From<&Mesh> for Polyhedron {
+    fn from(mesh: &Mesh) -> Self {
+        let face_index: Faces = mesh
+            .indices_buffer()
+            .chunks_exact(3)
+            .map(|triangle| vec![triangle[0], triangle[2], triangle[1]])
+            .collect();
+        let face_set = (0..face_index.len() as VertexKey).collect();
+
+        Polyhedron::from(
+            "",
+            mesh.positions_buffer()
+                .chunks_exact(3)
+                .map(|point| Point::new(point[0] as _, point[1] as _, point[2] as _))
+                .collect(),
+            face_index,
+            Some(vec![face_set]),
+        )
+    }
+}
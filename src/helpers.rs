@@ -1,6 +1,16 @@
 use crate::*;
 use ultraviolet::DVec3;
 
+/// The golden ratio.
+pub(crate) const PHI: f64 = 1.618_033_988_749_895;
+
+/// Returns the point with the given coordinates, computed at `f64`
+/// precision, rounded to [`Float`].
+#[inline]
+pub(crate) fn rounded_point(x: f64, y: f64, z: f64) -> Point {
+    Point::new(x as _, y as _, z as _)
+}
+
 // Extend a vector with some element(s)
 // ```
 // extend![..foo, 4, 5, 6]
@@ -41,6 +51,17 @@ pub(crate) fn _to_vadd(points: &PointsSlice, v: &Vector) -> Points {
     points.par_iter().map(|p| *p + *v).collect()
 }
 
+/// Returns the texture coordinates of a point projected onto a sphere
+/// around the origin.
+#[inline]
+pub(crate) fn spherical_uv(point: &Point) -> [Float; 2] {
+    let direction = point.normalized();
+    [
+        0.5 + direction.z.atan2(direction.x) / Float::TAU(),
+        0.5 + direction.y.asin() / Float::PI(),
+    ]
+}
+
 #[inline]
 pub(crate) fn vadd(points: &mut Points, v: &Vector) {
     points.par_iter_mut().for_each(|p| *p += *v);
@@ -98,58 +119,78 @@ pub(crate) fn vertex_edges(v: VertexKey, edges: &EdgesSlice) -> Edges {
         .collect()
 }
 
+/// Returns the face of `vfaces` the walk around `v` starts at. For
+/// vertices on the boundary of an open mesh this is the face after the
+/// boundary, so the walk doesn't stop early.
 #[inline]
-pub(crate) fn ordered_vertex_edges_recurse(
-    v: u32,
-    vfaces: &FacesSlice,
-    face: &FaceSlice,
-    k: usize,
-) -> Edges {
-    if k < vfaces.len() {
-        let i = index_of(&v, face).unwrap();
-        let j = (i + face.len() - 1) % face.len();
-        let edge = [v, face[j]];
-        let nface = face_with_edge(&edge, vfaces);
-        let mut result = vec![edge];
-        result.extend(ordered_vertex_edges_recurse(v, vfaces, &nface, k + 1));
-        result
-    } else {
-        vec![]
-    }
+fn first_vertex_face(v: VertexKey, vfaces: &FacesSlice) -> usize {
+    vfaces
+        .iter()
+        .position(|face| {
+            let i = index_of(&v, face).unwrap();
+            face_with_edge(&[face[(i + 1) % face.len()], v], vfaces).is_empty()
+        })
+        .unwrap_or(0)
 }
 
+/// Returns the edges around `v`, given the faces containing `v`, in the
+/// order the faces are walked by [`ordered_vertex_faces()`]. For vertices
+/// on the boundary of an open mesh both boundary edges are included.
 #[inline]
-pub(crate) fn ordered_vertex_edges(v: u32, vfaces: &FacesSlice) -> Edges {
+pub(crate) fn ordered_vertex_edges(v: VertexKey, vfaces: &FacesSlice) -> Edges {
     if vfaces.is_empty() {
         vec![]
     } else {
-        let face = &vfaces[0];
-        let i = index_of(&v, face).unwrap();
-        let j = (i + face.len() - 1) % face.len();
-        let edge = [v, face[j]];
-        let nface = face_with_edge(&edge, vfaces);
-        let mut result = vec![edge];
-        result.extend(ordered_vertex_edges_recurse(v, vfaces, &nface, 1));
+        let start = first_vertex_face(v, vfaces);
+        let mut face = vfaces[start].clone();
+        let mut result = Vec::with_capacity(vfaces.len() + 1);
+
+        let i = index_of(&v, &face).unwrap();
+        let start_edge = [v, face[(i + 1) % face.len()]];
+        if face_with_edge(&[start_edge[1], v], vfaces).is_empty() {
+            result.push(start_edge);
+        }
+
+        for _ in 0..vfaces.len() {
+            let i = index_of(&v, &face).unwrap();
+            let edge = [v, face[(i + face.len() - 1) % face.len()]];
+            result.push(edge);
+            face = face_with_edge(&edge, vfaces);
+            if face.is_empty() {
+                break;
+            }
+        }
         result
     }
 }
 
 #[inline]
 pub(crate) fn points_to_faces(points: &PointsSlice, face_index: &FacesSlice) -> Faces {
-    points
+    // The indices of the faces each vertex is in.
+    let mut face_indices = vec![Vec::new(); points.len()];
+    face_index.iter().enumerate().for_each(|(index, face)| {
+        face.iter()
+            .for_each(|v| face_indices[*v as usize].push(index as VertexKey))
+    });
+
+    face_indices
         .par_iter()
         .enumerate()
-        .map(|vertex| {
+        .map(|(vertex, face_indices)| {
+            if face_indices.is_empty() {
+                return Face::new();
+            }
+            let faces: Faces = face_indices
+                .iter()
+                .map(|index| face_index[*index as usize].clone())
+                .collect();
             // Each old vertex creates a new face.
-            ordered_vertex_faces(
-                vertex.0 as VertexKey,
-                &vertex_faces(vertex.0 as VertexKey, face_index),
-            )
-            .iter()
-            .map(|original_face|
-                // With vertex faces in left-hand order.
-                index_of(original_face, face_index).unwrap() as VertexKey)
-            .collect()
+            ordered_vertex_faces(vertex as VertexKey, &faces)
+                .iter()
+                .map(|original_face|
+                    // With vertex faces in left-hand order.
+                    face_indices[index_of(original_face, &faces).unwrap()])
+                .collect()
         })
         .collect()
 }
@@ -202,9 +243,7 @@ pub(crate) fn _average_magnitude(points: &PointsSlice) -> Float {
 
 #[inline]
 pub(crate) fn max_magnitude(points: &PointsSlice) -> Float {
-    vnorm(points)
-        .into_par_iter()
-        .reduce(|| Float::NAN, Float::max)
+    vnorm(points).into_iter().fold(Float::NAN, Float::max)
 }
 
 /// Returns a [`Faces`] of faces
@@ -247,45 +286,45 @@ pub(crate) fn face_with_edge(edge: &Edge, faces: &FacesSlice) -> Face {
     result
 }
 
+/// Returns `true` if every edge is shared by two faces, i.e. the mesh has
+/// no boundary.
+pub(crate) fn is_closed(face_index: &FacesSlice) -> bool {
+    let edges: HashSet<Edge> = face_index
+        .iter()
+        .flat_map(|face| {
+            face.iter()
+                .circular_tuple_windows::<(_, _)>()
+                .map(|(a, b)| [*a, *b])
+        })
+        .collect();
+    edges.iter().all(|edge| edges.contains(&[edge[1], edge[0]]))
+}
+
 #[inline]
 pub(crate) fn index_of<T: PartialEq>(element: &T, list: &[T]) -> Option<usize> {
     list.iter().position(|e| *e == *element)
 }
 
-/// Used internally by [`ordered_vertex_faces()`].
-#[inline]
-pub(crate) fn ordered_vertex_faces_recurse(
-    v: VertexKey,
-    face_index: &FacesSlice,
-    cface: &FaceSlice,
-    k: VertexKey,
-) -> Faces {
-    if (k as usize) < face_index.len() {
-        let i = index_of(&v, &cface).unwrap() as i32;
-        let j = ((i - 1 + cface.len() as i32) % cface.len() as i32) as usize;
-        let edge = [v, cface[j]];
-        let mut nfaces = vec![face_with_edge(&edge, face_index)];
-        nfaces.extend(ordered_vertex_faces_recurse(
-            v,
-            face_index,
-            &nfaces[0],
-            k + 1,
-        ));
-        nfaces
-    } else {
-        Faces::new()
-    }
-}
-
+/// Returns the faces containing `vertex_number` ordered by walking around
+/// the vertex. `face_index` must only contain faces with `vertex_number`.
+///
+/// For vertices on the boundary of an open mesh the walk starts and stops
+/// at the boundary.
 #[inline]
 pub(crate) fn ordered_vertex_faces(vertex_number: VertexKey, face_index: &FacesSlice) -> Faces {
-    let mut result = vec![face_index[0].clone()];
-    result.extend(ordered_vertex_faces_recurse(
-        vertex_number,
-        face_index,
-        &face_index[0],
-        1,
-    ));
+    let mut face = face_index[first_vertex_face(vertex_number, face_index)].clone();
+    let mut result = Vec::with_capacity(face_index.len());
+
+    for _ in 0..face_index.len() {
+        let i = index_of(&vertex_number, &face).unwrap();
+        let edge = [vertex_number, face[(i + face.len() - 1) % face.len()]];
+        let next_face = face_with_edge(&edge, face_index);
+        result.push(face);
+        if next_face.is_empty() {
+            break;
+        }
+        face = next_face;
+    }
 
     result
 }
@@ -298,7 +337,7 @@ pub(crate) fn edge_length(edge: &Edge, points: &PointsSlice) -> Float {
 }
 
 #[inline]
-pub(crate) fn _edge_lengths(edges: &_EdgeSlice, points: &PointsSlice) -> Vec<Float> {
+pub(crate) fn edge_lengths(edges: &EdgesSlice, points: &PointsSlice) -> Vec<Float> {
     edges
         .par_iter()
         .map(|edge| edge_length(edge, points))
@@ -328,20 +367,22 @@ pub(crate) fn max_resize(points: &mut Points, radius: Float) {
     points.par_iter_mut().for_each(|v| *v *= radius / max);
 }
 
+/// Blends points between their current position and their projection on a
+/// sphere of the given `radius`, centered at the origin.
 #[inline]
-pub(crate) fn _project_on_sphere(points: &mut Points, radius: Float) {
-    points
-        .par_iter_mut()
-        .for_each(|point| *point = radius * point.normalized());
+pub(crate) fn project_on_sphere(points: &mut PointsSlice, radius: Float, strength: Float) {
+    points.par_iter_mut().for_each(|point| {
+        *point = (1.0 - strength) * *point + strength * radius * point.normalized()
+    });
 }
 
 #[inline]
 pub(crate) fn face_irregularity(face: &FaceSlice, points: &PointsSlice) -> Float {
     let lengths = face_edges(face, points);
     // The largest value in lengths or NaN (0./0.) otherwise.
-    lengths.par_iter().cloned().reduce(|| Float::NAN, Float::max)
+    lengths.iter().cloned().fold(Float::NAN, Float::max)
         // divide by the smallest value in lengths or NaN (0./0.) otherwise.
-        / lengths.par_iter().cloned().reduce(|| Float::NAN, Float::min)
+        / lengths.iter().cloned().fold(Float::NAN, Float::min)
 }
 
 #[inline]
@@ -500,7 +541,7 @@ pub(crate) fn _vertex_ids(entries: &[(Face, Point)], offset: VertexKey) -> Vec<(
 
 #[inline]
 pub(crate) fn vertex(key: &FaceSlice, entries: &[(&FaceSlice, VertexKey)]) -> Option<VertexKey> {
-    entries.par_iter().find_first(|f| key == f.0).map(|entry| entry.1)
+    entries.iter().find(|f| key == f.0).map(|entry| entry.1)
 }
 
 #[inline]
@@ -508,12 +549,12 @@ pub(crate) fn vertex_point<'a>(
     key: &FaceSlice,
     entries: &'a [(&FaceSlice, Point)],
 ) -> Option<&'a Point> {
-    entries.par_iter().find_first(|f| key == f.0).map(|entry| &entry.1)
+    entries.iter().find(|f| key == f.0).map(|entry| &entry.1)
 }
 
 #[inline]
 pub(crate) fn vertex_edge(key: &Edge, entries: &[(&Edge, VertexKey)]) -> Option<VertexKey> {
-    entries.par_iter().find_first(|f| key == f.0).map(|entry| entry.1)
+    entries.iter().find(|f| key == f.0).map(|entry| entry.1)
 }
 
 #[inline]
@@ -521,7 +562,7 @@ pub(crate) fn vertex_edge_point<'a>(
     key: &Edge,
     entries: &'a [(&Edge, Point)],
 ) -> Option<&'a Point> {
-    entries.par_iter().find_first(|f| key == f.0).map(|entry| &entry.1)
+    entries.iter().find(|f| key == f.0).map(|entry| &entry.1)
 }
 
 #[inline]
@@ -533,14 +574,6 @@ pub(crate) fn vertex_values<T>(entries: &[(T, Point)]) -> Points {
     entries.iter().map(|e| e.1).collect()
 }
 
-#[inline]
-pub(crate) fn selected_face(face: &FaceSlice, face_arity: Option<&Vec<usize>>) -> bool {
-    match face_arity {
-        None => true,
-        Some(arity) => arity.contains(&face.len()),
-    }
-}
-
 #[inline]
 pub(crate) fn _distinct_edges(faces: &FacesSlice) -> Edges {
     faces
@@ -566,10 +599,278 @@ pub(crate) fn face_centers(face_index: &FacesSlice, points: &PointsSlice) -> Poi
         .collect()
 }
 
+/// The point on the line through `a` and `b` closest to the origin.
+#[inline]
+pub(crate) fn edge_tangent_point(a: &Point, b: &Point) -> Point {
+    let direction = *b - *a;
+    let mag_sq = direction.mag_sq();
+    if mag_sq < EPSILON {
+        *a
+    } else {
+        *a - (direction.dot(*a) / mag_sq) * direction
+    }
+}
+
+/// The (unnormalized) normal of a face computed with Newell's method.
+///
+/// Unlike [`face_normal()`] this is robust for non-planar and concave faces.
 #[inline]
-pub(crate) fn reciprocate_face_centers(face_index: &FacesSlice, points: &PointsSlice) -> Points {
-    face_centers(face_index, points)
+pub(crate) fn newell_normal(points: &PointRefSlice) -> Normal {
+    points
         .iter()
-        .map(|center| *center / center.mag_sq())
+        .circular_tuple_windows::<(_, _)>()
+        .fold(Normal::zero(), |normal, (a, b)| normal + a.cross(**b))
+}
+
+/// Moves each point towards the planes of the faces it belongs to.
+///
+/// Returns the largest distance of a point from the plane of one of its
+/// faces before the move.
+pub(crate) fn planarize_step(face_index: &FacesSlice, points: &mut Points) -> Float {
+    let mut adjustments = vec![Vector::zero(); points.len()];
+    let mut counts = vec![0 as Float; points.len()];
+    let mut max_distance: Float = 0.;
+
+    face_index.iter().for_each(|face| {
+        let face_points = index_as_points(face, points);
+        let normal = newell_normal(&face_points);
+        // Skip degenerate faces.
+        if EPSILON < normal.mag_sq() {
+            let normal = normal.normalized();
+            let centroid = centroid_ref(&face_points);
+            face.iter().for_each(|v| {
+                let distance = normal.dot(centroid - points[*v as usize]);
+                max_distance = max_distance.max(distance.abs());
+                adjustments[*v as usize] += distance * normal;
+                counts[*v as usize] += 1.;
+            });
+        }
+    });
+
+    points
+        .par_iter_mut()
+        .zip(adjustments.par_iter().zip(counts.par_iter()))
+        .filter(|(_, (_, count))| 0. < **count)
+        .for_each(|(point, (adjustment, count))| *point += *adjustment / *count);
+
+    max_distance
+}
+
+/// The average distance of the edge midpoints from the origin.
+///
+/// For a polyhedron with a midsphere centered at the origin this is the
+/// same as [`Polyhedron::midradius()`].
+#[inline]
+pub(crate) fn mean_edge_midpoint_distance(edges: &EdgesSlice, points: &PointsSlice) -> Float {
+    edges
+        .par_iter()
+        .map(|edge| (0.5 * (points[edge[0] as usize] + points[edge[1] as usize])).mag())
+        .sum::<Float>()
+        / edges.len() as Float
+}
+
+/// Greatest common divisor.
+#[inline]
+pub(crate) fn gcd(a: i64, b: i64) -> i64 {
+    if 0 == b {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Rotates a vector, given in coordinates of the triangular lattice, by
+/// `turns` times 60° anticlockwise.
+#[inline]
+pub(crate) fn lattice_rotate(v: &[i64; 2], turns: usize) -> [i64; 2] {
+    (0..turns % 6).fold(*v, |v, _| [-v[1], v[0] + v[1]])
+}
+
+/// A [SplitMix64](https://prng.di.unimi.it/splitmix64.c) step. Used as a
+/// stateless hash to get reproducible pseudo-random numbers.
+#[inline]
+pub(crate) fn split_mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Advances `state` and returns a pseudo-random number in [0, 1).
+#[inline]
+pub(crate) fn random_unit(state: &mut u64) -> Float {
+    *state = split_mix(*state);
+    // Top 24 bits.
+    (*state >> 40) as Float / (1u64 << 24) as Float
+}
+
+/// Returns a pseudo-random point inside the unit ball that only depends on
+/// `seed` and `index`.
+pub(crate) fn random_in_unit_ball(seed: u64, index: u64) -> Point {
+    let mut state = split_mix(seed ^ split_mix(index));
+    loop {
+        let mut coordinate = || {
+            state = split_mix(state);
+            // Top 24 bits as a number in [-1, 1).
+            (state >> 40) as Float / (1u64 << 23) as Float - 1.0
+        };
+        let point = Point::new(coordinate(), coordinate(), coordinate());
+        if point.mag_sq() <= 1.0 {
+            return point;
+        }
+    }
+}
+
+/// Returns the triangles of the convex hull of `points`, with the same
+/// clockwise winding as the rest of the crate. Points inside the hull are not
+/// referenced. Returns no faces if all points are coplanar.
+///
+/// This is a simple incremental hull; each point is checked against all
+/// current faces.
+pub(crate) fn convex_hull(points: &PointsSlice) -> Faces {
+    if points.len() < 4 {
+        return Faces::new();
+    }
+
+    let epsilon = 1e-6 * max_magnitude(points).max(1.0);
+
+    // Initial tetrahedron from extreme points.
+    let farthest = |from: &dyn Fn(&Point) -> Float| -> usize {
+        (0..points.len())
+            .max_by(|a, b| from(&points[*a]).partial_cmp(&from(&points[*b])).unwrap())
+            .unwrap()
+    };
+    let a = 0;
+    let b = farthest(&|p| (*p - points[a]).mag_sq());
+    let line = (points[b] - points[a]).normalized();
+    let c = farthest(&|p| (*p - points[a]).cross(line).mag_sq());
+    let base_normal = (points[b] - points[a])
+        .cross(points[c] - points[a])
+        .normalized();
+    let d = farthest(&|p| (*p - points[a]).dot(base_normal).abs());
+    let d_distance = (points[d] - points[a]).dot(base_normal);
+    if d_distance.abs() < epsilon || base_normal.mag_sq().is_nan() {
+        return Faces::new();
+    }
+    let (b, c) = if 0.0 < d_distance { (c, b) } else { (b, c) };
+
+    let plane = |face: &[usize; 3]| -> (Normal, Float) {
+        let normal = (points[face[1]] - points[face[0]])
+            .cross(points[face[2]] - points[face[0]])
+            .normalized();
+        (normal, normal.dot(points[face[0]]))
+    };
+
+    let mut faces: Vec<([usize; 3], Normal, Float)> = [[a, b, c], [a, d, b], [b, d, c], [c, d, a]]
+        .iter()
+        .map(|face| {
+            let (normal, offset) = plane(face);
+            (*face, normal, offset)
+        })
+        .collect();
+
+    for (index, point) in points.iter().enumerate() {
+        if [a, b, c, d].contains(&index) {
+            continue;
+        }
+
+        let (visible, hidden): (Vec<_>, Vec<_>) = faces
+            .into_iter()
+            .partition(|(_, normal, offset)| epsilon < normal.dot(*point) - offset);
+        faces = hidden;
+
+        if visible.is_empty() {
+            continue;
+        }
+
+        let visible_edges: HashSet<(usize, usize)> = visible
+            .iter()
+            .flat_map(|(face, _, _)| {
+                vec![(face[0], face[1]), (face[1], face[2]), (face[2], face[0])]
+            })
+            .collect();
+
+        // Edges on the horizon only belong to one visible face.
+        visible_edges
+            .iter()
+            .filter(|(from, to)| !visible_edges.contains(&(*to, *from)))
+            .for_each(|(from, to)| {
+                let face = [*from, *to, index];
+                let (normal, offset) = plane(&face);
+                faces.push((face, normal, offset));
+            });
+    }
+
+    faces
+        .iter()
+        .map(|(face, _, _)| face.iter().rev().map(|v| *v as VertexKey).collect())
         .collect()
 }
+
+/// Returns the points made of the coordinates of `point` with all
+/// combinations of signs and all cyclic permutations. With `odd` the odd
+/// permutations are included as well. Duplicates are removed.
+///
+/// The coordinates are given at `f64` precision and rounded once.
+pub(crate) fn signed_permutations(point: [f64; 3], odd: bool) -> Points {
+    let point = [point[0] as Float, point[1] as Float, point[2] as Float];
+    let permutations: &[[usize; 3]] = if odd {
+        &[
+            [0, 1, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [0, 2, 1],
+            [2, 1, 0],
+            [1, 0, 2],
+        ]
+    } else {
+        &[[0, 1, 2], [1, 2, 0], [2, 0, 1]]
+    };
+
+    let mut points = Points::new();
+    (0..8).for_each(|signs| {
+        let signed: Vec<Float> = (0..3)
+            .map(|i| {
+                if 0 == signs & (1 << i) {
+                    point[i]
+                } else {
+                    -point[i]
+                }
+            })
+            .collect();
+        permutations.iter().for_each(|permutation| {
+            let point = Point::new(
+                signed[permutation[0]],
+                signed[permutation[1]],
+                signed[permutation[2]],
+            );
+            if !points.contains(&point) {
+                points.push(point);
+            }
+        })
+    });
+
+    points
+}
+
+/// Escapes `text` for use in a JSON string.
+#[cfg(any(feature = "gltf", feature = "threejs"))]
+pub(crate) fn json_escape(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            c if c.is_control() => format!("\\u{:04x}", c as u32),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Escapes `text` for use in XML.
+#[cfg(any(feature = "3mf", feature = "svg", feature = "x3d"))]
+pub(crate) fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
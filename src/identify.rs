@@ -0,0 +1,187 @@
+//! Identification of the Platonic, Archimedean and Catalan solids.
+use crate::*;
+
+/// Creates a solid.
+type Solid = fn() -> Polyhedron;
+
+/// The relative difference below which measurements count as equal.
+const TOLERANCE: Float = 1e-3;
+
+/// The Platonic solids.
+const PLATONIC: [(&str, Solid); 5] = [
+    ("tetrahedron", Polyhedron::tetrahedron),
+    ("cube", Polyhedron::hexahedron),
+    ("octahedron", Polyhedron::octahedron),
+    ("dodecahedron", Polyhedron::dodecahedron),
+    ("icosahedron", Polyhedron::icosahedron),
+];
+
+/// The Archimedean solids and their duals, the Catalan solids.
+const ARCHIMEDEAN: [(&str, &str, Solid); 13] = [
+    (
+        "truncated tetrahedron",
+        "triakis tetrahedron",
+        Polyhedron::truncated_tetrahedron,
+    ),
+    (
+        "cuboctahedron",
+        "rhombic dodecahedron",
+        Polyhedron::cuboctahedron,
+    ),
+    (
+        "truncated cube",
+        "triakis octahedron",
+        Polyhedron::truncated_cube,
+    ),
+    (
+        "truncated octahedron",
+        "tetrakis hexahedron",
+        Polyhedron::truncated_octahedron,
+    ),
+    (
+        "rhombicuboctahedron",
+        "deltoidal icositetrahedron",
+        Polyhedron::rhombicuboctahedron,
+    ),
+    (
+        "truncated cuboctahedron",
+        "disdyakis dodecahedron",
+        Polyhedron::truncated_cuboctahedron,
+    ),
+    (
+        "snub cube",
+        "pentagonal icositetrahedron",
+        Polyhedron::snub_cube,
+    ),
+    (
+        "icosidodecahedron",
+        "rhombic triacontahedron",
+        Polyhedron::icosidodecahedron,
+    ),
+    (
+        "truncated dodecahedron",
+        "triakis icosahedron",
+        Polyhedron::truncated_dodecahedron,
+    ),
+    (
+        "truncated icosahedron",
+        "pentakis dodecahedron",
+        Polyhedron::truncated_icosahedron,
+    ),
+    (
+        "rhombicosidodecahedron",
+        "deltoidal hexecontahedron",
+        Polyhedron::rhombicosidodecahedron,
+    ),
+    (
+        "truncated icosidodecahedron",
+        "disdyakis triacontahedron",
+        Polyhedron::truncated_icosidodecahedron,
+    ),
+    (
+        "snub dodecahedron",
+        "pentagonal hexecontahedron",
+        Polyhedron::snub_dodecahedron,
+    ),
+];
+
+/// Returns `true` if all `values` are equal, up to the [`TOLERANCE`].
+fn all_equal(values: &[Float]) -> bool {
+    let min = values.iter().cloned().fold(Float::INFINITY, Float::min);
+    let max = values.iter().cloned().fold(0.0, Float::max);
+    max - min <= TOLERANCE * max
+}
+
+impl Polyhedron {
+    /// Returns `true` if all points are at the same distance from the
+    /// centroid and all edges have the same length.
+    fn is_uniform(&self) -> bool {
+        let center = centroid(&self.points);
+        let distances = self
+            .points
+            .iter()
+            .map(|point| (*point - center).mag())
+            .collect::<Vec<_>>();
+
+        all_equal(&distances) && all_equal(&edge_lengths(&self.to_edges(), &self.points))
+    }
+
+    /// Returns `true` if all faces are planar and at the same distance from
+    /// the centroid.
+    fn is_face_uniform(&self) -> bool {
+        let center = centroid(&self.points);
+        let distances = self
+            .face_index
+            .iter()
+            .map(|face| {
+                let face_points = index_as_points(face, &self.points);
+                let normal = newell_normal(&face_points).normalized();
+                (center - centroid_ref(&face_points)).dot(normal).abs()
+            })
+            .collect::<Vec<_>>();
+
+        all_equal(&distances)
+            && self
+                .face_planarity()
+                .iter()
+                .all(|planarity| *planarity < TOLERANCE)
+    }
+
+    /// Returns the name of the Platonic, Archimedean or Catalan solid this
+    /// polyhedron is, e.g. `"cuboctahedron"`, or `None` if it is none of
+    /// them.
+    ///
+    /// The polyhedron must have the same
+    /// [`topology_hash()`](Polyhedron::topology_hash()) as the solid.
+    /// Additionally, the points of a Platonic or Archimedean solid must be at
+    /// the same distance from their centroid and all edges must have the same
+    /// length. The faces of a Catalan solid must be planar and at the same
+    /// distance from the centroid. Position, orientation, scale and chirality
+    /// don't matter.
+    ///
+    /// This tells e.g. the rhombicuboctahedron apart from the
+    /// pseudo-rhombicuboctahedron, which has the same numbers of points of
+    /// each valence and faces of each arity.
+    ///
+    /// Catalan solids are the [`reciprocal()`](Polyhedron::reciprocal())s
+    /// of Archimedean ones. The faces of their [`dual()`](Polyhedron::dual())s
+    /// are generally not planar.
+    ///
+    /// ```
+    /// # use polyhedron_ops::Polyhedron;
+    /// let mut cube = Polyhedron::hexahedron();
+    /// cube.ambo(None, true);
+    /// assert_eq!(cube.identify(), Some("cuboctahedron"));
+    /// ```
+    pub fn identify(&self) -> Option<&'static str> {
+        let face_arities = self.face_arity_histogram();
+        let vertex_valences = self.vertex_valence_histogram();
+
+        // The histograms are cheap to compare and rule out most candidates
+        // before the topology hashes are computed.
+        PLATONIC
+            .iter()
+            .map(|(name, solid)| (*name, *solid))
+            .chain(ARCHIMEDEAN.iter().map(|(name, _, solid)| (*name, *solid)))
+            .find(|(_, solid)| {
+                let solid = solid();
+                solid.face_arity_histogram() == face_arities
+                    && solid.vertex_valence_histogram() == vertex_valences
+                    && self.is_uniform()
+                    && solid.topology_hash() == self.topology_hash()
+            })
+            .map(|(name, _)| name)
+            .or_else(|| {
+                ARCHIMEDEAN
+                    .iter()
+                    .find(|(_, _, solid)| {
+                        let mut solid = solid();
+                        solid.face_arity_histogram() == vertex_valences
+                            && solid.vertex_valence_histogram() == face_arities
+                            && self.is_face_uniform()
+                            && solid.dual(false).topology_hash() == self.topology_hash()
+                    })
+                    .map(|(_, name, _)| *name)
+            })
+    }
+}
@@ -0,0 +1,624 @@
+//! The [Johnson solids](https://en.wikipedia.org/wiki/Johnson_solid).
+//!
+//! Each solid is assembled from the points of its parts – pyramids, cupolae,
+//! rotundae, prisms and antiprisms – or from those of the Platonic or
+//! Archimedean solid it is augmented, diminished or gyrated from. The points
+//! of the solids *J84*–*J92*, which can't be built like this, come from
+//! tables. The faces are the convex hull of the points. All edges have unit
+//! length.
+use crate::{archimedean::*, *};
+
+/// Tolerance for telling points apart by their height.
+const TOLERANCE: Float = 1e-4;
+
+// The points of the solids that can't be assembled from parts of other
+// solids, solved numerically for unit edges.
+
+/// The snub disphenoid (*J84*).
+const SNUB_DISPHENOID: [[Float; 3]; 8] = [
+    [0.6445843, 0.2055616, 0.0],
+    [-0.6445843, 0.2055616, 0.0],
+    [0.0, -0.2055616, 0.6445843],
+    [0.0, -0.2055616, -0.6445843],
+    [0.5, -0.7839309, 0.0],
+    [-0.5, -0.7839309, 0.0],
+    [0.0, 0.7839309, 0.5],
+    [0.0, 0.7839309, -0.5],
+];
+
+/// The snub square antiprism (*J85*).
+const SNUB_SQUARE_ANTIPRISM: [[Float; 3]; 16] = [
+    [0.70710677, 0.6768685, 0.0],
+    [0.0, 0.6768685, 0.70710677],
+    [-0.70710677, 0.6768685, 0.0],
+    [0.0, 0.6768685, -0.70710677],
+    [1.2132055, -0.185607, 0.0],
+    [0.8578659, 0.185607, 0.8578659],
+    [0.0, -0.185607, 1.2132055],
+    [-0.8578659, 0.185607, 0.8578659],
+    [-1.2132055, -0.185607, 0.0],
+    [-0.8578659, 0.185607, -0.8578659],
+    [0.0, -0.185607, -1.2132055],
+    [0.8578659, 0.185607, -0.8578659],
+    [0.5, -0.6768685, 0.5],
+    [-0.5, -0.6768685, 0.5],
+    [-0.5, -0.6768685, -0.5],
+    [0.5, -0.6768685, -0.5],
+];
+
+/// The sphenocorona (*J86*).
+const SPHENOCORONA: [[Float; 3]; 10] = [
+    [0.0, 0.6630418, -0.5],
+    [0.0, 0.6630418, 0.5],
+    [0.8527269, 0.1406849, -0.5],
+    [0.8527269, 0.1406849, 0.5],
+    [-0.8527269, 0.1406849, -0.5],
+    [-0.8527269, 0.1406849, 0.5],
+    [0.0, -0.2941581, -0.7894276],
+    [0.0, -0.2941581, 0.7894276],
+    [0.5, -0.6502536, 0.0],
+    [-0.5, -0.6502536, 0.0],
+];
+
+/// The sphenomegacorona (*J88*).
+const SPHENOMEGACORONA: [[Float; 3]; 12] = [
+    [0.5, -0.7614657, 0.0],
+    [-0.5, -0.7614657, 0.0],
+    [0.5946333, 0.0993738, -0.5],
+    [0.5946333, 0.0993738, 0.5],
+    [-0.5946333, 0.0993738, -0.5],
+    [-0.5946333, 0.0993738, 0.5],
+    [0.0, -0.6221306, -0.8547431],
+    [0.0, -0.6221306, 0.8547431],
+    [0.0, 0.2814779, -1.2831023],
+    [0.0, 0.2814779, 1.2831023],
+    [0.0, 0.9033708, -0.5],
+    [0.0, 0.9033708, 0.5],
+];
+
+/// The hebesphenomegacorona (*J89*).
+const HEBESPHENOMEGACORONA: [[Float; 3]; 14] = [
+    [0.5, -0.9606965, 0.0],
+    [-0.5, -0.9606965, 0.0],
+    [0.7168448, -0.1222585, -0.5],
+    [0.7168448, -0.1222585, 0.5],
+    [-0.7168448, -0.1222585, -0.5],
+    [-0.7168448, -0.1222585, 0.5],
+    [0.0, -0.7333775, -0.8356591],
+    [0.0, -0.7333775, 0.8356591],
+    [0.0, 0.2306956, -1.101296],
+    [0.0, 0.2306956, 1.101296],
+    [0.5, 0.8539476, -0.5],
+    [0.5, 0.8539476, 0.5],
+    [-0.5, 0.8539476, -0.5],
+    [-0.5, 0.8539476, 0.5],
+];
+
+/// The disphenocingulum (*J90*).
+const DISPHENOCINGULUM: [[Float; 3]; 16] = [
+    [0.0, 1.104438, -0.5],
+    [0.0, 1.104438, 0.5],
+    [0.7671311, 0.4629476, -0.5],
+    [-0.7671311, 0.4629476, -0.5],
+    [-0.7671311, 0.4629476, 0.5],
+    [0.7671311, 0.4629476, 0.5],
+    [0.0, 0.325003, -1.1264831],
+    [0.0, 0.325003, 1.1264831],
+    [0.5, -1.104438, 0.0],
+    [-0.5, -1.104438, 0.0],
+    [0.5, -0.4629476, -0.7671311],
+    [-0.5, -0.4629476, -0.7671311],
+    [-0.5, -0.4629476, 0.7671311],
+    [0.5, -0.4629476, 0.7671311],
+    [1.1264831, -0.325003, 0.0],
+    [-1.1264831, -0.325003, 0.0],
+];
+
+/// The bilunabirotunda (*J91*).
+const BILUNABIROTUNDA: [[Float; 3]; 14] = [
+    [-0.809017, 0.0, 0.0],
+    [0.809017, 0.0, 0.0],
+    [0.0, -1.309017, 0.5],
+    [0.0, -1.309017, -0.5],
+    [0.0, 1.309017, 0.5],
+    [0.0, 1.309017, -0.5],
+    [-0.5, -0.5, 0.809017],
+    [-0.5, 0.5, 0.809017],
+    [-0.5, -0.5, -0.809017],
+    [-0.5, 0.5, -0.809017],
+    [0.5, -0.5, 0.809017],
+    [0.5, 0.5, 0.809017],
+    [0.5, -0.5, -0.809017],
+    [0.5, 0.5, -0.809017],
+];
+
+/// The triangular hebesphenorotunda (*J92*).
+const TRIANGULAR_HEBESPHENOROTUNDA: [[Float; 3]; 18] = [
+    [-1.139489, -0.2746361, 0.6685212],
+    [1.148701, -0.2746361, 0.6525659],
+    [-0.0092118, -0.2746361, -1.3210871],
+    [-0.5715038, -0.8519864, 0.0819554],
+    [-0.5622921, 0.082186, 1.4030425],
+    [-1.28144, -0.2746361, -0.3213525],
+    [-0.7862803, 0.6595363, 0.61787],
+    [0.3567274, -0.8519864, 0.4539591],
+    [0.3624206, -0.2746361, 1.2704359],
+    [-0.9282312, 0.6595363, -0.3720037],
+    [0.2147765, -0.8519864, -0.5359146],
+    [0.1419509, 0.6595363, 0.9898737],
+    [-0.9339244, 0.082186, -1.1884805],
+    [0.9190195, -0.2746361, -0.9490834],
+    [-0.1419509, 0.6595363, -0.9898737],
+    [1.4962165, 0.082186, -0.214562],
+    [0.9282312, 0.6595363, 0.3720037],
+    [0.7862803, 0.6595363, -0.61787],
+];
+
+/// The circumradius of a regular *n*-gon with unit edges.
+fn circumradius(n: usize) -> Float {
+    0.5 / (Float::PI() / n as Float).sin()
+}
+
+/// The inradius of a regular *n*-gon with unit edges.
+fn inradius(n: usize) -> Float {
+    0.5 / (Float::PI() / n as Float).tan()
+}
+
+/// Rotates `point` around the *y* axis.
+fn turn(point: Point, angle: Float) -> Point {
+    let (sin, cos) = angle.sin_cos();
+    Point::new(
+        point.x * cos - point.z * sin,
+        point.y,
+        point.x * sin + point.z * cos,
+    )
+}
+
+/// A regular *n*-gon with unit edges parallel to the *xz* plane at height
+/// `y`. The first point lies at `angle` around the *y* axis.
+fn ring(n: usize, angle: Float, y: Float) -> Points {
+    (0..n)
+        .map(|i| {
+            turn(
+                Point::new(circumradius(n), y, 0.0),
+                angle + i as Float * Float::TAU() / n as Float,
+            )
+        })
+        .collect()
+}
+
+/// The height of an *n*-gonal antiprism with unit edges.
+fn antiprism_height(n: usize) -> Float {
+    let offset = 2.0 * circumradius(n) * (0.5 * Float::PI() / n as Float).sin();
+    (1.0 - offset * offset).sqrt()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cap {
+    Pyramid,
+    Cupola,
+    Rotunda,
+}
+
+impl Cap {
+    /// The points of the cap sitting on `ring(n, 0.0, 0.0)`, pointing
+    /// up. The points of the ring itself are not included.
+    fn points(&self, n: usize) -> Points {
+        match self {
+            Cap::Pyramid => vec![Point::new(0.0, (1.0 - circumradius(n).powi(2)).sqrt(), 0.0)],
+            // The top edges are parallel to every other base edge.
+            Cap::Cupola => ring(
+                n / 2,
+                -Float::PI() / n as Float,
+                (1.0 - (inradius(n) - inradius(n / 2)).powi(2)).sqrt(),
+            ),
+            // The upper half of an icosidodecahedron.
+            Cap::Rotunda => {
+                let axis = Vector::new(0.0, PHI as Float, 1.0).normalized();
+                let z_axis = Vector::unit_x().cross(axis);
+                let points: Points = icosidodecahedron_points()
+                    .into_iter()
+                    .map(|point| Point::new(point.x, point.dot(axis), point.dot(z_axis)))
+                    .filter(|point| -TOLERANCE < point.y)
+                    .collect();
+                let base = points.iter().find(|point| point.y < TOLERANCE).unwrap();
+                let angle = -base.z.atan2(base.x);
+                points
+                    .iter()
+                    .filter(|point| TOLERANCE <= point.y)
+                    .map(|point| turn(*point, angle))
+                    .collect()
+            }
+        }
+    }
+
+    /// Returns the points of the cap sitting on the face made of `points`,
+    /// pointing away from `center`. With `gyro` the cap is turned by one
+    /// edge.
+    fn on_face(&self, points: &PointsSlice, center: Point, gyro: bool) -> Points {
+        let n = points.len();
+        let face_center = centroid(points);
+        let x_axis = (points[0] - face_center).normalized();
+        let point_refs: Vec<&Point> = points.iter().collect();
+        let mut y_axis = newell_normal(&point_refs).normalized();
+        if y_axis.dot(face_center - center) < 0.0 {
+            y_axis = -y_axis;
+        }
+        let z_axis = x_axis.cross(y_axis);
+        let angle = if gyro { Float::TAU() / n as Float } else { 0.0 };
+
+        self.points(n)
+            .into_iter()
+            .map(|point| {
+                let point = turn(point, angle);
+                face_center + point.x * x_axis + point.y * y_axis + point.z * z_axis
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Band {
+    None,
+    Prism,
+    Antiprism,
+}
+
+/// Stacks the `top` and `bottom` caps on either end of `band`, an
+/// *n*-gonal prism or antiprism. With `gyro` the bottom cap is turned by one
+/// edge.
+fn stack(n: usize, top: Option<Cap>, band: Band, bottom: Option<Cap>, gyro: bool) -> Points {
+    let (angle, y) = match band {
+        Band::None => (0.0, 0.0),
+        Band::Prism => (0.0, -1.0),
+        Band::Antiprism => (Float::PI() / n as Float, -antiprism_height(n)),
+    };
+    let angle = if gyro {
+        angle + Float::TAU() / n as Float
+    } else {
+        angle
+    };
+
+    let mut points = ring(n, 0.0, 0.0);
+    if Band::None != band {
+        points.extend(ring(n, angle, y));
+    }
+    if let Some(top) = top {
+        points.extend(top.points(n));
+    }
+    if let Some(bottom) = bottom {
+        points.extend(
+            bottom
+                .points(n)
+                .into_iter()
+                .map(|point| turn(Point::new(point.x, y - point.y, point.z), angle)),
+        );
+    }
+
+    points
+}
+
+/// Picks `count` of `directions`: the first one and, with `para`, the one
+/// opposite to it. Otherwise ones neither adjacent nor opposite to all
+/// picked so far.
+fn pick(directions: &[Vector], count: usize, para: bool) -> Vec<usize> {
+    let mut picked = vec![0];
+    while picked.len() < count {
+        let next = (0..directions.len())
+            .find(|index| {
+                picked.iter().all(|other| {
+                    let cos = directions[*index].dot(directions[*other]);
+                    if para {
+                        cos < -0.99
+                    } else {
+                        -0.9 < cos && cos < -0.1
+                    }
+                })
+            })
+            .unwrap();
+        picked.push(next);
+    }
+    picked
+}
+
+/// The faces of `polyhedron` with `arity` vertices.
+fn faces_with_arity(polyhedron: &Polyhedron, arity: usize) -> Faces {
+    polyhedron
+        .faces()
+        .iter()
+        .filter(|face| arity == face.len())
+        .cloned()
+        .collect()
+}
+
+/// The unit normals of `faces`, pointing outwards for a polyhedron centered
+/// on the origin.
+fn face_directions(faces: &FacesSlice, points: &PointsSlice) -> Vec<Vector> {
+    faces
+        .iter()
+        .map(|face| {
+            centroid(&face.iter().map(|v| points[*v as usize]).collect::<Vec<_>>()).normalized()
+        })
+        .collect()
+}
+
+/// Puts a `cap` on `count` faces with `arity` vertices of `polyhedron`,
+/// picked as in [`pick()`].
+///
+/// Of the two ways to put a cupola or a rotunda on a face the one that
+/// doesn't make adjacent faces coplanar is used.
+fn augment(polyhedron: &Polyhedron, arity: usize, count: usize, para: bool, cap: Cap) -> Points {
+    let faces = faces_with_arity(polyhedron, arity);
+    let directions = face_directions(&faces, polyhedron.points());
+    let center = centroid(polyhedron.points());
+
+    pick(&directions, count, para).into_iter().fold(
+        polyhedron.points().clone(),
+        |mut points, face| {
+            let face_points: Points = faces[face]
+                .iter()
+                .map(|v| polyhedron.points()[*v as usize])
+                .collect();
+            let cap_points = if Cap::Pyramid == cap {
+                cap.on_face(&face_points, center, false)
+            } else {
+                [false, true]
+                    .iter()
+                    .map(|gyro| cap.on_face(&face_points, center, *gyro))
+                    .max_by_key(|cap_points| {
+                        let points = points.iter().chain(cap_points).cloned().collect();
+                        Polyhedron::from_hull("", points).faces().len()
+                    })
+                    .unwrap()
+            };
+            points.extend(cap_points);
+            points
+        },
+    )
+}
+
+/// Turns the `gyrate` and removes the `diminish` pentagonal cupolae of a
+/// rhombicosidodecahedron. The cupolae are picked as in [`pick()`], gyrated
+/// ones first.
+fn rhombicosidodecahedron(gyrate: usize, diminish: usize, para: bool) -> Points {
+    let polyhedron = Polyhedron::from_hull("", rhombicosidodecahedron_points());
+    let faces = faces_with_arity(&polyhedron, 5);
+    let directions = face_directions(&faces, polyhedron.points());
+
+    let mut points = polyhedron.points().clone();
+    let mut removed = Vec::new();
+    pick(&directions, gyrate + diminish, para)
+        .into_iter()
+        .enumerate()
+        .for_each(|(index, face)| {
+            if index < gyrate {
+                // Rotate the top pentagon by 36° around the cupola axis.
+                let axis = directions[face];
+                let (sin, cos) = (Float::PI() / 5.0).sin_cos();
+                let face_center = centroid(
+                    &faces[face]
+                        .iter()
+                        .map(|v| points[*v as usize])
+                        .collect::<Vec<_>>(),
+                );
+                faces[face].iter().for_each(|v| {
+                    let offset = points[*v as usize] - face_center;
+                    points[*v as usize] = face_center + cos * offset + sin * axis.cross(offset);
+                });
+            } else {
+                removed.extend(faces[face].iter().cloned());
+            }
+        });
+
+    points
+        .into_iter()
+        .enumerate()
+        .filter(|(v, _)| !removed.contains(&(*v as VertexKey)))
+        .map(|(_, point)| point)
+        .collect()
+}
+
+fn icosahedron_points() -> Points {
+    signed_permutations([0.0, 0.5, 0.5 * PHI], false)
+}
+
+fn dodecahedron_points() -> Points {
+    signed_permutations([0.5 * PHI, 0.5 * PHI, 0.5 * PHI], false)
+        .into_iter()
+        .chain(signed_permutations([0.0, 0.5, 0.5 * PHI * PHI], false))
+        .collect()
+}
+
+impl Polyhedron {
+    /// Creates the *n*-th [Johnson solid](https://en.wikipedia.org/wiki/Johnson_solid),
+    /// with unit edges, centered on its centroid.
+    ///
+    /// The faces are put into face sets by their number of vertices.
+    ///
+    /// Returns `None` if *n* is not in *[1, 92]*.
+    pub fn johnson(n: usize) -> Option<Self> {
+        use Band::{Antiprism, Prism};
+        use Cap::*;
+
+        // Gyroelongated bicupolae and birotundae.
+        let gyroelongated = |n, top, bottom| stack(n, Some(top), Antiprism, Some(bottom), false);
+        // The remaining stacks.
+        let bi = |n, top, band, bottom, gyro| stack(n, Some(top), band, Some(bottom), gyro);
+        let mono = |n, top, band| stack(n, Some(top), band, None, false);
+        let none = Band::None;
+
+        let hull = |points| Self::from_hull("", points);
+        let table = |points: &[[Float; 3]]| -> Points {
+            points.iter().map(|point| Point::from(*point)).collect()
+        };
+        let augmented_prism = |n: usize, sides: &[usize]| -> Points {
+            let center = Point::new(0.0, -0.5, 0.0);
+            let mut points = stack(n, None, Prism, None, false);
+            sides.iter().for_each(|side| {
+                let face = [
+                    points[*side],
+                    points[(*side + 1) % n],
+                    points[(*side + 1) % n + n],
+                    points[*side + n],
+                ];
+                points.extend(Pyramid.on_face(&face, center, false));
+            });
+            points
+        };
+        let icosahedron = |count| {
+            let points = icosahedron_points();
+            let directions: Vec<Vector> = points.iter().map(|point| point.normalized()).collect();
+            let removed = pick(&directions, count, false);
+            points
+                .into_iter()
+                .enumerate()
+                .filter(|(v, _)| !removed.contains(v))
+                .map(|(_, point)| point)
+                .collect::<Points>()
+        };
+
+        let points = match n {
+            1 => mono(4, Pyramid, none),
+            2 => mono(5, Pyramid, none),
+            3 => mono(6, Cupola, none),
+            4 => mono(8, Cupola, none),
+            5 => mono(10, Cupola, none),
+            6 => mono(10, Rotunda, none),
+            7 => mono(3, Pyramid, Prism),
+            8 => mono(4, Pyramid, Prism),
+            9 => mono(5, Pyramid, Prism),
+            10 => mono(4, Pyramid, Antiprism),
+            11 => mono(5, Pyramid, Antiprism),
+            12 => bi(3, Pyramid, none, Pyramid, false),
+            13 => bi(5, Pyramid, none, Pyramid, false),
+            14 => bi(3, Pyramid, Prism, Pyramid, false),
+            15 => bi(4, Pyramid, Prism, Pyramid, false),
+            16 => bi(5, Pyramid, Prism, Pyramid, false),
+            17 => gyroelongated(4, Pyramid, Pyramid),
+            18 => mono(6, Cupola, Prism),
+            19 => mono(8, Cupola, Prism),
+            20 => mono(10, Cupola, Prism),
+            21 => mono(10, Rotunda, Prism),
+            22 => mono(6, Cupola, Antiprism),
+            23 => mono(8, Cupola, Antiprism),
+            24 => mono(10, Cupola, Antiprism),
+            25 => mono(10, Rotunda, Antiprism),
+            // Gyrobifastigium.
+            26 => {
+                let h = 0.5 * (3.0 as Float).sqrt();
+                vec![
+                    Point::new(0.5, 0.0, 0.5),
+                    Point::new(0.5, 0.0, -0.5),
+                    Point::new(-0.5, 0.0, 0.5),
+                    Point::new(-0.5, 0.0, -0.5),
+                    Point::new(0.5, h, 0.0),
+                    Point::new(-0.5, h, 0.0),
+                    Point::new(0.0, -h, 0.5),
+                    Point::new(0.0, -h, -0.5),
+                ]
+            }
+            27 => bi(6, Cupola, none, Cupola, false),
+            28 => bi(8, Cupola, none, Cupola, false),
+            29 => bi(8, Cupola, none, Cupola, true),
+            30 => bi(10, Cupola, none, Cupola, false),
+            31 => bi(10, Cupola, none, Cupola, true),
+            32 => bi(10, Rotunda, none, Cupola, false),
+            33 => bi(10, Rotunda, none, Cupola, true),
+            34 => bi(10, Rotunda, none, Rotunda, false),
+            35 => bi(6, Cupola, Prism, Cupola, false),
+            36 => bi(6, Cupola, Prism, Cupola, true),
+            37 => bi(8, Cupola, Prism, Cupola, true),
+            38 => bi(10, Cupola, Prism, Cupola, false),
+            39 => bi(10, Cupola, Prism, Cupola, true),
+            40 => bi(10, Rotunda, Prism, Cupola, false),
+            41 => bi(10, Rotunda, Prism, Cupola, true),
+            42 => bi(10, Rotunda, Prism, Rotunda, false),
+            43 => bi(10, Rotunda, Prism, Rotunda, true),
+            44 => gyroelongated(6, Cupola, Cupola),
+            45 => gyroelongated(8, Cupola, Cupola),
+            46 => gyroelongated(10, Cupola, Cupola),
+            47 => gyroelongated(10, Rotunda, Cupola),
+            48 => gyroelongated(10, Rotunda, Rotunda),
+            49 => augmented_prism(3, &[0]),
+            50 => augmented_prism(3, &[0, 1]),
+            51 => augmented_prism(3, &[0, 1, 2]),
+            52 => augmented_prism(5, &[0]),
+            53 => augmented_prism(5, &[0, 2]),
+            54 => augmented_prism(6, &[0]),
+            55 => augmented_prism(6, &[0, 3]),
+            56 => augmented_prism(6, &[0, 2]),
+            57 => augmented_prism(6, &[0, 2, 4]),
+            58 => augment(&hull(dodecahedron_points()), 5, 1, false, Pyramid),
+            59 => augment(&hull(dodecahedron_points()), 5, 2, true, Pyramid),
+            60 => augment(&hull(dodecahedron_points()), 5, 2, false, Pyramid),
+            61 => augment(&hull(dodecahedron_points()), 5, 3, false, Pyramid),
+            62 => icosahedron(2),
+            63 => icosahedron(3),
+            // Augment the triangle surrounded by the three pentagons.
+            64 => {
+                let tridiminished = hull(icosahedron(3));
+                let edge_faces: HashMap<Edge, usize> = tridiminished
+                    .faces()
+                    .iter()
+                    .flat_map(|face| {
+                        face.iter()
+                            .circular_tuple_windows::<(_, _)>()
+                            .map(move |(a, b)| ([*a, *b], face.len()))
+                    })
+                    .collect();
+                let face = tridiminished
+                    .faces()
+                    .iter()
+                    .find(|face| {
+                        3 == face.len()
+                            && face
+                                .iter()
+                                .circular_tuple_windows::<(_, _)>()
+                                .all(|(a, b)| Some(&5) == edge_faces.get(&[*b, *a]))
+                    })
+                    .unwrap();
+                let face_points: Points = face
+                    .iter()
+                    .map(|v| tridiminished.points()[*v as usize])
+                    .collect();
+                tridiminished
+                    .points()
+                    .iter()
+                    .cloned()
+                    .chain(Pyramid.on_face(&face_points, Point::zero(), false))
+                    .collect()
+            }
+            65 => augment(&hull(truncated_tetrahedron_points()), 6, 1, false, Cupola),
+            66 => augment(&hull(truncated_cube_points()), 8, 1, false, Cupola),
+            67 => augment(&hull(truncated_cube_points()), 8, 2, true, Cupola),
+            68 => augment(&hull(truncated_dodecahedron_points()), 10, 1, false, Cupola),
+            69 => augment(&hull(truncated_dodecahedron_points()), 10, 2, true, Cupola),
+            70 => augment(&hull(truncated_dodecahedron_points()), 10, 2, false, Cupola),
+            71 => augment(&hull(truncated_dodecahedron_points()), 10, 3, false, Cupola),
+            72 => rhombicosidodecahedron(1, 0, false),
+            73 => rhombicosidodecahedron(2, 0, true),
+            74 => rhombicosidodecahedron(2, 0, false),
+            75 => rhombicosidodecahedron(3, 0, false),
+            76 => rhombicosidodecahedron(0, 1, false),
+            77 => rhombicosidodecahedron(1, 1, true),
+            78 => rhombicosidodecahedron(1, 1, false),
+            79 => rhombicosidodecahedron(2, 1, false),
+            80 => rhombicosidodecahedron(0, 2, true),
+            81 => rhombicosidodecahedron(0, 2, false),
+            82 => rhombicosidodecahedron(1, 2, false),
+            83 => rhombicosidodecahedron(0, 3, false),
+            84 => table(&SNUB_DISPHENOID),
+            85 => table(&SNUB_SQUARE_ANTIPRISM),
+            86 => table(&SPHENOCORONA),
+            87 => augment(&hull(table(&SPHENOCORONA)), 4, 1, false, Pyramid),
+            88 => table(&SPHENOMEGACORONA),
+            89 => table(&HEBESPHENOMEGACORONA),
+            90 => table(&DISPHENOCINGULUM),
+            91 => table(&BILUNABIROTUNDA),
+            92 => table(&TRIANGULAR_HEBESPHENOROTUNDA),
+            _ => return None,
+        };
+
+        Some(Self::from_hull(&format!("J{}", n), points))
+    }
+}
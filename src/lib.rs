@@ -7,7 +7,8 @@
 //!
 //! The internal representation uses mesh buffers. These need furter
 //! preprocessing before they can be sent to a GPU but are almost fine to send
-//! to an offline renderer, as-is.
+//! to an offline renderer, as-is. [`to_gpu_buffers()`](Polyhedron::to_gpu_buffers())
+//! does this preprocessing.
 //!
 //! See the `playground` example for code on how to do either.
 //! ## Example
@@ -17,10 +18,10 @@
 //!
 //! // Conway notation: gapcD
 //! let polyhedron = Polyhedron::dodecahedron()
-//!     .chamfer(None, true)
-//!     .propellor(None, true)
+//!     .chamfer(None, None, None, true)
+//!     .propeller(None, None, true)
 //!     .ambo(None, true)
-//!     .gyro(None, None, true)
+//!     .gyro(None, None, None, true)
 //!     .finalize();
 //!
 //! // Export as ./polyhedron-gapcD.obj
@@ -39,7 +40,7 @@
 //! ## Cargo Features
 //! ```toml
 //! [dependencies]
-//! polyhedron-ops = { version = "0.2.1", features = [ "bevy", "nsi", "obj" ] }
+//! polyhedron-ops = { version = "0.2.1", features = [ "3mf", "bevy", "glam", "gltf", "kiss3d", "meshopt", "nalgebra", "nsi", "obj", "off", "petgraph", "ply", "python", "scad", "serde", "svg", "three-d", "threejs", "tri-mesh", "usd", "wasm", "x3d" ] }
 //! ```
 //! * `bevy` – A polyhedro can be converted into a [`bevy`](https://bevyengine.org/)
 //!   [`Mesh`](https://docs.rs/bevy/latest/bevy/render/mesh/struct.Mesh.html).
@@ -49,28 +50,163 @@
 //!   Mesh::from(polyhedron)
 //!   ```
 //!
+//! * `3mf` – Add support for output to [3MF](https://3mf.io/) for 3D
+//!   printing, with faces colored by face set, via the
+//!   [`write_to_3mf()`](Polyhedron::write_to_3mf()) function.
+//!
+//! * `glam` – Points can be accessed as [`glam`](https://crates.io/crates/glam)
+//!   vectors, without copying, via the
+//!   [`points_as_glam()`](Polyhedron::points_as_glam()) function.
+//!
+//! * `gltf` – Add support for output to binary [glTF 2.0](https://www.khronos.org/gltf/),
+//!   with one primitive per face set, via the
+//!   [`write_to_gltf()`](Polyhedron::write_to_gltf()) function.
+//!
+//! * `kiss3d` – A polyhedron can be converted into a [`kiss3d`](https://crates.io/crates/kiss3d)
+//!   [`Mesh`](https://docs.rs/kiss3d/latest/kiss3d/resource/struct.Mesh.html)
+//!   with `try_from()`. This fails for meshes with more than 65536 points.
+//!
+//! * `meshopt` – Add support for optimizing and quantizing GPU buffers with
+//!   [meshoptimizer](https://github.com/zeux/meshoptimizer) via the
+//!   [`to_optimized_gpu_buffers()`](Polyhedron::to_optimized_gpu_buffers())
+//!   and [`to_quantized_gpu_buffers()`](Polyhedron::to_quantized_gpu_buffers())
+//!   functions.
+//!
+//! * `nalgebra` – Points can be accessed as [`nalgebra`](https://crates.io/crates/nalgebra)
+//!   points, without copying, via the
+//!   [`points_as_nalgebra()`](Polyhedron::points_as_nalgebra()) function.
+//!
 //! * `nsi` – Add supports for sending data to renderers implementing the [ɴsɪ](https://crates.io/crates/nsi/)
-//!   API. The function is called [`to_nsi()`](Polyhedron::to_nsi()).
+//!   API. The functions are called [`to_nsi()`](Polyhedron::to_nsi()) and,
+//!   for ball-and-stick models,
+//!   [`to_nsi_wireframe()`](Polyhedron::to_nsi_wireframe()).
+//!
+//! * `obj` – Add support for output to and input from [Wavefront OBJ](https://en.wikipedia.org/wiki/Wavefront_.obj_file)
+//!   via the [`write_to_obj()`](Polyhedron::write_to_obj()) and
+//!   [`read_from_obj()`](Polyhedron::read_from_obj()) functions. Normals,
+//!   a group per face set and an MTL file are written by
+//!   [`write_to_obj_with_face_sets()`](Polyhedron::write_to_obj_with_face_sets()).
+//!
+//! * `off` – Add support for reading and writing [OFF](https://en.wikipedia.org/wiki/OFF_(file_format))
+//!   files via the [`read_from_off()`](Polyhedron::read_from_off()) and
+//!   [`write_to_off()`](Polyhedron::write_to_off()) functions.
+//!
+//! * `petgraph` – Add support for converting the vertices and edges or the
+//!   faces of a polyhedron into a [`petgraph`](https://crates.io/crates/petgraph)
+//!   graph via the [`to_graph()`](Polyhedron::to_graph()) and
+//!   [`to_face_graph()`](Polyhedron::to_face_graph()) functions.
+//!
+//! * `parallel` – Run operators multithreaded with
+//!   [`rayon`](https://crates.io/crates/rayon). This is on by default.
+//!   Disable it to build for `wasm32-unknown-unknown`.
+//!
+//! * `ply` – Add support for output to [PLY](https://en.wikipedia.org/wiki/PLY_(file_format)),
+//!   with faces colored by face set, via the
+//!   [`write_to_ply()`](Polyhedron::write_to_ply()) function.
+//!
+//! * `python` – Add [Python](https://www.python.org/) bindings via
+//!   [PyO3](https://pyo3.rs/). The `polyhedron_ops` module exposes a
+//!   `Polyhedron` class with the seeds, the operators and the exporters
+//!   enabled by other features. Build it with
+//!   [maturin](https://github.com/PyO3/maturin), which passes the `cdylib`
+//!   crate type to cargo.
+//!
+//! * `scad` – Add support for output to [OpenSCAD](https://openscad.org/)
+//!   via the [`write_to_scad()`](Polyhedron::write_to_scad()) function.
+//!
+//! * `serde` – Add support for (de)serializing a [`Polyhedron`] with
+//!   [serde](https://serde.rs/).
+//!
+//! * `svg` – Add support for drawing [Schlegel diagrams](https://en.wikipedia.org/wiki/Schlegel_diagram)
+//!   and unfolded nets as SVG via the
+//!   [`write_schlegel_svg()`](Polyhedron::write_schlegel_svg()) and
+//!   [`write_net_svg()`](Polyhedron::write_net_svg()) functions.
+//!
+//! * `three-d` – A polyhedron can be converted into a [`three-d`](https://crates.io/crates/three-d)
+//!   `CPUMesh`.
 //!
-//! * `obj` – Add support for output to [Wavefront OBJ](https://en.wikipedia.org/wiki/Wavefront_.obj_file)
-//!   via the [`write_to_obj()`](Polyhedron::write_to_obj()) function.
+//! * `threejs` – Add support for output to a [Three.js](https://threejs.org/)
+//!   `BufferGeometry`, with one group per face set, via the
+//!   [`write_to_threejs()`](Polyhedron::write_to_threejs()) function.
+//!
+//! * `tri-mesh` – A polyhedron can be converted to and from a
+//!   [`tri-mesh`](https://crates.io/crates/tri-mesh) half-edge `Mesh`.
+//!
+//! * `usd` – Add support for output to [USD](https://graphics.pixar.com/usd/)
+//!   as a subdivision surface, like with `nsi`, via the
+//!   [`write_to_usd()`](Polyhedron::write_to_usd()) function.
+//!
+//! * `wasm` – Add [WebAssembly](https://webassembly.org/) bindings via
+//!   [`wasm-bindgen`](https://rustwasm.github.io/wasm-bindgen/). These
+//!   expose creating a polyhedron from its Conway notation and extracting
+//!   GPU buffers from it.
+//!
+//! * `x3d` – Add support for output to [X3D](https://www.web3d.org/x3d/what-x3d)
+//!   and [VRML](https://en.wikipedia.org/wiki/VRML)97, with faces colored by
+//!   face set, via the [`write_to_x3d()`](Polyhedron::write_to_x3d()) and
+//!   [`write_to_vrml()`](Polyhedron::write_to_vrml()) functions.
 use itertools::Itertools;
 use num_traits::FloatConst;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{Debug, Display, Formatter, Write},
+    iter::{once, Iterator},
+    sync::Arc,
+};
 #[cfg(feature = "obj")]
 use std::{
     error::Error,
     fs::File,
-    io::Write as IoWrite,
+    io::{BufWriter, Read, Write as IoWrite},
     path::{Path, PathBuf},
 };
-use std::{
-    fmt::{Display, Write},
-    iter::Iterator,
-};
 use ultraviolet as uv;
 
+mod archimedean;
+mod csg;
+#[cfg(feature = "gltf")]
+mod gltf;
+#[cfg(feature = "petgraph")]
+mod graph;
+#[cfg(feature = "tri-mesh")]
+mod half_edge;
 mod helpers;
+mod identify;
+mod johnson;
+mod notation;
+#[cfg(feature = "off")]
+mod off;
+#[cfg(feature = "meshopt")]
+mod optimize;
+#[cfg(feature = "ply")]
+mod ply;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "scad")]
+mod scad;
+mod seeds;
+#[cfg(not(feature = "parallel"))]
+mod sequential;
+#[cfg(not(feature = "parallel"))]
+use sequential::*;
+#[cfg(feature = "svg")]
+mod svg;
+#[cfg(feature = "3mf")]
+mod three_mf;
+#[cfg(feature = "3mf")]
+pub use three_mf::LengthUnit;
+#[cfg(feature = "threejs")]
+mod threejs;
+mod topology;
+#[cfg(feature = "usd")]
+mod usd;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod wythoff;
+#[cfg(feature = "x3d")]
+mod x3d;
 use helpers::*;
 
 #[cfg(test)]
@@ -84,6 +220,9 @@ pub mod prelude {
 }
 
 static EPSILON: f32 = 0.00000001;
+/// How many points [`sample_volume()`](Polyhedron::sample_volume()) draws at
+/// most for each point it returns.
+static SAMPLE_VOLUME_ATTEMPTS: usize = 100;
 
 pub type Float = f32;
 pub type VertexKey = u32;
@@ -105,7 +244,334 @@ pub type Points = Vec<Point>;
 pub(crate) type PointsSlice = [Point];
 pub(crate) type PointRefSlice<'a> = [&'a Point];
 
+/// The class of a [geodesic
+/// polyhedron](https://en.wikipedia.org/wiki/Geodesic_polyhedron).
+///
+/// The class determines the direction of the lattice vector *(m, n)* each
+/// triangle is subdivided along. See
+/// [`geodesic()`](Polyhedron::geodesic()).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeodesicClass {
+    /// Subdivision along the edges, *(frequency, 0)*.
+    I,
+    /// Subdivision perpendicular to the edges, *(frequency, frequency)*.
+    II,
+    /// Skewed subdivision, *(frequency, n)*. The result is chiral.
+    III(usize),
+}
+
+/// Computes the new vertex of a face from the face and the points of the
+/// polyhedron. See [`KisApex::Custom`].
+pub type ApexFn = dyn Fn(&Face, &Points) -> Point + Send + Sync;
+
+/// Computes a parameter of a face from its index, its arity and its
+/// centroid.
+///
+/// E.g. `&|_, _, centroid| centroid.y.abs()` makes spikes grow towards the
+/// poles.
+pub type FaceParameterFn<'a> = dyn Fn(usize, usize, &Point) -> Float + Send + Sync + 'a;
+
+/// Where [`kis_with_apex()`](Polyhedron::kis_with_apex()) places the new
+/// vertex of each face.
+#[derive(Clone)]
+pub enum KisApex {
+    /// The face centroid, offset along the face normal by the given height.
+    Normal(Float),
+    /// The face centroid, offset by the given height along the direction
+    /// from the centroid of the polyhedron through the face centroid.
+    Radial(Float),
+    /// The face centroid, offset along the face normal by a height computed
+    /// per face.
+    NormalPerFace(Arc<FaceParameterFn<'static>>),
+    /// Computed by a closure from the face and the points of the polyhedron.
+    Custom(Arc<ApexFn>),
+}
+
+/// Closures are printed as `..`.
+impl Debug for KisApex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KisApex::Normal(height) => f.debug_tuple("Normal").field(height).finish(),
+            KisApex::Radial(height) => f.debug_tuple("Radial").field(height).finish(),
+            KisApex::NormalPerFace(_) => write!(f, "NormalPerFace(..)"),
+            KisApex::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// Formats the apex for use in the name of a polyhedron. Heights along the
+/// normal are written as is, the other variants are marked in braces.
+impl Display for KisApex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KisApex::Normal(height) => write!(f, "{:.2}", height),
+            KisApex::Radial(height) => write!(f, "{{r}}{:.2}", height),
+            KisApex::NormalPerFace(_) => write!(f, "{{n..}}"),
+            KisApex::Custom(_) => write!(f, "{{..}}"),
+        }
+    }
+}
+
+/// Decides if a face is selected from its index, the face and the points of
+/// the polyhedron. See [`FaceSelector::Predicate`].
+pub type FaceSelectorFn = dyn Fn(usize, &Face, &Points) -> bool + Send + Sync;
+
+/// Selects the faces an operator is applied to.
+///
+/// Operators that work on vertices apply the selector to the faces of the
+/// [`dual()`](Polyhedron::dual()) instead. These have the indices of the
+/// vertices and the valences of the vertices as their arities.
+#[derive(Clone)]
+pub enum FaceSelector {
+    /// All faces.
+    All,
+    /// Faces with one of the given arities (number of vertices).
+    Arity(Vec<usize>),
+    /// Faces whose edges have (roughly) the same length.
+    Regular,
+    /// Faces with the given indices.
+    Indices(Vec<usize>),
+    /// Faces in the face set with the given index.
+    FaceSet(usize),
+    /// Faces selected by all of the given selectors.
+    And(Vec<FaceSelector>),
+    /// Faces for which the closure returns `true`.
+    Predicate(Arc<FaceSelectorFn>),
+}
+
+/// Closures are printed as `..`.
+impl Debug for FaceSelector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FaceSelector::All => write!(f, "All"),
+            FaceSelector::Arity(arity) => f.debug_tuple("Arity").field(arity).finish(),
+            FaceSelector::Regular => write!(f, "Regular"),
+            FaceSelector::Indices(indices) => f.debug_tuple("Indices").field(indices).finish(),
+            FaceSelector::FaceSet(face_set) => f.debug_tuple("FaceSet").field(face_set).finish(),
+            FaceSelector::And(selectors) => f.debug_tuple("And").field(selectors).finish(),
+            FaceSelector::Predicate(_) => write!(f, "Predicate(..)"),
+        }
+    }
+}
+
+impl FaceSelector {
+    /// Returns for each face if it is selected, given the points and the
+    /// face sets the faces refer to.
+    fn selection(&self, faces: &FacesSlice, points: &Points, face_sets: &[FaceSet]) -> Vec<bool> {
+        // Marks the faces with the given indices.
+        let mark = |indices: &mut dyn Iterator<Item = usize>| {
+            let mut selected = vec![false; faces.len()];
+            indices.for_each(|index| {
+                if let Some(selected) = selected.get_mut(index) {
+                    *selected = true;
+                }
+            });
+            selected
+        };
+
+        match self {
+            FaceSelector::All => vec![true; faces.len()],
+            FaceSelector::Arity(arity) => faces
+                .par_iter()
+                .map(|face| arity.contains(&face.len()))
+                .collect(),
+            FaceSelector::Regular => faces
+                .par_iter()
+                .map(|face| (face_irregularity(face, points) - 1.0).abs() < 0.1)
+                .collect(),
+            FaceSelector::Indices(indices) => mark(&mut indices.iter().cloned()),
+            FaceSelector::FaceSet(face_set) => match face_sets.get(*face_set) {
+                Some(face_set) => mark(&mut face_set.iter().map(|face| *face as usize)),
+                None => vec![false; faces.len()],
+            },
+            FaceSelector::And(selectors) => {
+                selectors
+                    .iter()
+                    .fold(vec![true; faces.len()], |mut selected, selector| {
+                        selected
+                            .iter_mut()
+                            .zip(selector.selection(faces, points, face_sets))
+                            .for_each(|(selected, other)| *selected &= other);
+                        selected
+                    })
+            }
+            FaceSelector::Predicate(predicate) => faces
+                .par_iter()
+                .enumerate()
+                .map(|(index, face)| predicate(index, face, points))
+                .collect(),
+        }
+    }
+}
+
+/// Formats the selector for use in the name of a polyhedron. Arities are
+/// written as is, the other selectors are marked in braces. Closures are
+/// written as `{..}`.
+impl Display for FaceSelector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FaceSelector::All => Ok(()),
+            FaceSelector::Arity(arity) => write!(f, "{}", format_vec(arity)),
+            FaceSelector::Regular => write!(f, "{{t}}"),
+            FaceSelector::Indices(indices) => write!(f, "{{i{}}}", format_vec(indices)),
+            FaceSelector::FaceSet(face_set) => write!(f, "{{f{}}}", face_set),
+            FaceSelector::And(selectors) => write!(
+                f,
+                "{}",
+                selectors
+                    .iter()
+                    .map(|selector| selector.to_string())
+                    .filter(|selector| !selector.is_empty())
+                    .join(",")
+            ),
+            FaceSelector::Predicate(_) => write!(f, "{{..}}"),
+        }
+    }
+}
+
+/// The directions [`jitter()`](Polyhedron::jitter()) displaces points in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JitterDirection {
+    /// Any direction.
+    All,
+    /// Only towards or away from the origin.
+    Radial,
+    /// Only perpendicular to the direction towards the origin.
+    Tangential,
+}
+
+/// How per-vertex normals of meshes are computed, e.g. for export.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NormalType {
+    /// Each face has its own normal. Points are duplicated for each face
+    /// they belong to, giving a faceted look.
+    Flat,
+    /// Each point has the area-weighted average normal of the faces it
+    /// belongs to.
+    Smooth,
+}
+
+/// How the attributes of vertices are arranged in the buffer returned by
+/// [`to_gpu_buffers()`](Polyhedron::to_gpu_buffers()).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VertexLayout {
+    /// All attributes of a vertex follow each other, followed by those of
+    /// the next vertex.
+    Interleaved,
+    /// All values of an attribute follow each other, followed by those of
+    /// the next attribute.
+    Planar,
+}
+
+/// What [`to_gpu_buffers()`](Polyhedron::to_gpu_buffers()) puts into the
+/// buffers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GpuBufferOptions {
+    /// How normals are computed.
+    pub normal_type: NormalType,
+    /// Add texture coordinates, projected onto a sphere around the origin.
+    pub uvs: bool,
+    /// Add the index of the first face set each vertex’s face is in.
+    pub face_set_ids: bool,
+    /// Make triangles counter-clockwise, seen from the outside.
+    pub reverse_winding: bool,
+    /// How attributes are arranged.
+    pub layout: VertexLayout,
+}
+
+impl GpuBufferOptions {
+    /// Returns the number of [`f32`] each attribute takes.
+    fn attribute_sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![3, 3];
+        if self.uvs {
+            sizes.push(2);
+        }
+        if self.face_set_ids {
+            sizes.push(1);
+        }
+        sizes
+    }
+
+    /// Returns the number of [`f32`] each vertex takes.
+    pub fn vertex_size(&self) -> usize {
+        self.attribute_sizes().iter().sum()
+    }
+}
+
+impl Default for GpuBufferOptions {
+    fn default() -> Self {
+        Self {
+            normal_type: NormalType::Flat,
+            uvs: false,
+            face_set_ids: false,
+            reverse_winding: true,
+            layout: VertexLayout::Interleaved,
+        }
+    }
+}
+
+/// How [`to_nsi()`](Polyhedron::to_nsi()) sets up the mesh.
+#[cfg(feature = "nsi")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NsiOptions {
+    /// The hardness of edges (default: 10).
+    pub crease_hardness: Option<f32>,
+    /// The hardness of the edges of the faces in a face set, by the index
+    /// of the face set, overriding `crease_hardness`. Faces are in the
+    /// first face set they are in. An edge between faces with different
+    /// hardnesses gets the higher one.
+    pub face_set_crease_hardness: HashMap<usize, f32>,
+    /// The hardness of vertices (default: 0).
+    pub corner_hardness: Option<f32>,
+    /// Whether to keep corners smooth, where more than two edges meet. When
+    /// set to `false` these automatically form a hard corner with the same
+    /// hardness as `crease_hardness`.
+    pub smooth_corners: Option<bool>,
+    /// If given, the polyhedron is sent as a plain polygon mesh with flat or
+    /// smooth normals instead of as a subdivision surface. The hardness
+    /// options are ignored then.
+    pub normal_type: Option<NormalType>,
+    /// Add texture coordinates, `st`, projected onto a sphere around the
+    /// origin.
+    pub st: bool,
+    /// If not empty, each face gets the color of the first face set it is
+    /// in, cycling through the `palette`, as the per-face attribute `Cs`.
+    /// Faces that are in no face set are white.
+    pub palette: Vec<[u8; 3]>,
+    /// The matrix to place the mesh with, as NSI’s `transformationmatrix`:
+    /// 4×4, with the translation in the last row.
+    pub transform: Option<[f64; 16]>,
+}
+
+/// Statistics of the edge lengths of a polyhedron, returned by
+/// [`edge_length_stats()`](Polyhedron::edge_length_stats()).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EdgeLengthStats {
+    pub min: Float,
+    pub max: Float,
+    pub mean: Float,
+    /// The standard deviation.
+    pub std_dev: Float,
+}
+
+/// How [`lace()`](Polyhedron::lace()) and its variants attach the
+/// antiprismatic band to the original edges.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LaceBand {
+    /// Two triangles meeting at the original edge.
+    Edge,
+    /// One quadrilateral spanning the original edge.
+    Joined,
+    /// Two triangles meeting across the original edge.
+    Opposite,
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate")
+)]
 pub struct Polyhedron {
     face_index: Faces,
     points: Points,
@@ -208,432 +674,3346 @@ impl Polyhedron {
         bounds
     }
 
-    /// Appends indices for newly added faces as a new FaceSet to the
-    /// FaceSetIndex.
-    fn append_new_face_set(&mut self, size: usize) {
-        self.face_set_index
-            .append(&mut vec![((self.face_index.len() as VertexKey)
-                ..((self.face_index.len() + size) as VertexKey))
-                .collect()]);
+    /// Returns the signed volume of the polyhedron.
+    ///
+    /// The volume is computed with the divergence theorem, summing the
+    /// signed volumes of the tetrahedra spanned by the origin and each
+    /// triangle of the faces, triangulated as fans. It is positive if the
+    /// faces are clockwise seen from the outside, as they are by default,
+    /// and negative if they are all
+    /// [`reverse()`](Polyhedron::reverse())d. A value that is off otherwise
+    /// hints at inconsistent winding or an open mesh.
+    pub fn volume(&self) -> Float {
+        -self
+            .face_index
+            .par_iter()
+            .map(|face| {
+                let a = self.points[face[0] as usize];
+                face[1..]
+                    .iter()
+                    .tuple_windows::<(_, _)>()
+                    .map(|(b, c)| a.dot(self.points[*b as usize].cross(self.points[*c as usize])))
+                    .sum::<Float>()
+            })
+            .sum::<Float>()
+            / 6.0
     }
 
-    pub fn planarize(&mut self, iterations: usize) {
-        let mut dual = self.clone().dual(false).finalize();
-        for _ in 0..iterations {
-            // Reciprocate face centers.
-            dual.points = reciprocate_face_centers(&self.face_index, &self.points);
-            self.points = reciprocate_face_centers(&dual.face_index, &dual.points);
-        }
+    /// Returns the triangles of the faces, triangulated as fans, with the
+    /// index of their face.
+    fn fan_triangles(&self) -> impl Iterator<Item = ([Point; 3], usize)> + '_ {
+        self.face_index
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, face)| {
+                let a = self.points[face[0] as usize];
+                face[1..]
+                    .iter()
+                    .tuple_windows::<(_, _)>()
+                    .map(move |(b, c)| {
+                        (
+                            [a, self.points[*b as usize], self.points[*c as usize]],
+                            index,
+                        )
+                    })
+            })
     }
 
-    /// Creates vertices with valence (aka degree) four.
-
-    /// It is also called [rectification](https://en.wikipedia.org/wiki/Rectification_(geometry)),
-    /// or the  [medial graph](https://en.wikipedia.org/wiki/Medial_graph) in graph theory.
-    #[inline]
-    pub fn ambo(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
-        let ratio_ = match ratio {
-            Some(r) => r.clamp(0.0, 1.0),
-            None => 1. / 2.,
-        };
-
-        let edges = self.to_edges();
-
-        let points: Vec<(&Edge, Point)> = edges
-            .par_iter()
-            .map(|edge| {
-                let edge_points = index_as_points(edge, &self.points);
-                (
-                    edge,
-                    ratio_ * *edge_points[0] + (1.0 - ratio_) * *edge_points[1],
+    /// Returns `true` if the `point` is inside the polyhedron.
+    ///
+    /// This uses the winding number of the faces around the `point`, the sum
+    /// of the solid angles of their triangles. It works for non-convex
+    /// polyhedra but assumes the polyhedron is closed. Points on the surface
+    /// may be reported as inside or outside.
+    pub fn contains(&self, point: &Point) -> bool {
+        let solid_angle = self
+            .fan_triangles()
+            .map(|(triangle, _)| {
+                // Van Oosterom and Strackee's formula.
+                let [a, b, c] = [
+                    triangle[0] - *point,
+                    triangle[1] - *point,
+                    triangle[2] - *point,
+                ];
+                let (a_mag, b_mag, c_mag) = (a.mag(), b.mag(), c.mag());
+                2.0 * a.dot(b.cross(c)).atan2(
+                    a_mag * b_mag * c_mag + a.dot(b) * c_mag + a.dot(c) * b_mag + b.dot(c) * a_mag,
                 )
             })
-            .collect();
+            .sum::<Float>();
 
-        let new_ids = vertex_ids_edge_ref_ref(&points, 0);
+        // The winding number is ±1 inside and 0 outside.
+        Float::PI() * 2.0 < solid_angle.abs()
+    }
 
-        let face_index: Faces = self
-            .face_index
-            .par_iter()
-            .map(|face| {
-                let edges = distinct_face_edges(face);
-                let result = edges
-                    .iter()
-                    .filter_map(|edge| vertex_edge(edge, &new_ids))
-                    .collect::<Vec<_>>();
-                result
-            })
-            .chain(
-                self.points
-                    // Each old vertex creates a new face ...
-                    .par_iter()
-                    .enumerate()
-                    .map(|polygon_vertex| {
-                        let vertex_number = polygon_vertex.0 as VertexKey;
-                        ordered_vertex_edges(
-                            vertex_number,
-                            &vertex_faces(vertex_number, &self.face_index),
-                        )
-                        .iter()
-                        .map(|ve| vertex_edge(&distinct_edge(ve), &new_ids).unwrap() as VertexKey)
-                        .collect::<Vec<_>>()
-                    }),
-            )
-            .collect();
+    /// Returns where a ray first hits the polyhedron: the distance from the
+    /// `origin` along the `direction`, in multiples of its length, and the
+    /// index of the face that was hit. Faces are hit from either side.
+    ///
+    /// Returns `None` if the ray misses the polyhedron.
+    pub fn ray_intersect(&self, origin: &Point, direction: &Vector) -> Option<(Float, usize)> {
+        self.fan_triangles()
+            .filter_map(|([a, b, c], index)| {
+                // Möller–Trumbore.
+                let (ab, ac) = (b - a, c - a);
+                let p = direction.cross(ac);
+                let determinant = ab.dot(p);
+                if determinant.abs() < EPSILON {
+                    return None;
+                }
 
-        self.append_new_face_set(face_index.len());
+                let t = (*origin - a) / determinant;
+                let u = t.dot(p);
+                let q = t.cross(ab);
+                let v = direction.dot(q);
+                if u < 0.0 || v < 0.0 || 1.0 < u + v {
+                    return None;
+                }
 
-        self.face_index = face_index;
-        self.points = vertex_values(&points);
+                let distance = ac.dot(q);
+                match 0.0 <= distance {
+                    true => Some((distance, index)),
+                    false => None,
+                }
+            })
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+    }
 
-        if change_name {
-            let mut params = String::new();
-            if let Some(ratio) = ratio {
-                write!(&mut params, "{:.2}", ratio).unwrap();
-            }
-            self.name = format!("a{}{}", params, self.name);
-        }
+    /// Returns `n` pseudo-random points, uniformly distributed over the
+    /// surface of the polyhedron.
+    ///
+    /// Faces are triangulated as fans and each triangle gets a share of the
+    /// points proportional to its area. The points only depend on `seed`
+    /// (default: 0) and `n`. The same seed always gives the same result.
+    pub fn sample_surface(&self, n: usize, seed: Option<u64>) -> Points {
+        let triangles = self
+            .fan_triangles()
+            .map(|(triangle, _)| triangle)
+            .collect::<Vec<_>>();
+        // The running sum of the triangle areas.
+        let areas = triangles
+            .iter()
+            .scan(0.0, |sum, [a, b, c]| {
+                *sum += (*b - *a).cross(*c - *a).mag() / 2.0;
+                Some(*sum)
+            })
+            .collect::<Vec<_>>();
+        let total_area = match areas.last() {
+            Some(area) if 0.0 < *area => *area,
+            _ => return Points::new(),
+        };
 
-        self
+        let seed = seed.unwrap_or(0);
+        (0..n as u64)
+            .into_par_iter()
+            .map(|index| {
+                let mut state = split_mix(seed ^ split_mix(index));
+                let area = random_unit(&mut state) * total_area;
+                let [a, b, c] = triangles[areas
+                    .partition_point(|sum| *sum <= area)
+                    .min(triangles.len() - 1)];
+                // Uniform barycentric coordinates.
+                let u = random_unit(&mut state).sqrt();
+                let v = random_unit(&mut state);
+                a * (1.0 - u) + b * (u * (1.0 - v)) + c * (u * v)
+            })
+            .collect()
     }
 
-    pub fn bevel(
-        &mut self,
-        ratio: Option<Float>,
-        height: Option<Float>,
-        vertex_valence: Option<Vec<usize>>,
-        regular_faces_only: Option<bool>,
-        change_name: bool,
-    ) -> &mut Self {
-        self.truncate(height, vertex_valence.clone(), regular_faces_only, false);
-        self.ambo(ratio, false);
-
-        if change_name {
-            let mut params = String::new();
-            if let Some(ratio) = ratio {
-                write!(&mut params, "{:.2}", ratio).unwrap();
-            }
-            if let Some(height) = height {
-                write!(&mut params, ",{:.2}", height).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
-            }
-            if let Some(vertex_valence) = vertex_valence {
-                write!(&mut params, ",{}", format_vec(&vertex_valence)).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
-            }
-            if let Some(regular_faces_only) = regular_faces_only {
-                if regular_faces_only {
-                    params.push_str(",{t}");
-                }
-            } else {
-                write!(&mut params, ",").unwrap();
-            }
-            params = params.trim_end_matches(',').to_string();
-            self.name = format!("b{}{}", params, self.name);
+    /// Returns `n` pseudo-random points, uniformly distributed inside the
+    /// polyhedron.
+    ///
+    /// Points are drawn from the bounding box and rejected unless the
+    /// polyhedron [`contains()`](Polyhedron::contains()) them. The points
+    /// only depend on `seed` (default: 0) and `n`. The same seed always
+    /// gives the same result.
+    ///
+    /// Each point gets a limited number of attempts, so fewer than `n`
+    /// points are returned if the polyhedron fills only a tiny part of its
+    /// bounding box or is not closed. Returns no points if the polyhedron
+    /// has no volume.
+    pub fn sample_volume(&self, n: usize, seed: Option<u64>) -> Points {
+        if self.volume().abs() < EPSILON {
+            return Points::new();
         }
 
-        self
+        let [x_min, y_min, z_min, x_max, y_max, z_max] = self.bounding_box();
+        let (min, size) = (
+            Point::new(x_min as _, y_min as _, z_min as _),
+            Vector::new(
+                (x_max - x_min) as _,
+                (y_max - y_min) as _,
+                (z_max - z_min) as _,
+            ),
+        );
+
+        let seed = seed.unwrap_or(0);
+        (0..n as u64)
+            .into_par_iter()
+            .filter_map(|index| {
+                let mut state = split_mix(seed ^ split_mix(index));
+                (0..SAMPLE_VOLUME_ATTEMPTS)
+                    .map(|_| {
+                        min + size
+                            * Vector::new(
+                                random_unit(&mut state),
+                                random_unit(&mut state),
+                                random_unit(&mut state),
+                            )
+                    })
+                    .find(|point| self.contains(point))
+            })
+            .collect()
     }
 
-    /// Performs Catmull-Clark subdivision.
+    /// Returns the distance from the centroid of the points to the nearest
+    /// face plane.
     ///
-    /// Each face is replaced with *n* quadralaterals based on edge midpoints
-    /// vertices and centroid edge midpoints are average of edge endpoints
-    /// and adjacent centroids original vertices replaced by weighted
-    /// average of original vertex, face centroids and edge midpoints.
-    pub fn catmull_clark_subdivide(&mut self, change_name: bool) -> &mut Self {
-        let new_face_vertices = self
-            .face_index
-            .par_iter()
+    /// For a polyhedron with an insphere this is its radius.
+    pub fn inradius(&self) -> Float {
+        let center = centroid(&self.points);
+        self.face_index
+            .iter()
             .map(|face| {
                 let face_points = index_as_points(face, &self.points);
-                (face.as_slice(), centroid_ref(&face_points))
+                let normal = newell_normal(&face_points).normalized();
+                (center - centroid_ref(&face_points)).dot(normal).abs()
             })
-            .collect::<Vec<_>>();
-
-        let edges = self.to_edges();
+            .fold(Float::INFINITY, Float::min)
+    }
 
-        let new_edge_vertices = edges
-            .par_iter()
+    /// Returns the distance from the centroid of the points to the nearest
+    /// edge.
+    ///
+    /// For a polyhedron with a midsphere this is its radius.
+    pub fn midradius(&self) -> Float {
+        let center = centroid(&self.points);
+        self.to_edges()
+            .iter()
             .map(|edge| {
-                let ep = index_as_points(edge, &self.points);
-                let af1 = face_with_edge(edge, &self.face_index);
-                let af2 = face_with_edge(&[edge[1], edge[0]], &self.face_index);
-                let fc1 = vertex_point(&af1, new_face_vertices.as_slice()).unwrap();
-                let fc2 = vertex_point(&af2, new_face_vertices.as_slice()).unwrap();
-                (edge, (*ep[0] + *ep[1] + *fc1 + *fc2) * 0.25)
+                let (a, b) = (self.points[edge[0] as usize], self.points[edge[1] as usize]);
+                let t = ((center - a).dot(b - a) / (b - a).mag_sq()).clamp(0.0, 1.0);
+                (center - (a + (b - a) * t)).mag()
             })
-            .collect::<Vec<_>>();
+            .fold(Float::INFINITY, Float::min)
+    }
 
-        let new_face_vertex_ids =
-            vertex_ids_ref_ref(new_face_vertices.as_slice(), self.points.len() as _);
-        let new_edge_vertex_ids = vertex_ids_edge_ref_ref(
-            new_edge_vertices.as_slice(),
-            (self.points.len() + new_face_vertices.len()) as _,
+    /// Returns the distance from the centroid of the points to the farthest
+    /// point.
+    ///
+    /// For a polyhedron with a circumsphere this is its radius.
+    pub fn circumradius(&self) -> Float {
+        let center = centroid(&self.points);
+        self.points
+            .iter()
+            .map(|point| (*point - center).mag())
+            .fold(0.0, Float::max)
+    }
+
+    /// Returns how far each face is from being planar: the largest distance
+    /// of one of its points from its best-fit plane, divided by its mean
+    /// edge length.
+    ///
+    /// The plane goes through the centroid of the face and is perpendicular
+    /// to its Newell normal. Planar faces have a value of zero. Faces with
+    /// large values should be [`planarize()`](Polyhedron::planarize())d or
+    /// [`triangulate()`](Polyhedron::triangulate())d before exporting to
+    /// formats that expect planar polygons.
+    pub fn face_planarity(&self) -> Vec<Float> {
+        self.face_index
+            .par_iter()
+            .map(|face| {
+                let face_points = index_as_points(face, &self.points);
+                let normal = newell_normal(&face_points).normalized();
+                let center = centroid_ref(&face_points);
+                let lengths = face_edges(face, &self.points);
+                let mean_length = lengths.iter().sum::<Float>() / lengths.len() as Float;
+
+                face_points
+                    .iter()
+                    .map(|point| (**point - center).dot(normal).abs())
+                    .fold(0.0, Float::max)
+                    / mean_length
+            })
+            .collect()
+    }
+
+    /// Returns how many points have each valence, i.e. number of edges
+    /// meeting at them.
+    ///
+    /// E.g. a cube has eight points of valence three, `{3: 8}`.
+    pub fn vertex_valence_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut valences = vec![0; self.points.len()];
+        self.to_edges().iter().for_each(|edge| {
+            valences[edge[0] as usize] += 1;
+            valences[edge[1] as usize] += 1;
+        });
+
+        let mut histogram = BTreeMap::new();
+        valences
+            .into_iter()
+            .for_each(|valence| *histogram.entry(valence).or_insert(0) += 1);
+        histogram
+    }
+
+    /// Returns how many faces have each arity, i.e. number of points.
+    ///
+    /// E.g. a truncated icosahedron has twelve pentagons and twenty
+    /// hexagons, `{5: 12, 6: 20}`.
+    pub fn face_arity_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        self.face_index
+            .iter()
+            .for_each(|face| *histogram.entry(face.len()).or_insert(0) += 1);
+        histogram
+    }
+
+    /// Returns the shortest, longest and mean edge length and their
+    /// standard deviation, or `None` if there are no edges.
+    pub fn edge_length_stats(&self) -> Option<EdgeLengthStats> {
+        let lengths = edge_lengths(&self.to_edges(), &self.points);
+        if lengths.is_empty() {
+            return None;
+        }
+
+        let mean = lengths.iter().sum::<Float>() / lengths.len() as Float;
+        let variance = lengths
+            .iter()
+            .map(|length| (length - mean) * (length - mean))
+            .sum::<Float>()
+            / lengths.len() as Float;
+
+        Some(EdgeLengthStats {
+            min: lengths.iter().cloned().fold(Float::INFINITY, Float::min),
+            max: lengths.iter().cloned().fold(0.0, Float::max),
+            mean,
+            std_dev: variance.sqrt(),
+        })
+    }
+
+    /// Returns the irregularity of each face: the ratio of its longest to
+    /// its shortest edge. Faces with edges of equal length have a value of
+    /// one.
+    pub fn face_irregularity(&self) -> Vec<Float> {
+        self.face_index
+            .par_iter()
+            .map(|face| face_irregularity(face, &self.points))
+            .collect()
+    }
+
+    /// Appends the points, faces and face sets of another polyhedron.
+    ///
+    /// The indices of the other polyhedron are offset so they keep referring
+    /// to the same points and faces. The meshes are not connected.
+    pub fn append(&mut self, other: &Polyhedron) -> &mut Self {
+        let points_len = self.points.len() as VertexKey;
+        let faces_len = self.face_index.len() as VertexKey;
+
+        self.face_set_index.extend(
+            other
+                .face_set_index
+                .iter()
+                .map(|face_set| face_set.iter().map(|face| face + faces_len).collect()),
+        );
+        self.face_index.extend(
+            other
+                .face_index
+                .iter()
+                .map(|face| face.iter().map(|vertex| vertex + points_len).collect()),
+        );
+        self.points.extend(&other.points);
+
+        self
+    }
+
+    /// Returns the compound of the polyhedron and its dual.
+    ///
+    /// The dual is scaled so both share the same midradius. For regular
+    /// polyhedra the edges of the two then cross at their midpoints. E.g.
+    /// the compound of a tetrahedron is the stella octangula.
+    ///
+    /// The faces of the polyhedron and those of the dual end up in two
+    /// separate face sets.
+    pub fn compound_with_dual(&self) -> Self {
+        let radius = mean_edge_midpoint_distance(&self.to_edges(), &self.points);
+
+        let mut dual = self.clone();
+        dual.dual(false);
+        let dual_radius = mean_edge_midpoint_distance(&dual.to_edges(), &dual.points);
+        dual.points
+            .par_iter_mut()
+            .for_each(|point| *point *= radius / dual_radius);
+        dual.face_set_index = vec![(0..dual.face_index.len() as VertexKey).collect()];
+
+        let mut compound = Self::from(
+            &format!("{}+d{}", self.name, self.name),
+            self.points.clone(),
+            self.face_index.clone(),
+            Some(vec![(0..self.face_index.len() as VertexKey).collect()]),
         );
+        compound.append(&dual);
+
+        compound
+    }
+
+    /// Returns the [Minkowski sum](https://en.wikipedia.org/wiki/Minkowski_addition)
+    /// of this and the `other` polyhedron – the convex hull of the sums of
+    /// all pairs of their points.
+    ///
+    /// Both polyhedra are assumed to be convex. As with
+    /// [`from_points_hull()`](Polyhedron::from_points_hull()), coplanar
+    /// triangles of the hull are merged into polygons.
+    pub fn minkowski(&self, other: &Polyhedron) -> Self {
+        let points: Points = self
+            .points
+            .iter()
+            .flat_map(|a| other.points.iter().map(move |b| *a + *b))
+            .collect();
+
+        let mut minkowski = Self::from_points_hull(&points);
+        minkowski.name = format!("{}⊕{}", self.name, other.name);
+
+        minkowski
+    }
+
+    /// Returns the union of this and the `other` polyhedron.
+    ///
+    /// The faces of the result are put into two face sets, by the polyhedron
+    /// they came from.
+    pub fn union(&self, other: &Polyhedron) -> Self {
+        let (face_index, points, face_set_index) = csg::boolean(self, other, csg::Operation::Union);
+        Self::from(
+            &format!("{}∪{}", self.name, other.name),
+            points,
+            face_index,
+            Some(face_set_index),
+        )
+    }
+
+    /// Returns the intersection of this and the `other` polyhedron.
+    ///
+    /// See [`union()`](Polyhedron::union()) for details.
+    pub fn intersection(&self, other: &Polyhedron) -> Self {
+        let (face_index, points, face_set_index) =
+            csg::boolean(self, other, csg::Operation::Intersection);
+        Self::from(
+            &format!("{}∩{}", self.name, other.name),
+            points,
+            face_index,
+            Some(face_set_index),
+        )
+    }
+
+    /// Returns this polyhedron with the `other` one carved out.
+    ///
+    /// See [`union()`](Polyhedron::union()) for details.
+    pub fn difference(&self, other: &Polyhedron) -> Self {
+        let (face_index, points, face_set_index) =
+            csg::boolean(self, other, csg::Operation::Difference);
+        Self::from(
+            &format!("{}∖{}", self.name, other.name),
+            points,
+            face_index,
+            Some(face_set_index),
+        )
+    }
+
+    /// Appends indices for newly added faces as a new FaceSet to the
+    /// FaceSetIndex.
+    fn append_new_face_set(&mut self, size: usize) {
+        self.face_set_index
+            .append(&mut vec![((self.face_index.len() as VertexKey)
+                ..((self.face_index.len() + size) as VertexKey))
+                .collect()]);
+    }
+
+    /// Returns the face sets of the dual, where the face of each vertex goes
+    /// into a set shared by all vertices touching the same face sets.
+    ///
+    /// Face set entries that are not valid face indices are ignored.
+    fn dual_face_sets(&self) -> Vec<FaceSet> {
+        if self.face_set_index.is_empty() {
+            return Vec::new();
+        }
+
+        let mut vertex_face_sets = vec![Vec::new(); self.points.len()];
+        self.face_set_index
+            .iter()
+            .enumerate()
+            .for_each(|(face_set_index, face_set)| {
+                face_set
+                    .iter()
+                    .filter_map(|face| self.face_index.get(*face as usize))
+                    .for_each(|face| {
+                        face.iter().for_each(|v| {
+                            vertex_face_sets[*v as usize].push(face_set_index);
+                        })
+                    })
+            });
+
+        let mut classes: Vec<Vec<usize>> = Vec::new();
+        let mut face_sets: Vec<FaceSet> = Vec::new();
+        vertex_face_sets
+            .into_iter()
+            .enumerate()
+            .for_each(|(v, mut class)| {
+                class.sort_unstable();
+                class.dedup();
+                match index_of(&class, &classes) {
+                    Some(i) => face_sets[i].push(v as VertexKey),
+                    None => {
+                        classes.push(class);
+                        face_sets.push(vec![v as VertexKey]);
+                    }
+                }
+            });
+
+        face_sets
+    }
+
+    /// Returns one face set per face arity, in ascending order of arity.
+    fn face_sets_by_arity(&self) -> Vec<FaceSet> {
+        let mut arities: Vec<usize> = self.face_index.iter().map(|face| face.len()).collect();
+        arities.sort_unstable();
+        arities.dedup();
+        arities
+            .iter()
+            .map(|arity| {
+                self.face_index
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, face)| *arity == face.len())
+                    .map(|(index, _)| index as VertexKey)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the index of the first face set each face is in or, for faces
+    /// in no face set, the number of face sets.
+    ///
+    /// Face set entries that are not valid face indices are ignored.
+    fn first_face_sets(&self) -> Vec<usize> {
+        let mut face_sets = vec![self.face_set_index.len(); self.face_index.len()];
+        self.face_set_index
+            .iter()
+            .enumerate()
+            .rev()
+            .for_each(|(set, face_set)| {
+                face_set.iter().for_each(|face| {
+                    if let Some(face_set) = face_sets.get_mut(*face as usize) {
+                        *face_set = set;
+                    }
+                })
+            });
+        face_sets
+    }
+
+    /// Returns the color of each face: the color of the first face set it is
+    /// in, cycling through the `palette`, or white if it is in no face set
+    /// or the `palette` is empty.
+    #[cfg(any(
+        feature = "nsi",
+        feature = "off",
+        feature = "ply",
+        feature = "svg",
+        feature = "x3d"
+    ))]
+    fn face_set_colors(&self, palette: &[[u8; 3]]) -> Vec<[u8; 3]> {
+        self.first_face_sets()
+            .iter()
+            .map(
+                |set| match *set < self.face_set_index.len() && !palette.is_empty() {
+                    true => palette[set % palette.len()],
+                    false => [255; 3],
+                },
+            )
+            .collect()
+    }
+
+    /// Returns outward facing normals: one per face with
+    /// [`NormalType::Flat`], one per point with [`NormalType::Smooth`].
+    fn normals(&self, normal_type: NormalType) -> Normals {
+        // Outward facing, area-weighted face normals.
+        let face_normals: Normals = self
+            .face_index
+            .par_iter()
+            .map(|face| -newell_normal(&index_as_points(face, &self.points)))
+            .collect();
+
+        match normal_type {
+            NormalType::Flat => face_normals
+                .iter()
+                .map(|normal| normal.normalized())
+                .collect(),
+            NormalType::Smooth => {
+                let mut normals = vec![Normal::zero(); self.points.len()];
+                self.face_index
+                    .iter()
+                    .zip(face_normals.iter())
+                    .for_each(|(face, normal)| {
+                        face.iter().for_each(|v| normals[*v as usize] += *normal)
+                    });
+                normals.par_iter_mut().for_each(|normal| {
+                    if EPSILON < normal.mag_sq() {
+                        normal.normalize();
+                    }
+                });
+                normals
+            }
+        }
+    }
+
+    /// Returns the indices of the faces each edge belongs to.
+    #[cfg(any(feature = "nsi", feature = "petgraph", feature = "svg"))]
+    fn edge_faces(&self) -> HashMap<Edge, Vec<usize>> {
+        let mut edge_faces: HashMap<Edge, Vec<usize>> = HashMap::new();
+        for (index, face) in self.face_index.iter().enumerate() {
+            for (a, b) in face.iter().circular_tuple_windows::<(_, _)>() {
+                edge_faces
+                    .entry(distinct_edge(&[*a, *b]))
+                    .or_default()
+                    .push(index);
+            }
+        }
+        edge_faces
+    }
+
+    /// Returns for each face if it is selected by `face_selector`. If there
+    /// is no selector all faces are selected.
+    fn selected_faces(&self, face_selector: Option<&FaceSelector>) -> Vec<bool> {
+        match face_selector {
+            Some(face_selector) => {
+                face_selector.selection(&self.face_index, &self.points, &self.face_set_index)
+            }
+            None => vec![true; self.face_index.len()],
+        }
+    }
+
+    /// Returns for each vertex if it is selected by `vertex_selector`, which
+    /// is applied to the faces of the dual. If there is no selector all
+    /// vertices are selected.
+    fn selected_vertices(&self, vertex_selector: Option<&FaceSelector>) -> Vec<bool> {
+        match vertex_selector {
+            Some(vertex_selector) => vertex_selector.selection(
+                &points_to_faces(&self.points, &self.face_index),
+                &face_centers(&self.face_index, &self.points),
+                &self.dual_face_sets(),
+            ),
+            None => vec![true; self.points.len()],
+        }
+    }
+
+    /// Removes points not referenced by any face and updates the face
+    /// index accordingly.
+    fn remove_unused_points(&mut self) {
+        let mut used = vec![false; self.points.len()];
+        self.face_index
+            .iter()
+            .flatten()
+            .for_each(|v| used[*v as usize] = true);
+
+        let mut new_index = vec![0; self.points.len()];
+        let mut points = Vec::with_capacity(self.points.len());
+        for (v, point) in self.points.iter().enumerate() {
+            if used[v] {
+                new_index[v] = points.len() as VertexKey;
+                points.push(*point);
+            }
+        }
+
+        self.face_index.par_iter_mut().for_each(|face| {
+            face.iter_mut().for_each(|v| *v = new_index[*v as usize]);
+        });
+        self.points = points;
+    }
+
+    /// Merges points that are closer to each other than `epsilon` (default:
+    /// `1e-5`).
+    ///
+    /// Edges that collapse in the process are removed from their faces and
+    /// faces left with less than three points are removed.
+    pub fn merge_points(&mut self, epsilon: Option<Float>) -> &mut Self {
+        let epsilon = epsilon.unwrap_or(1e-5);
+        let cell = |point: &Point| {
+            [
+                (point.x / epsilon).floor() as i64,
+                (point.y / epsilon).floor() as i64,
+                (point.z / epsilon).floor() as i64,
+            ]
+        };
+
+        // Points are looked up in the neighboring cells of a grid with a
+        // spacing of `epsilon`.
+        let mut grid: HashMap<[i64; 3], Vec<VertexKey>> = HashMap::new();
+        let mut points = Points::with_capacity(self.points.len());
+        let new_index: Vec<VertexKey> = self
+            .points
+            .iter()
+            .map(|point| {
+                let [x, y, z] = cell(point);
+                let existing = (-1..=1)
+                    .cartesian_product(-1..=1)
+                    .cartesian_product(-1..=1)
+                    .filter_map(|((dx, dy), dz)| grid.get(&[x + dx, y + dy, z + dz]))
+                    .flatten()
+                    .find(|v| (points[**v as usize] - *point).mag() <= epsilon)
+                    .cloned();
+                existing.unwrap_or_else(|| {
+                    let v = points.len() as VertexKey;
+                    grid.entry([x, y, z]).or_default().push(v);
+                    points.push(*point);
+                    v
+                })
+            })
+            .collect();
+
+        let mut face_map = Vec::with_capacity(self.face_index.len());
+        let mut face_index = Faces::with_capacity(self.face_index.len());
+        self.face_index.iter().for_each(|face| {
+            let mut face: Face = face
+                .iter()
+                .map(|v| new_index[*v as usize])
+                .dedup()
+                .collect();
+            while 1 < face.len() && face.first() == face.last() {
+                face.pop();
+            }
+
+            if 3 <= face.len() {
+                face_map.push(Some(face_index.len() as VertexKey));
+                face_index.push(face);
+            } else {
+                face_map.push(None);
+            }
+        });
+
+        self.face_set_index = self
+            .face_set_index
+            .iter()
+            .map(|face_set| {
+                face_set
+                    .iter()
+                    .filter_map(|face| face_map[*face as usize])
+                    .collect()
+            })
+            .collect();
+        self.face_index = face_index;
+        self.points = points;
+
+        self
+    }
+
+    /// Removes the selected faces, leaving an open mesh. Points no longer
+    /// used by any face are removed as well.
+    pub fn remove_faces(&mut self, face_selector: FaceSelector) -> &mut Self {
+        let selected = self.selected_faces(Some(&face_selector));
+        self.remove_selected_faces(&selected);
+        self.remove_unused_points();
+
+        self
+    }
+
+    /// Removes the faces flagged in `selected` and updates the face sets
+    /// accordingly. Points are kept.
+    fn remove_selected_faces(&mut self, selected: &[bool]) {
+        let mut face_map = Vec::with_capacity(self.face_index.len());
+        let mut face_index = Vec::with_capacity(self.face_index.len());
+        self.face_index
+            .iter()
+            .zip(selected)
+            .for_each(|(face, selected)| {
+                if *selected {
+                    face_map.push(None);
+                } else {
+                    face_map.push(Some(face_index.len() as VertexKey));
+                    face_index.push(face.clone());
+                }
+            });
+
+        self.face_set_index = self
+            .face_set_index
+            .iter()
+            .map(|face_set| {
+                face_set
+                    .iter()
+                    .filter_map(|face| face_map[*face as usize])
+                    .collect::<FaceSet>()
+            })
+            .filter(|face_set| !face_set.is_empty())
+            .collect();
+        self.face_index = face_index;
+    }
+
+    /// Merges adjacent faces whose normals differ by less than `tolerance`
+    /// radians (default: 0.001) and that lie in the same plane. The edges
+    /// between them are removed.
+    ///
+    /// Groups of faces whose outline would have holes are left alone. Points
+    /// no longer used by any face are removed.
+    pub fn merge_coplanar_faces(&mut self, tolerance: Option<Float>) -> &mut Self {
+        let min_cos = tolerance.unwrap_or(1e-3).cos();
+        let distance_tolerance = 1e-4 * max_magnitude(&self.points).max(1.0);
+
+        let planes: Vec<(Normal, Float)> = self
+            .face_index
+            .par_iter()
+            .map(|face| {
+                let face_points = index_as_points(face, &self.points);
+                let normal = newell_normal(&face_points).normalized();
+                (normal, normal.dot(centroid_ref(&face_points)))
+            })
+            .collect();
+
+        let edge_faces: HashMap<Edge, usize> = self
+            .face_index
+            .iter()
+            .enumerate()
+            .flat_map(|(index, face)| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .map(move |(a, b)| ([*a, *b], index))
+            })
+            .collect();
+
+        fn find(parent: &mut [usize], mut f: usize) -> usize {
+            while parent[f] != f {
+                parent[f] = parent[parent[f]];
+                f = parent[f];
+            }
+            f
+        }
+
+        let mut parent: Vec<usize> = (0..self.face_index.len()).collect();
+        edge_faces.iter().for_each(|(edge, face)| {
+            if let Some(other) = edge_faces.get(&[edge[1], edge[0]]) {
+                let (normal, distance) = planes[*face];
+                let (other_normal, other_distance) = planes[*other];
+                if min_cos <= normal.dot(other_normal)
+                    && (distance - other_distance).abs() < distance_tolerance
+                {
+                    let (root, other_root) = (find(&mut parent, *face), find(&mut parent, *other));
+                    parent[other_root.max(root)] = other_root.min(root);
+                }
+            }
+        });
+
+        let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+        (0..self.face_index.len()).for_each(|face| {
+            let root = find(&mut parent, face);
+            groups.entry(root).or_default().push(face);
+        });
+
+        // Traces the outline of a group of faces. Returns `None` if the
+        // outline isn't a single loop.
+        let outline = |group: &[usize]| -> Option<Face> {
+            let edges: HashSet<Edge> = group
+                .iter()
+                .flat_map(|face| {
+                    self.face_index[*face]
+                        .iter()
+                        .circular_tuple_windows::<(_, _)>()
+                        .map(|(a, b)| [*a, *b])
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            let boundary: Vec<&Edge> = edges
+                .iter()
+                .filter(|edge| !edges.contains(&[edge[1], edge[0]]))
+                .collect();
+            let next: HashMap<VertexKey, VertexKey> =
+                boundary.iter().map(|edge| (edge[0], edge[1])).collect();
+            if next.len() != boundary.len() {
+                return None;
+            }
+
+            let start = boundary.iter().map(|edge| edge[0]).min()?;
+            let mut face = vec![start];
+            let mut v = next[&start];
+            while v != start && face.len() <= boundary.len() {
+                face.push(v);
+                v = *next.get(&v)?;
+            }
+            if face.len() == boundary.len() {
+                Some(face)
+            } else {
+                None
+            }
+        };
+
+        // A merged face takes the place of the first face of its group.
+        let mut merged: Vec<Option<Face>> = self.face_index.iter().cloned().map(Some).collect();
+        groups
+            .values()
+            .filter(|group| 1 < group.len())
+            .for_each(|group| {
+                if let Some(face) = outline(group) {
+                    merged[group[0]] = Some(face);
+                    group[1..].iter().for_each(|face| merged[*face] = None);
+                }
+            });
+
+        let mut face_map = Vec::with_capacity(merged.len());
+        let mut face_index = Vec::with_capacity(merged.len());
+        merged
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, face)| match face {
+                Some(face) => {
+                    face_map.push(face_index.len() as VertexKey);
+                    face_index.push(face);
+                }
+                None => face_map.push(face_map[find(&mut parent, index)]),
+            });
+
+        self.face_set_index = self
+            .face_set_index
+            .iter()
+            .map(|face_set| {
+                face_set
+                    .iter()
+                    .map(|face| face_map[*face as usize])
+                    .unique()
+                    .collect::<FaceSet>()
+            })
+            .collect();
+        self.face_index = face_index;
+
+        // Points inside merged faces are no longer used.
+        self.remove_unused_points();
+
+        self
+    }
+
+    /// Clips the polyhedron against a plane. Everything in front of the
+    /// plane, i.e. on the side `plane_normal` points to, is removed.
+    ///
+    /// Edges crossing the plane are split with a new point. If `cap` is
+    /// `true` the holes in the cross-section are closed with new faces that
+    /// are added as a new face set.
+    pub fn slice(&mut self, plane_point: Point, plane_normal: Vector, cap: bool) -> &mut Self {
+        let plane_normal = plane_normal.normalized();
+        let distances: Vec<Float> = self
+            .points
+            .par_iter()
+            .map(|point| {
+                let distance = (*point - plane_point).dot(plane_normal);
+                if distance.abs() < 1e-6 {
+                    0.0
+                } else {
+                    distance
+                }
+            })
+            .collect();
+
+        let cut_points: Vec<(Face, Point)> = self
+            .to_edges()
+            .par_iter()
+            .filter(|edge| distances[edge[0] as usize] * distances[edge[1] as usize] < 0.0)
+            .map(|edge| {
+                let (a, b) = (edge[0] as usize, edge[1] as usize);
+                let t = distances[a] / (distances[a] - distances[b]);
+                (
+                    edge.to_vec(),
+                    self.points[a] + t * (self.points[b] - self.points[a]),
+                )
+            })
+            .collect();
+
+        let new_ids = vertex_ids_ref(&cut_points, self.points_len() as VertexKey);
+
+        let mut on_plane: Vec<bool> = distances.iter().map(|d| 0.0 == *d).collect();
+        on_plane.resize(on_plane.len() + cut_points.len(), true);
+
+        let clipped_faces: Vec<Face> = self
+            .face_index
+            .par_iter()
+            .map(|face| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .flat_map(|(a, b)| {
+                        let kept = if distances[*a as usize] <= 0.0 {
+                            Some(*a)
+                        } else {
+                            None
+                        };
+                        let cut = vertex(&distinct_edge(&[*a, *b]), &new_ids);
+                        kept.into_iter().chain(cut)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut face_map = Vec::with_capacity(self.face_index.len());
+        let mut face_index = Vec::with_capacity(self.face_index.len());
+        clipped_faces.into_iter().for_each(|face| {
+            if 2 < face.len() {
+                face_map.push(Some(face_index.len() as VertexKey));
+                face_index.push(face);
+            } else {
+                face_map.push(None);
+            }
+        });
+
+        self.face_set_index = self
+            .face_set_index
+            .iter()
+            .map(|face_set| {
+                face_set
+                    .iter()
+                    .filter_map(|face| face_map[*face as usize])
+                    .collect::<FaceSet>()
+            })
+            .filter(|face_set| !face_set.is_empty())
+            .collect();
+        self.face_index = face_index;
+        self.points.extend(vertex_values(&cut_points));
+
+        if cap {
+            let edges: HashSet<Edge> = self
+                .face_index
+                .iter()
+                .flat_map(|face| {
+                    face.iter()
+                        .circular_tuple_windows::<(_, _)>()
+                        .map(|(a, b)| [*a, *b])
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+
+            // Boundary edges on the plane, reversed so the caps face
+            // outwards.
+            let mut next: HashMap<VertexKey, VertexKey> = edges
+                .iter()
+                .filter(|edge| {
+                    !edges.contains(&[edge[1], edge[0]])
+                        && on_plane[edge[0] as usize]
+                        && on_plane[edge[1] as usize]
+                })
+                .map(|edge| (edge[1], edge[0]))
+                .collect();
+
+            let mut caps = Vec::new();
+            while let Some(&start) = next.keys().min() {
+                let mut cap_face = vec![start];
+                let mut v = next.remove(&start).unwrap();
+                while v != start {
+                    cap_face.push(v);
+                    match next.remove(&v) {
+                        Some(w) => v = w,
+                        None => break,
+                    }
+                }
+                if 2 < cap_face.len() {
+                    caps.push(cap_face);
+                }
+            }
+
+            self.append_new_face_set(caps.len());
+            self.face_index.extend(caps);
+        }
+
+        self.remove_unused_points();
+
+        self
+    }
+
+    /// Smoothes the polyhedron by moving each point towards the average of
+    /// its neighbors (the umbrella operator) by `lambda` (default: 0.5).
+    ///
+    /// If `constrain_to_sphere` is `true` points are projected back onto the
+    /// unit sphere after each iteration.
+    pub fn relax(
+        &mut self,
+        iterations: usize,
+        lambda: Option<Float>,
+        constrain_to_sphere: bool,
+    ) -> &mut Self {
+        let lambda = lambda.unwrap_or(0.5);
+
+        let mut neighbors = vec![Vec::new(); self.points.len()];
+        self.to_edges().iter().for_each(|edge| {
+            neighbors[edge[0] as usize].push(edge[1]);
+            neighbors[edge[1] as usize].push(edge[0]);
+        });
+
+        for _ in 0..iterations {
+            let points = self.points.clone();
+            self.points
+                .par_iter_mut()
+                .zip(neighbors.par_iter())
+                .filter(|(_, neighbors)| !neighbors.is_empty())
+                .for_each(|(point, neighbors)| {
+                    let average = neighbors
+                        .iter()
+                        .fold(Point::zero(), |sum, v| sum + points[*v as usize])
+                        / neighbors.len() as Float;
+                    *point += lambda * (average - *point);
+                });
+
+            if constrain_to_sphere {
+                project_on_sphere(&mut self.points, 1., 1.);
+            }
+        }
+
+        self
+    }
+
+    /// Iteratively moves the points of each face towards the plane through
+    /// the face's centroid, perpendicular to its average normal.
+    ///
+    /// Stops once no point is further than `tolerance` (default: 1e-4) from
+    /// the planes of its faces or after `max_iterations` (default: 100).
+    ///
+    /// This is cheaper than [`canonicalize()`](Polyhedron::canonicalize())
+    /// as it does not touch the edges.
+    pub fn planarize(
+        &mut self,
+        tolerance: Option<Float>,
+        max_iterations: Option<usize>,
+    ) -> &mut Self {
+        let tolerance = tolerance.unwrap_or(1e-4);
+
+        for _ in 0..max_iterations.unwrap_or(100) {
+            if planarize_step(&self.face_index, &mut self.points) < tolerance {
+                break;
+            }
+        }
+
+        self
+    }
+
+    /// Moves the points towards the canonical form of the polyhedron using
+    /// George W. Hart's algorithm.
+    ///
+    /// In canonical form all edges are tangent to the unit sphere, the
+    /// center of the tangent points is the origin and all faces are planar.
+    /// Each iteration moves the points a bit closer to meeting these
+    /// constraints.
+    pub fn canonicalize(&mut self, iterations: usize) -> &mut Self {
+        let edges = self.to_edges();
+
+        let mut valences = vec![0 as Float; self.points.len()];
+        edges
+            .iter()
+            .flatten()
+            .for_each(|v| valences[*v as usize] += 1.);
+
+        for _ in 0..iterations {
+            // Push edges towards being tangent to the unit sphere.
+            let mut adjustments = vec![Vector::zero(); self.points.len()];
+            let tangent_points = edges
+                .iter()
+                .map(|edge| {
+                    let tangent_point = edge_tangent_point(
+                        &self.points[edge[0] as usize],
+                        &self.points[edge[1] as usize],
+                    );
+                    let adjustment = 0.5 * (1. - tangent_point.mag()) * tangent_point;
+                    adjustments[edge[0] as usize] += adjustment;
+                    adjustments[edge[1] as usize] += adjustment;
+                    tangent_point
+                })
+                .collect::<Points>();
+
+            // Recenter on the center of the tangent points.
+            let center = tangent_points
+                .iter()
+                .fold(Point::zero(), |sum, point| sum + *point)
+                / tangent_points.len() as Float;
+
+            self.points
+                .par_iter_mut()
+                .zip(adjustments.par_iter().zip(valences.par_iter()))
+                .for_each(|(point, (adjustment, valence))| {
+                    *point += *adjustment / *valence - center
+                });
+
+            // Move points towards the planes of their faces.
+            planarize_step(&self.face_index, &mut self.points);
+        }
+
+        self
+    }
+
+    /// Creates vertices with valence (aka degree) four.
+
+    /// It is also called [rectification](https://en.wikipedia.org/wiki/Rectification_(geometry)),
+    /// or the  [medial graph](https://en.wikipedia.org/wiki/Medial_graph) in graph theory.
+    #[inline]
+    pub fn ambo(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
+        let ratio_ = match ratio {
+            Some(r) => r.clamp(0.0, 1.0),
+            None => 1. / 2.,
+        };
+
+        let edges = self.to_edges();
+
+        let points: Vec<(&Edge, Point)> = edges
+            .par_iter()
+            .map(|edge| {
+                let edge_points = index_as_points(edge, &self.points);
+                (
+                    edge,
+                    ratio_ * *edge_points[0] + (1.0 - ratio_) * *edge_points[1],
+                )
+            })
+            .collect();
+
+        let new_ids = vertex_ids_edge_ref_ref(&points, 0);
+
+        let face_index: Faces = self
+            .face_index
+            .par_iter()
+            .map(|face| {
+                let edges = distinct_face_edges(face);
+                let result = edges
+                    .iter()
+                    .filter_map(|edge| vertex_edge(edge, &new_ids))
+                    .collect::<Vec<_>>();
+                result
+            })
+            .chain(
+                self.points
+                    // Each old vertex creates a new face ...
+                    .par_iter()
+                    .enumerate()
+                    .map(|polygon_vertex| {
+                        let vertex_number = polygon_vertex.0 as VertexKey;
+                        ordered_vertex_edges(
+                            vertex_number,
+                            &vertex_faces(vertex_number, &self.face_index),
+                        )
+                        .iter()
+                        .map(|ve| vertex_edge(&distinct_edge(ve), &new_ids).unwrap() as VertexKey)
+                        .collect::<Vec<_>>()
+                    })
+                    // Corners of open meshes with a single face.
+                    .filter(|face| 2 < face.len()),
+            )
+            .collect();
+
+        // Only the faces of the old vertices are new.
+        self.append_new_face_set(face_index.len() - self.face_index.len());
+
+        self.face_index = face_index;
+        self.points = vertex_values(&points);
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
+            }
+            self.name = format!("a{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Applies [`dual()`](Polyhedron::dual()), [`kis()`](Polyhedron::kis()),
+    /// [`dual()`](Polyhedron::dual()) and [`ambo()`](Polyhedron::ambo()), in
+    /// that order.
+    /// # Arguments
+    /// * `vertex_selector` – Only selected vertices will be affected. See
+    ///   [`FaceSelector`].
+    pub fn bevel(
+        &mut self,
+        ratio: Option<Float>,
+        height: Option<Float>,
+        vertex_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        self.dual(false);
+        self.kis(height, vertex_selector.clone(), false);
+        self.dual(false);
+        self.ambo(ratio, false);
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
+            }
+            if let Some(height) = height {
+                write!(&mut params, ",{:.2}", height).unwrap();
+            } else {
+                write!(&mut params, ",").unwrap();
+            }
+            if let Some(vertex_selector) = &vertex_selector {
+                write!(&mut params, ",{}", vertex_selector).unwrap();
+            }
+            params = params.trim_end_matches(',').to_string();
+            self.name = format!("b{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Replaces each edge with a bowtie – a pair of quadrilaterals that
+    /// meet at the edge's midpoint – and each face with a smaller copy of
+    /// itself, rotated so its vertices face the edges. The gaps at the
+    /// corners are filled with triangles.
+    /// # Arguments
+    /// * `ratio` – How far the inner face reaches towards the edges. Will be
+    ///   clamped to `[0, 1]`. Default value is `0.5`.
+    pub fn bowtie(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
+        let ratio_ = match ratio {
+            Some(r) => r.clamp(0.0, 1.0),
+            None => 1. / 2.,
+        };
+
+        let edge_points: Vec<(Face, Point)> = self
+            .to_edges()
+            .par_iter()
+            .map(|edge| {
+                (
+                    edge.to_vec(),
+                    (self.points[edge[0] as usize] + self.points[edge[1] as usize]) * 0.5,
+                )
+            })
+            .collect();
+
+        // One inner point per face and edge, keyed by the face and the
+        // start of the edge.
+        let face_points: Vec<(Face, Point)> = self
+            .face_index
+            .par_iter()
+            .flat_map(|face| {
+                let face_points = index_as_points(face, &self.points);
+                let centroid = centroid_ref(&face_points);
+                face.iter()
+                    .zip(face_points.iter().circular_tuple_windows::<(_, _)>())
+                    .map(|(a, (point_a, point_b))| {
+                        let midpoint = (**point_a + **point_b) * 0.5;
+                        (
+                            extend![..face, *a],
+                            centroid + ratio_ * (midpoint - centroid),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let new_points: Vec<(Face, Point)> = edge_points.into_iter().chain(face_points).collect();
+        let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
+
+        let inner = |face: &FaceSlice, a: VertexKey| -> VertexKey {
+            vertex(&extend![..face.to_vec(), a], &new_ids).unwrap()
+        };
+
+        let inner_faces: Faces = self
+            .face_index
+            .par_iter()
+            .map(|face| face.iter().map(|a| inner(face, *a)).collect())
+            .collect();
+
+        let corner_faces: Faces = self
+            .face_index
+            .par_iter()
+            .flat_map(|face| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .map(|(a, b)| vec![*b, inner(face, *b), inner(face, *a)])
+                    .collect::<Faces>()
+            })
+            .collect();
+
+        // Each face emits the half of the bowtie at the start of each of
+        // its edges.
+        let bowtie_faces: Faces = self
+            .face_index
+            .par_iter()
+            .flat_map(|face| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .map(|(a, b)| {
+                        let midpoint = vertex(&distinct_edge(&[*a, *b]), &new_ids).unwrap();
+                        let opposite_face = face_with_edge(&[*b, *a], &self.face_index);
+                        if opposite_face.is_empty() {
+                            vec![*a, midpoint, inner(face, *a)]
+                        } else {
+                            vec![*a, inner(&opposite_face, *b), midpoint, inner(face, *a)]
+                        }
+                    })
+                    .collect::<Faces>()
+            })
+            .collect();
+
+        self.face_index = inner_faces;
+        self.append_new_face_set(corner_faces.len());
+        self.face_index.extend(corner_faces);
+        self.append_new_face_set(bowtie_faces.len());
+        self.face_index.extend(bowtie_faces);
+
+        self.points.extend(vertex_values(&new_points));
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
+            }
+            self.name = format!("B{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Performs one level of Catmull-Clark subdivision.
+    ///
+    /// Each face is replaced with *n* quadralaterals based on edge midpoints
+    /// vertices and centroid edge midpoints are average of edge endpoints
+    /// and adjacent centroids original vertices replaced by weighted
+    /// average of original vertex, face centroids and edge midpoints.
+    ///
+    /// This is the same as `catmull_clark(1, None, change_name)`.
+    pub fn catmull_clark_subdivide(&mut self, change_name: bool) -> &mut Self {
+        self.catmull_clark(1, None, change_name)
+    }
+
+    /// Performs `levels` of Catmull-Clark subdivision.
+    ///
+    /// All original edges are creased with `crease_hardness` (default: 0,
+    /// i.e. smooth), using the same semi-sharp crease semantics as
+    /// [`to_nsi()`](Polyhedron::to_nsi()). An edge with hardness *h* stays
+    /// sharp for *h* levels and the fractional part is blended with the
+    /// smooth result. Boundary edges of open meshes are always sharp.
+    pub fn catmull_clark(
+        &mut self,
+        levels: usize,
+        crease_hardness: Option<Float>,
+        change_name: bool,
+    ) -> &mut Self {
+        let crease_hardness_ = crease_hardness.unwrap_or(0.);
+
+        let mut creases = if 0. < crease_hardness_ {
+            self.to_edges()
+                .into_iter()
+                .map(|edge| (edge, crease_hardness_))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        for _ in 0..levels {
+            creases = self.catmull_clark_step(&creases);
+        }
+
+        if change_name && 0 < levels {
+            let mut params = String::new();
+            if 1 != levels {
+                write!(&mut params, "{}", levels).unwrap();
+            }
+            if let Some(crease_hardness) = crease_hardness {
+                write!(&mut params, ",{:.2}", crease_hardness).unwrap();
+            }
+            self.name = format!("v{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Performs one level of Catmull-Clark subdivision honoring the given
+    /// creases. Returns the creases of the resulting mesh.
+    fn catmull_clark_step(&mut self, creases: &[(Edge, Float)]) -> Vec<(Edge, Float)> {
+        let crease_hardness = |edge: &Edge| -> Float {
+            creases
+                .iter()
+                .find(|crease| crease.0 == *edge)
+                .map_or(0., |crease| crease.1)
+        };
+
+        let new_face_vertices = self
+            .face_index
+            .par_iter()
+            .map(|face| {
+                let face_points = index_as_points(face, &self.points);
+                (face.as_slice(), centroid_ref(&face_points))
+            })
+            .collect::<Vec<_>>();
+
+        let edges = self.to_edges();
+
+        let new_edge_vertices = edges
+            .par_iter()
+            .map(|edge| {
+                let ep = index_as_points(edge, &self.points);
+                let midpoint = (*ep[0] + *ep[1]) * 0.5;
+                let af1 = face_with_edge(edge, &self.face_index);
+                let af2 = face_with_edge(&[edge[1], edge[0]], &self.face_index);
+                let hardness = crease_hardness(edge);
+                match (
+                    vertex_point(&af1, new_face_vertices.as_slice()),
+                    vertex_point(&af2, new_face_vertices.as_slice()),
+                ) {
+                    (Some(fc1), Some(fc2)) if hardness < 1. => {
+                        let smooth = (*ep[0] + *ep[1] + *fc1 + *fc2) * 0.25;
+                        (edge, smooth + hardness * (midpoint - smooth))
+                    }
+                    // Sharp and boundary edges.
+                    _ => (edge, midpoint),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let new_face_vertex_ids =
+            vertex_ids_ref_ref(new_face_vertices.as_slice(), self.points.len() as _);
+        let new_edge_vertex_ids = vertex_ids_edge_ref_ref(
+            new_edge_vertices.as_slice(),
+            (self.points.len() + new_face_vertices.len()) as _,
+        );
+
+        let new_face_index = self
+            .face_index
+            .par_iter()
+            .flat_map(|face| {
+                let centroid = vertex(face, &new_face_vertex_ids).unwrap();
+
+                face.iter()
+                    .circular_tuple_windows::<(_, _, _)>()
+                    .map(|triplet| {
+                        let mid1 = vertex_edge(
+                            &distinct_edge(&[*triplet.0, *triplet.1]),
+                            new_edge_vertex_ids.as_slice(),
+                        )
+                        .unwrap();
+                        let mid2 = vertex_edge(
+                            &distinct_edge(&[*triplet.1, *triplet.2]),
+                            new_edge_vertex_ids.as_slice(),
+                        )
+                        .unwrap();
+                        vec![centroid, mid1, *triplet.1, mid2]
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let new_points = self
+            .points
+            .par_iter()
+            .enumerate()
+            .map(|point| {
+                let i = point.0 as u32;
+                let v = point.1;
+                let vertex_edges = vertex_edges(i, &edges);
+
+                // Edges that are creased or on the boundary.
+                let sharp_edges = vertex_edges
+                    .iter()
+                    .filter_map(|edge| {
+                        let hardness = if face_with_edge(edge, &self.face_index).is_empty()
+                            || face_with_edge(&[edge[1], edge[0]], &self.face_index).is_empty()
+                        {
+                            1.
+                        } else {
+                            crease_hardness(edge)
+                        };
+                        if 0. < hardness {
+                            Some((if edge[0] == i { edge[1] } else { edge[0] }, hardness))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                let vertex_faces = vertex_faces(i, &self.face_index)
+                    .iter()
+                    .map(|face| vertex_point(face, new_face_vertices.as_slice()).unwrap())
+                    .collect::<Vec<_>>();
+                let n = vertex_faces.len() as Float;
+                let f = centroid_ref(&vertex_faces);
+                let r = vertex_edges.iter().fold(Point::zero(), |sum, edge| {
+                    sum + (self.points[edge[0] as usize] + self.points[edge[1] as usize]) * 0.5
+                }) / vertex_edges.len() as Float;
+                let smooth = (f + 2.0 * r + (n - 3.0) * *v) / n;
+
+                let sharp = match sharp_edges.len() {
+                    0 | 1 => return smooth,
+                    // Crease rule.
+                    2 => {
+                        (6.0 * *v
+                            + self.points[sharp_edges[0].0 as usize]
+                            + self.points[sharp_edges[1].0 as usize])
+                            / 8.0
+                    }
+                    // Corner rule.
+                    _ => *v,
+                };
+
+                let hardness = sharp_edges.iter().map(|edge| edge.1).sum::<Float>()
+                    / sharp_edges.len() as Float;
+                if 1. <= hardness {
+                    sharp
+                } else {
+                    smooth + hardness * (sharp - smooth)
+                }
+            })
+            .chain(vertex_values(new_face_vertices.as_slice()))
+            .chain(vertex_values(new_edge_vertices.as_slice()))
+            .collect::<Points>();
+
+        // Both halves of a creased edge inherit its hardness, minus one.
+        let new_creases = creases
+            .iter()
+            .filter(|crease| 1. < crease.1)
+            .flat_map(|crease| {
+                let mid = vertex_edge(&crease.0, new_edge_vertex_ids.as_slice()).unwrap();
+                vec![
+                    (distinct_edge(&[crease.0[0], mid]), crease.1 - 1.),
+                    (distinct_edge(&[mid, crease.0[1]]), crease.1 - 1.),
+                ]
+            })
+            .collect();
+
+        self.points = new_points;
+        self.face_index = new_face_index;
+
+        new_creases
+    }
+
+    /// Replaces each edge with a hexagon. Original faces are shrunk and
+    /// retained.
+    ///
+    /// The original points are moved along their normal onto the planes of
+    /// their hexagons. On polyhedra where all vertices and edges are alike,
+    /// e.g. the Platonic solids, the hexagons are planar.
+    /// # Arguments
+    /// * `ratio` – The amount the original faces are shrunk towards their
+    ///   centroid. Will be clamped to `[0, 1]`. Default value is `0.5`.
+    ///   Ignored if `order` is above `1`. The result is scaled to keep the
+    ///   original circumradius.
+    /// * `order` – The number of hexagon rings inserted in one pass. Default
+    ///   value is `1`.
+    ///
+    ///   Orders above one are the Goldberg–Coxeter operation
+    ///   *GC(order + 1, 0)*, see
+    ///   [`goldberg_coxeter()`](Polyhedron::goldberg_coxeter()), scaled to
+    ///   keep the original circumradius. Open meshes are left unchanged by
+    ///   these.
+    /// * `face_selector` – Only selected faces are shrunk. Edges between two
+    ///   unselected faces are kept. Ignored if `order` is above `1`.
+    pub fn chamfer(
+        &mut self,
+        ratio: Option<Float>,
+        order: Option<usize>,
+        face_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        let order_ = order.unwrap_or(1).max(1);
+
+        if 1 < order_ {
+            let radius = max_magnitude(&self.points);
+
+            self.goldberg_coxeter(order_ + 1, 0, false);
+
+            // The dual passes pull the points towards the center. Restore the
+            // circumradius so chamfer composes with other operators.
+            let new_radius = max_magnitude(&self.points);
+            if 0.0 < new_radius {
+                let scale = radius / new_radius;
+                self.points.par_iter_mut().for_each(|point| {
+                    *point *= scale;
+                });
+            }
+        } else {
+            let ratio_ = match ratio {
+                Some(r) => r.clamp(0.0, 1.0),
+                None => 1. / 2.,
+            };
+
+            let selected = self.selected_faces(face_selector.as_ref());
+
+            let new_points: Vec<(Face, Point)> = self
+                .face_index
+                .par_iter()
+                .zip(selected.par_iter())
+                .filter(|(_, selected)| **selected)
+                .flat_map(|(face, _)| {
+                    let face_points = index_as_points(face, &self.points);
+                    let centroid = centroid_ref(&face_points);
+                    face.iter()
+                        .zip(&face_points)
+                        .map(|(v, point)| {
+                            (extend![..face, *v], **point + ratio_ * (centroid - **point))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+
+            let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
+
+            let edge_faces: HashMap<Edge, usize> = self
+                .face_index
+                .iter()
+                .enumerate()
+                .flat_map(|(index, face)| {
+                    face.iter()
+                        .circular_tuple_windows::<(_, _)>()
+                        .map(move |(a, b)| ([*a, *b], index))
+                })
+                .collect();
+
+            // The shrunk copy of `v` in `face`. Unselected faces are not
+            // shrunk and keep their vertices.
+            let inner =
+                |face: &Face, v: VertexKey| vertex(&extend![..face, v], &new_ids).unwrap_or(v);
+
+            let face_index: Faces = self
+                .face_index
+                .par_iter()
+                .map(|face| face.iter().map(|v| inner(face, *v)).collect())
+                .chain(
+                    self.face_index
+                        .par_iter()
+                        .zip(selected.par_iter())
+                        .flat_map(|(face, face_selected)| {
+                            face.iter()
+                                .circular_tuple_windows::<(_, _)>()
+                                .filter_map(|(a, b)| {
+                                    let (a, b) = (*a, *b);
+                                    match edge_faces.get(&[b, a]).cloned() {
+                                        // Edge on the boundary of an open mesh.
+                                        None if *face_selected => {
+                                            Some(vec![a, b, inner(face, b), inner(face, a)])
+                                        }
+                                        Some(opposite)
+                                            if a < b && (*face_selected || selected[opposite]) =>
+                                        {
+                                            let opposite_face = &self.face_index[opposite];
+                                            // Next to an unselected face the
+                                            // hexagon collapses to a quadrilateral.
+                                            let mut new_face = vec![
+                                                a,
+                                                inner(opposite_face, a),
+                                                inner(opposite_face, b),
+                                                b,
+                                                inner(face, b),
+                                                inner(face, a),
+                                            ];
+                                            new_face.dedup();
+                                            if new_face.first() == new_face.last() {
+                                                new_face.pop();
+                                            }
+                                            Some(new_face)
+                                        }
+                                        _ => None,
+                                    }
+                                })
+                                .collect::<Faces>()
+                        }),
+                )
+                .collect::<Faces>();
+
+            // The shrunk faces replace the original ones in-place so existing
+            // face sets stay valid.
+            self.append_new_face_set(face_index.len() - self.face_index.len());
+
+            let radius = max_magnitude(&self.points);
+
+            // Points of unselected faces stay in place so these faces stay
+            // planar.
+            let mut movable = vec![true; self.points.len()];
+            self.face_index
+                .iter()
+                .zip(&selected)
+                .filter(|(_, selected)| !**selected)
+                .for_each(|(face, _)| face.iter().for_each(|v| movable[*v as usize] = false));
+
+            let normals = self.normals(NormalType::Smooth);
+            let points_len = self.points.len();
+            self.points.extend(vertex_values(&new_points));
+
+            // Each hexagon is planar if the original points of its edge are
+            // on the plane through the four shrunk points. Move the original
+            // points along their normal by the average of the offsets to the
+            // planes of their hexagons.
+            let mut offsets = vec![(0.0 as Float, 0usize); points_len];
+            self.face_index
+                .iter()
+                .zip(&selected)
+                .filter(|(_, selected)| **selected)
+                .for_each(|(face, _)| {
+                    face.iter()
+                        .circular_tuple_windows::<(_, _)>()
+                        .for_each(|(a, b)| match edge_faces.get(&[*b, *a]) {
+                            Some(opposite) if a < b && selected[*opposite] => {
+                                let opposite_face = &self.face_index[*opposite];
+                                let plane_points = index_as_points(
+                                    &[
+                                        inner(opposite_face, *a),
+                                        inner(opposite_face, *b),
+                                        inner(face, *b),
+                                        inner(face, *a),
+                                    ],
+                                    &self.points,
+                                );
+                                let plane_normal = newell_normal(&plane_points);
+                                let plane_center = centroid_ref(&plane_points);
+                                [*a, *b]
+                                    .iter()
+                                    .filter(|v| movable[**v as usize])
+                                    .for_each(|v| {
+                                        let v = *v as usize;
+                                        let slope = plane_normal.dot(normals[v]);
+                                        if EPSILON < slope.abs() {
+                                            offsets[v].0 += plane_normal
+                                                .dot(plane_center - self.points[v])
+                                                / slope;
+                                            offsets[v].1 += 1;
+                                        }
+                                    });
+                            }
+                            _ => (),
+                        })
+                });
+
+            self.points[..points_len]
+                .par_iter_mut()
+                .zip(offsets.par_iter())
+                .zip(normals.par_iter())
+                .filter(|((_, offset), _)| 0 < offset.1)
+                .for_each(|((point, offset), normal)| {
+                    *point += (offset.0 / offset.1 as Float) * *normal;
+                });
+            self.face_index = face_index;
+
+            // Moving the original points changes the overall size.
+            // Restore the circumradius so chamfer composes with other
+            // operators.
+            let new_radius = max_magnitude(&self.points);
+            if 0.0 < new_radius {
+                let scale = radius / new_radius;
+                self.points.par_iter_mut().for_each(|point| {
+                    *point *= scale;
+                });
+            }
+        }
+
+        if change_name {
+            let mut params = String::new();
+            // The ratio and face selector are ignored for orders above one.
+            if 1 < order_ {
+                write!(&mut params, ",{}", order_).unwrap();
+            } else {
+                if let Some(ratio) = ratio {
+                    write!(&mut params, "{:.2}", ratio).unwrap();
+                }
+                if let Some(order) = order {
+                    write!(&mut params, ",{}", order).unwrap();
+                } else {
+                    write!(&mut params, ",").unwrap();
+                }
+                if let Some(face_selector) = &face_selector {
+                    write!(&mut params, ",{}", face_selector).unwrap();
+                }
+            }
+            params = params.trim_end_matches(',').to_string();
+            self.name = format!("c{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Contracts selected faces – the rough inverse of
+    /// [`kis()`](Polyhedron::kis()) and
+    /// [`truncate()`](Polyhedron::truncate()).
+    ///
+    /// Where all faces around a vertex are selected, the vertex is removed
+    /// and its faces are merged into one. E.g. the pyramids added by
+    /// [`kis()`](Polyhedron::kis()) are collapsed back to their base.
+    /// Vertices with fewer faces are tried first and each face is merged at
+    /// most once.
+    ///
+    /// Each other selected face that shares no vertex with a merged face or
+    /// a contracted face is contracted to a single vertex at its centroid.
+    /// Faces that degenerate to less than three vertices are removed.
+    ///
+    /// Merged faces take the face sets of the first face they were merged
+    /// from. The remaining faces keep their face sets.
+    /// # Arguments
+    /// * `face_selector` – Only selected faces will be contracted. Default
+    ///   is to contract triangles.
+    pub fn contract(
+        &mut self,
+        face_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        let selected = self.selected_faces(Some(
+            face_selector
+                .as_ref()
+                .unwrap_or(&FaceSelector::Arity(vec![3])),
+        ));
+
+        let mut vertex_faces = vec![Vec::new(); self.points.len()];
+        self.face_index
+            .iter()
+            .enumerate()
+            .for_each(|(index, face)| {
+                face.iter()
+                    .for_each(|v| vertex_faces[*v as usize].push(index))
+            });
+
+        // The face around a vertex its faces are merged into, walking from
+        // face to face. `None` if the walk does not close, e.g. at the
+        // boundary of an open mesh.
+        let merged_face = |v: VertexKey| -> Option<Face> {
+            let faces = &vertex_faces[v as usize];
+            // Each face, rotated to start after `v`, without `v`.
+            let mut fans: Vec<Face> = faces
+                .iter()
+                .map(|face| {
+                    let face = &self.face_index[*face];
+                    let start = index_of(&v, face).unwrap();
+                    (1..face.len())
+                        .map(|i| face[(start + i) % face.len()])
+                        .collect()
+                })
+                .collect();
+            let mut merged_face = fans.pop()?;
+            while !fans.is_empty() {
+                let last = *merged_face.last().unwrap();
+                let next = fans.iter().position(|fan| fan[0] == last)?;
+                merged_face.extend(&fans.swap_remove(next)[1..]);
+            }
+            if merged_face.first() == merged_face.last() {
+                merged_face.pop();
+                Some(merged_face)
+            } else {
+                None
+            }
+        };
+
+        let mut candidates: Vec<VertexKey> = (0..self.points.len() as VertexKey)
+            .filter(|v| {
+                let faces = &vertex_faces[*v as usize];
+                !faces.is_empty() && faces.iter().all(|face| selected[*face])
+            })
+            .collect();
+        candidates.sort_by_key(|v| vertex_faces[*v as usize].len());
+
+        // Merge the faces around vertices. The merged face replaces the
+        // first face it was merged from.
+        let mut claimed = vec![false; self.face_index.len()];
+        let mut replacements: Vec<Option<Face>> = vec![None; self.face_index.len()];
+        let mut touched = vec![false; self.points.len()];
+        candidates.iter().for_each(|v| {
+            let faces = &vertex_faces[*v as usize];
+            if faces.iter().all(|face| !claimed[*face]) {
+                if let Some(merged_face) = merged_face(*v) {
+                    faces.iter().for_each(|face| claimed[*face] = true);
+                    merged_face.iter().for_each(|v| touched[*v as usize] = true);
+                    replacements[faces[0]] = Some(merged_face);
+                }
+            }
+        });
+
+        // Contract the other selected faces to their first vertex.
+        let mut new_index: Vec<VertexKey> = (0..self.points.len() as VertexKey).collect();
+        let mut centroids = Vec::new();
+        self.face_index
+            .iter()
+            .zip(&selected)
+            .zip(claimed.iter_mut())
+            .filter(|((_, selected), claimed)| **selected && !**claimed)
+            .for_each(|((face, _), claimed)| {
+                if face.iter().all(|v| !touched[*v as usize]) {
+                    *claimed = true;
+                    face.iter().for_each(|v| {
+                        touched[*v as usize] = true;
+                        new_index[*v as usize] = face[0];
+                    });
+                    centroids.push((face[0], centroid_ref(&index_as_points(face, &self.points))));
+                }
+            });
+        centroids
+            .into_iter()
+            .for_each(|(v, centroid)| self.points[v as usize] = centroid);
+
+        let mut face_map = Vec::with_capacity(self.face_index.len());
+        let mut new_faces = Vec::with_capacity(self.face_index.len());
+        self.face_index
+            .iter()
+            .zip(&claimed)
+            .zip(replacements)
+            .for_each(|((face, claimed), replacement)| {
+                let face = match (claimed, replacement) {
+                    (_, Some(replacement)) => replacement,
+                    (false, None) => face.clone(),
+                    (true, None) => {
+                        face_map.push(None);
+                        return;
+                    }
+                };
+                let mut new_face: Face = face
+                    .iter()
+                    .map(|v| new_index[*v as usize])
+                    .dedup()
+                    .collect();
+                while 1 < new_face.len() && new_face.first() == new_face.last() {
+                    new_face.pop();
+                }
+                if 2 < new_face.len() {
+                    face_map.push(Some(new_faces.len() as VertexKey));
+                    new_faces.push(new_face);
+                } else {
+                    face_map.push(None);
+                }
+            });
+
+        self.face_set_index = self
+            .face_set_index
+            .iter()
+            .map(|face_set| {
+                face_set
+                    .iter()
+                    .filter_map(|face| face_map.get(*face as usize).copied().flatten())
+                    .collect::<FaceSet>()
+            })
+            .filter(|face_set| !face_set.is_empty())
+            .collect();
+        self.face_index = new_faces;
+        self.remove_unused_points();
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(face_selector) = &face_selector {
+                write!(&mut params, "{}", face_selector).unwrap();
+            }
+            self.name = format!("uk{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Subdivides each face in a cross pattern around its center.
+    ///
+    /// Each *n*-gon gets a central vertex, a vertex at each edge midpoint and
+    /// one inner vertex per original vertex, between the center and the
+    /// midpoints of the two adjacent edges. It is
+    /// replaced with *n* quadrilaterals at its corners, *n* triangles along
+    /// its edges and *n* triangles fanning around the central vertex.
+    /// # Arguments
+    /// * `height` – An offset to add to the central vertex along the face
+    ///   normal.
+    pub fn cross(&mut self, height: Option<Float>, change_name: bool) -> &mut Self {
+        let height_ = height.unwrap_or(0.);
+
+        let edges = self.to_edges();
+
+        let new_points: Vec<(Face, Point)> = self
+            .face_index
+            .par_iter()
+            .flat_map(|face| {
+                let face_points = index_as_points(face, &self.points);
+                let center =
+                    centroid_ref(&face_points) + face_normal(&face_points).unwrap() * height_;
+                face.iter()
+                    .cycle()
+                    .skip(1)
+                    .zip(face_points.iter().circular_tuple_windows::<(_, _, _)>())
+                    .map(|(v, p)| {
+                        // Halfway between the center and the line connecting
+                        // the adjacent edge midpoints. This keeps the corner
+                        // quadrilaterals convex.
+                        (
+                            extend![..face, *v],
+                            0.5 * center + 0.125 * (**p.0 + 2.0 * **p.1 + **p.2),
+                        )
+                    })
+                    .chain(once((face.clone(), center)))
+                    .collect::<Vec<_>>()
+            })
+            .chain(edges.par_iter().map(|edge| {
+                let edge_points = index_as_points(edge, &self.points);
+                (edge.to_vec(), 0.5 * (*edge_points[0] + *edge_points[1]))
+            }))
+            .collect();
+
+        let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
+
+        self.face_index = self
+            .face_index
+            .par_iter()
+            .flat_map(|face| {
+                let center = vertex(face, &new_ids).unwrap();
+                face.iter()
+                    .circular_tuple_windows::<(_, _, _)>()
+                    .flat_map(|v| {
+                        let mid_ab = vertex(&distinct_edge(&[*v.0, *v.1]), &new_ids).unwrap();
+                        let mid_bc = vertex(&distinct_edge(&[*v.1, *v.2]), &new_ids).unwrap();
+                        let inner_b = vertex(&extend![..face, *v.1], &new_ids).unwrap();
+                        let inner_c = vertex(&extend![..face, *v.2], &new_ids).unwrap();
+                        vec![
+                            vec![mid_ab, *v.1, mid_bc, inner_b],
+                            vec![mid_bc, inner_c, inner_b],
+                            vec![inner_b, inner_c, center],
+                        ]
+                    })
+                    .collect::<Faces>()
+            })
+            .collect();
+
+        self.points.extend(vertex_values(&new_points));
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(height) = height {
+                write!(&mut params, "{:.2}", height).unwrap();
+            }
+            self.name = format!("X{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Replaces each face with a vertex, and each vertex with a face.
+    pub fn dual(&mut self, change_name: bool) -> &mut Self {
+        let new_points = face_centers(&self.face_index, &self.points);
+        self.face_set_index = self.dual_face_sets();
+        self.face_index = points_to_faces(&self.points, &self.face_index);
+        self.points = new_points;
+
+        if change_name {
+            self.name = format!("d{}", self.name);
+        }
+
+        self
+    }
+
+    /// Replaces each face with a vertex, and each vertex with a face, by
+    /// [polar reciprocation](https://en.wikipedia.org/wiki/Dual_polyhedron#Polar_reciprocation)
+    /// about a sphere centered at the origin.
+    ///
+    /// The new vertex for each face is the pole of the face's plane. Unlike
+    /// [`dual()`](Polyhedron::dual()), which uses face centroids, applying
+    /// this twice with the same `radius` returns the original geometry (as
+    /// long as the faces are planar).
+    /// # Arguments
+    /// * `radius` - The radius of the sphere. Default is the average distance
+    ///   of the edge midpoints from the origin, i.e. the
+    ///   [`midradius()`](Polyhedron::midradius()) of polyhedra with a
+    ///   midsphere centered at the origin.
+    ///
+    /// The name is prefixed with `R`, to tell it apart from `d`ual.
+    pub fn reciprocal(&mut self, radius: Option<Float>, change_name: bool) -> &mut Self {
+        let radius_sq = radius
+            .unwrap_or_else(|| mean_edge_midpoint_distance(&self.to_edges(), &self.points))
+            .powi(2);
+
+        let new_points = self
+            .face_index
+            .par_iter()
+            .map(|face| {
+                let face_points = index_as_points(face, &self.points);
+                let normal = newell_normal(&face_points).normalized();
+                let centroid = centroid_ref(&face_points);
+                let distance = normal.dot(centroid);
+                if EPSILON < distance.abs() {
+                    normal * (radius_sq / distance)
+                } else if EPSILON < centroid.mag_sq() {
+                    // The plane contains the center, fall back to the
+                    // reciprocal of the centroid.
+                    centroid * (radius_sq / centroid.mag_sq())
+                } else {
+                    // The centroid is the center, fall back to the point on
+                    // the sphere along the normal.
+                    normal * radius_sq.sqrt()
+                }
+            })
+            .collect();
+        self.face_set_index = self.dual_face_sets();
+        self.face_index = points_to_faces(&self.points, &self.face_index);
+        self.points = new_points;
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(radius) = radius {
+                write!(&mut params, "{:.2}", radius).unwrap();
+            }
+            self.name = format!("R{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Applies [`ambo()`](Polyhedron::ambo()) twice.
+    /// # Arguments
+    /// * `ratio` – The ratio of the first (inner) ambo pass.
+    /// * `second_ratio` – The ratio of the second (outer) ambo pass.
+    ///   Defaults to `ratio`.
+    pub fn expand(
+        &mut self,
+        ratio: Option<Float>,
+        second_ratio: Option<Float>,
+        change_name: bool,
+    ) -> &mut Self {
+        self.ambo(ratio, false);
+        self.ambo(second_ratio.or(ratio), false);
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
+            }
+            if let Some(second_ratio) = second_ratio {
+                write!(&mut params, ",{:.2}", second_ratio).unwrap();
+            }
+            self.name = format!("e{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Subdivides each face into a patch of the triangular lattice and
+    /// projects the new vertices onto the circumscribed sphere.
+    ///
+    /// For [`GeodesicClass::I`] each edge is split into `frequency` segments.
+    /// Followed by [`dual()`](Polyhedron::dual()) this yields the
+    /// corresponding Goldberg polyhedron.
+    ///
+    /// Faces that are not triangles are split into triangles at their
+    /// centroid first.
+    pub fn geodesic(
+        &mut self,
+        frequency: usize,
+        class: GeodesicClass,
+        change_name: bool,
+    ) -> &mut Self {
+        let (m, n) = match class {
+            GeodesicClass::I => (frequency, 0),
+            GeodesicClass::II => (frequency, frequency),
+            GeodesicClass::III(n) => (frequency, n),
+        };
+
+        if 0 < m + n {
+            let radius = self
+                .points
+                .iter()
+                .map(|point| point.mag())
+                .fold(0., Float::max);
+            let points_len = self.points.len();
+
+            self.triangulate_with_centroids();
+            self.goldberg_coxeter_patches(m, n, false);
+
+            project_on_sphere(&mut self.points[points_len..], radius, 1.);
+
+            if change_name {
+                self.name = format!("geo{},{}{}", m, n, self.name);
+            }
+        }
+
+        self
+    }
+
+    /// Applies the [Goldberg–Coxeter
+    /// construction](https://en.wikipedia.org/wiki/Goldberg%E2%80%93Coxeter_construction)
+    /// *GC(m, n)*.
+    ///
+    /// Every face of the dual is replaced with a patch of the triangular
+    /// lattice spanned by the vector *(m, n)* and the result is dualized
+    /// back. For polyhedra where all vertices have valence three this yields
+    /// the Goldberg polyhedra. If *m* and *n* differ and are both non-zero
+    /// the result is chiral.
+    ///
+    /// Vertices of any valence are supported. If *m − n* is not a multiple
+    /// of three they keep their valence, otherwise they become faces with
+    /// twice as many sides. E.g. *GC(2, 0)* is a
+    /// [`chamfer()`](Polyhedron::chamfer()) and *GC(2, 1)* a
+    /// [`whirl()`](Polyhedron::whirl()), for any polyhedron.
+    ///
+    /// The lattice patches are unfolded across every edge, so open meshes
+    /// are left unchanged.
+    pub fn goldberg_coxeter(&mut self, m: usize, n: usize, change_name: bool) -> &mut Self {
+        if 0 < m + n && is_closed(&self.face_index) {
+            self.dual(false);
+            self.goldberg_coxeter_patches(m, n, true);
+            self.dual(false);
+
+            if change_name {
+                self.name = format!("GC{},{}{}", m, n, self.name);
+            }
+        }
+
+        self
+    }
+
+    /// Splits all faces that are not triangles into triangles meeting at the
+    /// face centroid.
+    fn triangulate_with_centroids(&mut self) {
+        let new_points: Vec<(&FaceSlice, Point)> = self
+            .face_index
+            .par_iter()
+            .filter(|face| 3 != face.len())
+            .map(|face| {
+                (
+                    face.as_slice(),
+                    centroid_ref(&index_as_points(face, &self.points)),
+                )
+            })
+            .collect();
+
+        let new_ids = vertex_ids_ref_ref(&new_points, self.points.len() as VertexKey);
+
+        self.points.extend(vertex_values_as_ref(&new_points));
+
+        self.face_index = self
+            .face_index
+            .par_iter()
+            .flat_map(|face: &Face| match vertex(face, &new_ids) {
+                Some(centroid) => face
+                    .iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .map(|(a, b)| vec![*a, *b, centroid])
+                    .collect(),
+                None => vec![face.clone()],
+            })
+            .collect();
+    }
+
+    /// Replaces each face with a patch of the triangular lattice spanned by
+    /// the lattice vector *(m, n)*.
+    ///
+    /// Without `sectors` all faces must be triangles and each one becomes a
+    /// whole lattice triangle. With `sectors` each face is split into
+    /// sectors, one per edge, meeting at the face centroid. Each sector
+    /// covers a third of a lattice triangle, so faces of any arity are
+    /// subdivided the same way. If the centroid is not a lattice point the
+    /// lattice triangle around it becomes a face with as many sides as the
+    /// original face.
+    ///
+    /// The triangles of the lattice are assigned to the face containing their
+    /// centroid. Their corners, which may be outside that face, are looked up
+    /// by unfolding the neighboring face across the respective edge.
+    fn goldberg_coxeter_patches(&mut self, m: usize, n: usize, sectors: bool) {
+        let (m, n) = (m as i64, n as i64);
+        let tip = lattice_rotate(&[m, n], 1);
+
+        // Lattice coordinates of the three face corners. For sectors these
+        // are scaled by three, so the centroid of the lattice triangle has
+        // integer coordinates. Lattice points are then the points whose
+        // coordinates are multiples of three.
+        let (scale, corners) = if sectors {
+            (3, [[0, 0], [3 * m, 3 * n], [m + tip[0], n + tip[1]]])
+        } else {
+            (1, [[0, 0], [m, n], tip])
+        };
+        let is_lattice_point = |p: &[i64; 2]| 0 == p[0] % scale && 0 == p[1] % scale;
+
+        let determinant = |u: &[i64; 2], v: &[i64; 2]| u[0] * v[1] - u[1] * v[0];
+        // Twice the area of a face in lattice units.
+        let area = determinant(&corners[1], &corners[2]);
+
+        // Barycentric coordinates of a lattice point, scaled by `area`, in
+        // the order of the face corners.
+        let barycentric = |p: &[i64; 2]| {
+            let a = determinant(p, &corners[2]);
+            let b = determinant(&corners[1], p);
+            [area - a - b, a, b]
+        };
+
+        // The bounding box of a face.
+        let min = [
+            corners.iter().map(|c| c[0]).min().unwrap(),
+            corners.iter().map(|c| c[1]).min().unwrap(),
+        ];
+        let max = [
+            corners.iter().map(|c| c[0]).max().unwrap(),
+            corners.iter().map(|c| c[1]).max().unwrap(),
+        ];
+
+        // Split each face into one triangle per edge, ending in the face
+        // centroid.
+        let points_len = self.points.len();
+        let faces: Faces = if sectors {
+            let centroids = self
+                .face_index
+                .par_iter()
+                .map(|face| centroid_ref(&index_as_points(face, &self.points)))
+                .collect::<Vec<_>>();
+            self.points.extend(centroids);
+
+            self.face_index
+                .iter()
+                .enumerate()
+                .flat_map(|(index, face)| {
+                    let centroid = (points_len + index) as VertexKey;
+                    face.iter()
+                        .circular_tuple_windows::<(_, _)>()
+                        .map(move |(a, b)| vec![*a, *b, centroid])
+                })
+                .collect()
+        } else {
+            self.face_index.clone()
+        };
+
+        let new_points: Vec<(Face, Point)> = faces
+            .par_iter()
+            .flat_map(|face| {
+                let face_points = index_as_points(face, &self.points);
+                let point = |weights: &[i64; 3]| {
+                    (weights[0] as Float * *face_points[0]
+                        + weights[1] as Float * *face_points[1]
+                        + weights[2] as Float * *face_points[2])
+                        / area as Float
+                };
+
+                // Points on an edge are created by the face running along it
+                // from the smaller to the larger vertex key.
+                (0..3)
+                    .filter(|i| face[*i] < face[(i + 1) % 3])
+                    .flat_map(|i| {
+                        let j = (i + 1) % 3;
+                        let direction =
+                            [corners[j][0] - corners[i][0], corners[j][1] - corners[i][1]];
+                        let steps = gcd(direction[0], direction[1]);
+                        (1..steps)
+                            .map(|k| {
+                                [
+                                    corners[i][0] + k * direction[0] / steps,
+                                    corners[i][1] + k * direction[1] / steps,
+                                ]
+                            })
+                            .filter(|p| is_lattice_point(p))
+                            .map(|p| {
+                                let weights = barycentric(&p);
+                                (
+                                    vec![face[i], face[j], weights[j] as VertexKey],
+                                    point(&weights),
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .chain(
+                        (min[0]..=max[0])
+                            .flat_map(|x| (min[1]..=max[1]).map(move |y| [x, y]))
+                            .filter(|p| is_lattice_point(p))
+                            .filter_map(|p| {
+                                let weights = barycentric(&p);
+                                if weights.iter().all(|w| 0 < *w) {
+                                    Some((
+                                        extend![
+                                            ..face,
+                                            (p[0] - min[0]) as VertexKey,
+                                            (p[1] - min[1]) as VertexKey
+                                        ],
+                                        point(&weights),
+                                    ))
+                                } else {
+                                    None
+                                }
+                            }),
+                    )
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
+
+        // The vertex on the edge from a to b with the given barycentric
+        // weights of a and b.
+        let edge_vertex = |a: VertexKey, b: VertexKey, a_weight: i64, b_weight: i64| {
+            if 0 == b_weight {
+                a
+            } else if 0 == a_weight {
+                b
+            } else if a < b {
+                vertex(&[a, b, b_weight as VertexKey], &new_ids).unwrap()
+            } else {
+                vertex(&[b, a, a_weight as VertexKey], &new_ids).unwrap()
+            }
+        };
+
+        // Looks up the vertex at a lattice point on or inside a face.
+        let face_vertex = |face: &FaceSlice, p: &[i64; 2]| -> Option<VertexKey> {
+            let weights = barycentric(p);
+            if weights.iter().any(|w| *w < 0) {
+                None
+            } else if let Some(i) = (0..3).find(|i| 0 == weights[(i + 2) % 3]) {
+                let j = (i + 1) % 3;
+                Some(edge_vertex(face[i], face[j], weights[i], weights[j]))
+            } else {
+                vertex(
+                    &extend![
+                        ..face.to_vec(),
+                        (p[0] - min[0]) as VertexKey,
+                        (p[1] - min[1]) as VertexKey
+                    ],
+                    &new_ids,
+                )
+            }
+        };
+
+        // Looks up the vertex at a lattice point, unfolding the neighboring
+        // face if the point is outside the given one.
+        let lattice_vertex = |face: &FaceSlice, p: &[i64; 2]| -> VertexKey {
+            face_vertex(face, p).unwrap_or_else(|| {
+                let weights = barycentric(p);
+                // The edge the point is beyond.
+                let i = if weights[2] < 0 {
+                    0
+                } else if weights[0] < 0 {
+                    1
+                } else {
+                    2
+                };
+                let j = (i + 1) % 3;
+
+                let neighbor = face_with_edge(&[face[j], face[i]], &faces);
+                let start = corners[index_of(&face[i], &neighbor).unwrap()];
+                let end = corners[index_of(&face[j], &neighbor).unwrap()];
+
+                let direction = [corners[j][0] - corners[i][0], corners[j][1] - corners[i][1]];
+                let neighbor_direction = [end[0] - start[0], end[1] - start[1]];
+                let turns = (0..6)
+                    .find(|k| lattice_rotate(&direction, *k) == neighbor_direction)
+                    .unwrap();
+
+                let offset = lattice_rotate(&[p[0] - corners[i][0], p[1] - corners[i][1]], turns);
+                face_vertex(&neighbor, &[start[0] + offset[0], start[1] + offset[1]]).unwrap()
+            })
+        };
+
+        let mut face_index = faces
+            .par_iter()
+            .flat_map(|face| {
+                (min[0].div_euclid(scale) - 1..=max[0].div_euclid(scale))
+                    .flat_map(|x| {
+                        (min[1].div_euclid(scale) - 1..=max[1].div_euclid(scale))
+                            .map(move |y| [x * scale, y * scale])
+                    })
+                    .flat_map(|p| {
+                        vec![
+                            [p, [p[0] + scale, p[1]], [p[0], p[1] + scale]],
+                            [
+                                [p[0] + scale, p[1]],
+                                [p[0] + scale, p[1] + scale],
+                                [p[0], p[1] + scale],
+                            ],
+                        ]
+                    })
+                    .filter(|triangle| {
+                        // Barycentric coordinates of the centroid of the
+                        // lattice triangle, scaled by three.
+                        let weights = triangle.iter().fold([0, 0, 0], |sum, p| {
+                            let weights = barycentric(p);
+                            [
+                                sum[0] + weights[0],
+                                sum[1] + weights[1],
+                                sum[2] + weights[2],
+                            ]
+                        });
+                        // Triangles whose centroid is on an edge belong to
+                        // exactly one of the two faces sharing it. The
+                        // triangle around a sector's centroid belongs to
+                        // none.
+                        (0..3).all(|i| {
+                            0 < weights[(i + 2) % 3]
+                                || (0 == weights[(i + 2) % 3] && face[i] < face[(i + 1) % 3])
+                        })
+                    })
+                    .map(|triangle| triangle.iter().map(|p| lattice_vertex(face, p)).collect())
+                    .collect::<Faces>()
+            })
+            .collect::<Faces>();
+
+        // Replace the lattice triangle around each centroid that is not a
+        // lattice point with a face connecting one of its corners per sector.
+        if sectors && !is_lattice_point(&corners[2]) {
+            let center = corners[2];
+            let triangle = if 1 == center[0].rem_euclid(3) {
+                let p = [center[0] - 1, center[1] - 1];
+                [p, [p[0] + 3, p[1]], [p[0], p[1] + 3]]
+            } else {
+                let p = [center[0] - 2, center[1] - 2];
+                [[p[0] + 3, p[1]], [p[0] + 3, p[1] + 3], [p[0], p[1] + 3]]
+            };
+
+            let mut sector = 0;
+            face_index.extend(self.face_index.iter().map(|face| {
+                let center_face = faces[sector..sector + face.len()]
+                    .iter()
+                    .map(|sector_face| {
+                        // The corner from the sector's first edge to the
+                        // centroid up to, but not including, its last edge.
+                        let p = triangle
+                            .iter()
+                            .find(|p| {
+                                let weights = barycentric(p);
+                                0 < weights[0] && 0 <= weights[1]
+                            })
+                            .unwrap();
+                        face_vertex(sector_face, p).unwrap()
+                    })
+                    .collect();
+                sector += face.len();
+                center_face
+            }));
+
+            // The centroids are not referenced, remove them.
+            let centroids_len = self.face_index.len() as VertexKey;
+            face_index.par_iter_mut().for_each(|face| {
+                face.iter_mut().for_each(|v| {
+                    *v -= centroids_len * (points_len as VertexKey <= *v) as VertexKey
+                })
+            });
+            self.points.truncate(points_len);
+        }
+
+        self.face_index = face_index;
+        self.points.extend(vertex_values(&new_points));
+    }
+
+    /// Splits each edge and connects new edges at the split point to the face
+    /// centroid. Existing points are retained.
+    /// ![Gyro](https://upload.wikimedia.org/wikipedia/commons/thumb/f/f6/Conway_gC.png/200px-Conway_gC.png)
+    /// # Arguments
+    /// * `ratio` – The ratio at which the adjacent edges get split.
+    /// * `height` – An offset to add to the face centroid point along the face
+    ///   normal.
+    /// * `order` – The number of rings each face is divided into. Default
+    ///   value is `1`.
+    ///
+    ///   With orders above one the connections from the edge points to the
+    ///   face centroid are split. The outermost ring then consists of
+    ///   hexagons, followed by rings of quadrilaterals and a fan of triangles
+    ///   around the face centroid.
+    pub fn gyro(
+        &mut self,
+        ratio: Option<Float>,
+        height: Option<Float>,
+        order: Option<usize>,
+        change_name: bool,
+    ) -> &mut Self {
+        let ratio_ = ratio.unwrap_or(1. / 3.);
+        let height_ = height.unwrap_or(0.);
+        self.gyro_with_heights(&|_, _, _| ratio_, &|_, _, _| height_, order, false);
 
-        let new_face_index = self
+        if change_name {
+            let mut params = String::new();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
+            }
+            if let Some(height) = height {
+                write!(&mut params, ",{:.2}", height).unwrap();
+            } else {
+                write!(&mut params, ",").unwrap();
+            }
+            if let Some(order) = order {
+                write!(&mut params, ",{}", order).unwrap();
+            }
+            params = params.trim_end_matches(',').to_string();
+            self.name = format!("g{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Like [`gyro()`](Polyhedron::gyro()) but the ratio at which the edges
+    /// of each face get split and the offset of each face centroid are
+    /// computed per face by `ratio` and `height`.
+    ///
+    /// Each face splits the edges it runs along in its own winding order.
+    /// Ratios are clamped to `[0, 1]`.
+    pub fn gyro_with_heights(
+        &mut self,
+        ratio: &FaceParameterFn<'_>,
+        height: &FaceParameterFn<'_>,
+        order: Option<usize>,
+        change_name: bool,
+    ) -> &mut Self {
+        let order_ = order.unwrap_or(1).max(1);
+
+        let edges = self.to_edges();
+        let reversed_edges: Edges = edges.par_iter().map(|edge| [edge[1], edge[0]]).collect();
+
+        // The ratio of the face running along each directed edge. On a
+        // boundary only one direction has a face, so both use its ratio.
+        let mut edge_ratios = HashMap::new();
+        self.face_index
+            .iter()
+            .enumerate()
+            .for_each(|(index, face)| {
+                let face_ratio = ratio(
+                    index,
+                    face.len(),
+                    &centroid_ref(&index_as_points(face, &self.points)),
+                )
+                .clamp(0.0, 1.0);
+                face.iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .for_each(|(a, b)| {
+                        edge_ratios.insert([*a, *b], face_ratio);
+                        edge_ratios.entry([*b, *a]).or_insert(face_ratio);
+                    });
+            });
+
+        // Retain original points, add face centroids and directed
+        // edge points each N-face becomes N pentagons.
+        let new_points: Vec<(&FaceSlice, Point)> = self
             .face_index
             .par_iter()
-            .flat_map(|face| {
-                let centroid = vertex(face, &new_face_vertex_ids).unwrap();
+            .enumerate()
+            .map(|(index, face)| {
+                let fp = index_as_points(face, &self.points);
+                let centroid = centroid_ref(&fp);
+                (
+                    face.as_slice(),
+                    centroid.normalized()
+                        + face_normal(&fp).unwrap() * height(index, face.len(), &centroid),
+                )
+            })
+            .chain(edges.par_iter().enumerate().flat_map(|edge| {
+                let edge_points = index_as_points(edge.1, &self.points);
+                let reversed_edge = &reversed_edges[edge.0];
+                vec![
+                    (
+                        &edge.1[..],
+                        *edge_points[0] + edge_ratios[edge.1] * (*edge_points[1] - *edge_points[0]),
+                    ),
+                    (
+                        &reversed_edge[..],
+                        *edge_points[1]
+                            + edge_ratios[reversed_edge] * (*edge_points[0] - *edge_points[1]),
+                    ),
+                ]
+            }))
+            .collect();
+
+        let new_ids = vertex_ids_ref_ref(&new_points, self.points_len() as VertexKey);
 
+        // Points on the rings between the edge points and the face
+        // centroid.
+        let ring_points: Vec<(Face, Point)> = self
+            .face_index
+            .par_iter()
+            .flat_map(|face| {
+                let centroid = *vertex_point(face, &new_points).unwrap();
                 face.iter()
-                    .circular_tuple_windows::<(_, _, _)>()
-                    .map(|triplet| {
-                        let mid1 = vertex_edge(
-                            &distinct_edge(&[*triplet.0, *triplet.1]),
-                            new_edge_vertex_ids.as_slice(),
-                        )
-                        .unwrap();
-                        let mid2 = vertex_edge(
-                            &distinct_edge(&[*triplet.1, *triplet.2]),
-                            new_edge_vertex_ids.as_slice(),
-                        )
-                        .unwrap();
-                        vec![centroid, mid1, *triplet.1, mid2]
+                    .circular_tuple_windows::<(_, _)>()
+                    .flat_map(|v| {
+                        let edge_point = *vertex_point(&[*v.0, *v.1], &new_points).unwrap();
+                        (1..order_).map(move |j| {
+                            (
+                                extend![..face, *v.0, j as VertexKey],
+                                edge_point
+                                    + (j as Float / order_ as Float) * (centroid - edge_point),
+                            )
+                        })
                     })
                     .collect::<Vec<_>>()
             })
-            .collect::<Vec<_>>();
+            .collect();
 
-        let new_points = self
-            .points
+        let ring_ids = vertex_ids_ref(
+            &ring_points,
+            (self.points_len() + new_points.len()) as VertexKey,
+        );
+
+        self.points.extend(vertex_values_as_ref(&new_points));
+        self.points.extend(vertex_values(&ring_points));
+
+        self.face_index = self
+            .face_index
+            .par_iter()
+            .flat_map(|face| {
+                face.iter()
+                    .cycle()
+                    .skip(face.len() - 1)
+                    .tuple_windows::<(_, _, _)>()
+                    .take(face.len())
+                    .flat_map(|v| {
+                        let a = *v.1;
+                        let b = *v.2;
+                        let z = *v.0;
+                        let eab = vertex(&[a, b], &new_ids).unwrap();
+                        let eza = vertex(&[z, a], &new_ids).unwrap();
+                        let eaz = vertex(&[a, z], &new_ids).unwrap();
+                        let centroid = vertex(face, &new_ids).unwrap();
+
+                        if 1 == order_ {
+                            vec![vec![a, eab, centroid, eza, eaz]]
+                        } else {
+                            let ring = |vertex_key: VertexKey, j: usize| {
+                                vertex(&extend![..face, vertex_key, j as VertexKey], &ring_ids)
+                                    .unwrap()
+                            };
+
+                            once(vec![a, eab, ring(a, 1), ring(z, 1), eza, eaz])
+                                .chain((1..order_ - 1).map(|j| {
+                                    vec![ring(z, j), ring(a, j), ring(a, j + 1), ring(z, j + 1)]
+                                }))
+                                .chain(once(vec![
+                                    ring(z, order_ - 1),
+                                    ring(a, order_ - 1),
+                                    centroid,
+                                ]))
+                                .collect()
+                        }
+                    })
+                    .collect::<Faces>()
+            })
+            .collect();
+
+        if change_name {
+            // The closures are marked like `KisApex::NormalPerFace`.
+            let mut params = String::from("{n..},{n..}");
+            if let Some(order) = order {
+                write!(&mut params, ",{}", order).unwrap();
+            }
+            self.name = format!("g{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Creates quadrilateral faces around each original edge. Original
+    /// edges are discarded.
+    ///
+    /// Each new face connects the two endpoints of an edge with the points
+    /// of the two faces sharing it.
+    ///
+    /// New faces go into a face set shared by all faces between the same
+    /// face sets.
+    /// # Arguments
+    /// * `ratio` – The ratio at which the point of each face is moved from
+    ///   its centroid towards the pole of its plane, see
+    ///   [`reciprocal()`](Polyhedron::reciprocal()). Will be clamped to `[0,
+    ///   1]`. Default value is `0.0`. With `1.0` the new faces of a regular
+    ///   polyhedron are planar.
+    /// * `height` - An offset to add to the face points along the face
+    ///   normal. Default value is `0.0`.
+    pub fn join(
+        &mut self,
+        ratio: Option<Float>,
+        height: Option<Float>,
+        change_name: bool,
+    ) -> &mut Self {
+        let ratio_ = match ratio {
+            Some(r) => r.clamp(0.0, 1.0),
+            None => 0.,
+        };
+        let height_ = height.unwrap_or(0.);
+
+        let edges = self.to_edges();
+        let radius_sq = mean_edge_midpoint_distance(&edges, &self.points).powi(2);
+
+        // One new point per face.
+        let new_points: Points = self
+            .face_index
             .par_iter()
+            .map(|face| {
+                let face_points = index_as_points(face, &self.points);
+                let centroid = centroid_ref(&face_points);
+                let normal = newell_normal(&face_points).normalized();
+                let distance = normal.dot(centroid);
+                let pole = if EPSILON < distance.abs() {
+                    normal * (radius_sq / distance)
+                } else {
+                    centroid
+                };
+                centroid + ratio_ * (pole - centroid) + face_normal(&face_points).unwrap() * height_
+            })
+            .collect();
+
+        let edge_faces: HashMap<Edge, usize> = self
+            .face_index
+            .iter()
             .enumerate()
-            .map(|point| {
-                let i = point.0 as u32;
-                let v = point.1;
-                let vertex_faces = vertex_faces(i, &self.face_index)
+            .flat_map(|(index, face)| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .map(move |(a, b)| ([*a, *b], index))
+            })
+            .collect();
+
+        let first_face_sets = self.first_face_sets();
+        let points_len = self.points.len();
+
+        // The new faces, each with the source faces on either side.
+        let new_faces: Vec<(Face, Vec<usize>)> = edges
+            .par_iter()
+            .filter_map(|edge| {
+                let face = edge_faces.get(edge).copied();
+                let opposite_face = edge_faces.get(&[edge[1], edge[0]]).copied();
+                let source_faces = face.iter().chain(opposite_face.iter()).copied().collect();
+                let point = |face: usize| (points_len + face) as VertexKey;
+                match (face, opposite_face) {
+                    (Some(f), Some(of)) => {
+                        Some((vec![edge[0], point(of), edge[1], point(f)], source_faces))
+                    }
+                    // Edges on the boundary of open meshes.
+                    (Some(f), None) => Some((vec![edge[0], edge[1], point(f)], source_faces)),
+                    (None, Some(of)) => Some((vec![edge[0], point(of), edge[1]], source_faces)),
+                    (None, None) => None,
+                }
+            })
+            .collect();
+
+        // Group the new faces by the face sets of their source faces.
+        let mut classes: Vec<Vec<usize>> = Vec::new();
+        let mut face_sets: Vec<FaceSet> = Vec::new();
+        new_faces
+            .iter()
+            .enumerate()
+            .for_each(|(index, (_, source_faces))| {
+                let mut class = source_faces
+                    .iter()
+                    .map(|face| first_face_sets[*face])
+                    .filter(|set| *set < self.face_set_index.len())
+                    .collect::<Vec<_>>();
+                class.sort_unstable();
+                class.dedup();
+                match index_of(&class, &classes) {
+                    Some(i) => face_sets[i].push(index as VertexKey),
+                    None => {
+                        classes.push(class);
+                        face_sets.push(vec![index as VertexKey]);
+                    }
+                }
+            });
+
+        self.points.extend(new_points);
+        self.face_index = new_faces.into_iter().map(|(face, _)| face).collect();
+        self.face_set_index = face_sets;
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
+            }
+            if let Some(height) = height {
+                write!(&mut params, ",{:.2}", height).unwrap();
+            }
+            self.name = format!("j{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Splits each face into triangles, one for each edge, which
+    /// extend to the face centroid. Existing points are retained.
+    /// # Arguments
+    /// * `height` - An offset to add to the face centroid point along the face
+    ///   normal.
+    /// * `face_selector` - Only selected faces will be affected.
+    pub fn kis(
+        &mut self,
+        height: Option<Float>,
+        face_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        let mut params = String::new();
+        if change_name {
+            if let Some(height) = height {
+                write!(&mut params, "{:.2}", height).unwrap();
+            }
+            if let Some(face_selector) = &face_selector {
+                write!(&mut params, ",{}", face_selector).unwrap();
+            }
+            params = params.trim_end_matches(',').to_string();
+        }
+
+        self.kis_with_apex(KisApex::Normal(height.unwrap_or(0.)), face_selector, false);
+
+        if change_name {
+            self.name = format!("k{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Like [`kis()`](Polyhedron::kis()) but with control over where the new
+    /// vertex of each face is placed.
+    ///
+    /// E.g. [`KisApex::Radial`] creates spikes pointing away from the center
+    /// of the polyhedron, even for non-planar faces whose normal is bent.
+    pub fn kis_with_apex(
+        &mut self,
+        apex: KisApex,
+        face_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        let selected = self.selected_faces(face_selector.as_ref());
+        self.kis_faces(&apex, &selected);
+
+        if change_name {
+            let mut params = apex.to_string();
+            if let Some(face_selector) = face_selector {
+                write!(&mut params, ",{}", face_selector).unwrap();
+            }
+            params = params.trim_end_matches(',').to_string();
+            self.name = format!("k{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Splits the faces marked in `selected` into triangles meeting at a new
+    /// vertex placed according to `apex`.
+    fn kis_faces(&mut self, apex: &KisApex, selected: &[bool]) {
+        let center = centroid(&self.points);
+
+        let new_points: Vec<(&FaceSlice, Point)> = self
+            .face_index
+            .par_iter()
+            .enumerate()
+            .filter_map(|(index, face)| {
+                if selected[index] {
+                    let face_points = index_as_points(face, &self.points);
+                    Some((
+                        face.as_slice(),
+                        match apex {
+                            KisApex::Normal(height) => {
+                                centroid_ref(&face_points)
+                                    + face_normal(&face_points).unwrap() * *height
+                            }
+                            KisApex::Radial(height) => {
+                                let centroid = centroid_ref(&face_points);
+                                centroid + (centroid - center).normalized() * *height
+                            }
+                            KisApex::NormalPerFace(height) => {
+                                let centroid = centroid_ref(&face_points);
+                                centroid
+                                    + face_normal(&face_points).unwrap()
+                                        * height(index, face.len(), &centroid)
+                            }
+                            KisApex::Custom(apex) => apex(face, &self.points),
+                        },
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let new_ids = vertex_ids_ref_ref(&new_points, self.points.len() as VertexKey);
+
+        self.points.extend(vertex_values_as_ref(&new_points));
+
+        self.face_index = self
+            .face_index
+            .par_iter()
+            .flat_map(|face: &Face| match vertex(face, &new_ids) {
+                Some(centroid) => face
                     .iter()
-                    .map(|face| vertex_point(face, new_face_vertices.as_slice()).unwrap())
-                    .collect::<Vec<_>>();
-                let n = vertex_faces.len() as Float;
-                let f = centroid_ref(&vertex_faces);
-                let r = centroid_ref(
-                    &vertex_edges(i, &edges)
-                        .iter()
-                        .map(|edge| vertex_edge_point(edge, new_edge_vertices.as_slice()).unwrap())
-                        .collect::<Vec<_>>(),
-                );
-                (f + 2.0 * r + (n - 3.0) * *v) / n
+                    .cycle()
+                    .tuple_windows::<(&VertexKey, _)>()
+                    .map(|v| vec![*v.0, *v.1, centroid as VertexKey])
+                    .take(face.len())
+                    .collect(),
+                None => vec![face.clone()],
             })
-            .chain(vertex_values(new_face_vertices.as_slice()))
-            .chain(vertex_values(new_edge_vertices.as_slice()))
-            .collect::<Points>();
-
-        self.points = new_points;
-        self.face_index = new_face_index;
+            .collect();
+    }
 
+    /// Insets the selected faces. This is an [`extrude()`](Polyhedron::extrude())
+    /// with zero height.
+    /// # Arguments
+    /// * `distance` – The amount the inset faces are shrunk towards their
+    ///   centroid. Default value is `0.3`.
+    /// * `face_selector` – Only selected faces will be affected.
+    pub fn inset(
+        &mut self,
+        distance: Option<Float>,
+        face_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
         if change_name {
-            self.name = format!("v{}", self.name);
+            let mut params = String::new();
+            if let Some(distance) = distance {
+                write!(&mut params, "{:.2}", distance).unwrap();
+            }
+            if let Some(face_selector) = &face_selector {
+                write!(&mut params, ",{}", face_selector).unwrap();
+            }
+            self.name = format!("i{}{}", params, self.name);
         }
 
+        self.extrude(
+            Some(0.0),
+            Some(distance.unwrap_or(0.3)),
+            face_selector,
+            false,
+        );
+
         self
     }
 
-    pub fn chamfer(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
+    /// Extrudes the selected faces along their normals and stitches the
+    /// extruded faces to their original boundary with quadrilaterals.
+    /// # Arguments
+    /// * `height` – The distance the faces are moved along their normal.
+    ///   Default value is `0.3`.
+    /// * `ratio` – The amount the extruded faces are shrunk towards their
+    ///   centroid. Will be clamped to `[0, 1]`. Default value is `0.0`.
+    /// * `face_selector` – Only selected faces will be affected.
+    pub fn extrude(
+        &mut self,
+        height: Option<Float>,
+        ratio: Option<Float>,
+        face_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        let height_ = height.unwrap_or(0.3);
         let ratio_ = match ratio {
             Some(r) => r.clamp(0.0, 1.0),
-            None => 1. / 2.,
+            None => 0.0,
         };
 
-        let new_points: Vec<(Face, Point)> = self
+        let selected = self.selected_faces(face_selector.as_ref());
+
+        let new_points = self
             .face_index
             .par_iter()
-            .flat_map(|face| {
+            .zip(selected.par_iter())
+            .filter(|(_, selected)| **selected)
+            .flat_map(|(face, _)| {
                 let face_points = index_as_points(face, &self.points);
                 let centroid = centroid_ref(&face_points);
-                // println!("{:?}", ep);
-                let mut result = Vec::new();
-                face.iter().enumerate().for_each(|face_point| {
-                    let j = face_point.0;
-                    let mut new_face = face.clone();
-                    new_face.push(face[j]);
-                    result.push((
-                        new_face,
-                        *face_points[j] + ratio_ * (centroid - *face_points[j]),
-                    ))
-                });
-                result
+                let offset = face_normal(&face_points).unwrap() * height_;
+                face.iter()
+                    .zip(&face_points)
+                    .map(|face_vertex_point| {
+                        (
+                            extend![..face, *face_vertex_point.0],
+                            **face_vertex_point.1
+                                + ratio_ * (centroid - **face_vertex_point.1)
+                                + offset,
+                        )
+                    })
+                    .collect::<Vec<_>>()
             })
-            .collect();
+            .collect::<Vec<_>>();
 
         let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
 
-        let face_index: Faces = self
+        // The extruded faces replace the original ones in-place so existing
+        // face sets stay valid.
+        let side_faces: Faces = self
             .face_index
             .par_iter()
-            .map(|face| {
-                // FIXME: use iterators with double collect
-                let mut new_face = Vec::with_capacity(face.len());
-                face.iter().for_each(|vertex_key| {
-                    let mut face_key = face.clone();
-                    face_key.push(*vertex_key);
-                    new_face.push(vertex(&face_key, &new_ids).unwrap());
-                });
-                new_face
-            })
-            .chain(self.face_index.par_iter().flat_map(|face| {
+            .zip(selected.par_iter())
+            .filter(|(_, selected)| **selected)
+            .flat_map(|(face, _)| {
                 face.iter()
-                    .circular_tuple_windows::<(_, _, _)>()
-                    .filter_map(|v| {
-                        if v.0 < v.1 {
-                            let a: VertexKey = *v.0;
-                            let b: VertexKey = *v.1;
-                            let opposite_face = face_with_edge(&[b, a], &self.face_index);
-
-                            /*once(a)
-                            .chain(once(vertex(&extend![..opposite_face, a], &new_ids).unwrap()))
-                            .chain(once(vertex(&extend![..opposite_face, b], &new_ids).unwrap()))
-                            .chain(once(b))
-                            .chain(once(vertex(&extend![..face, b], &new_ids).unwrap()))
-                            .chain(once(vertex(&extend![..face, a], &new_ids).unwrap()))*/
-
-                            Some(vec![
-                                a,
-                                vertex(&extend![..opposite_face, a], &new_ids).unwrap(),
-                                vertex(&extend![..opposite_face, b], &new_ids).unwrap(),
-                                b,
-                                vertex(&extend![..face, b], &new_ids).unwrap(),
-                                vertex(&extend![..face, a], &new_ids).unwrap(),
-                            ])
-                        } else {
-                            None
-                        }
+                    .circular_tuple_windows::<(_, _)>()
+                    .map(|v| {
+                        let inset_a = vertex(&extend![..face, *v.0], &new_ids).unwrap();
+                        let inset_b = vertex(&extend![..face, *v.1], &new_ids).unwrap();
+                        vec![*v.0, *v.1, inset_b, inset_a]
                     })
                     .collect::<Faces>()
-            }))
-            .collect::<Faces>();
+            })
+            .collect();
+
+        self.face_index = self
+            .face_index
+            .par_iter()
+            .zip(selected.par_iter())
+            .map(|(face, selected)| {
+                if *selected {
+                    face.iter()
+                        .map(|v| vertex(&extend![..face, *v], &new_ids).unwrap())
+                        .collect()
+                } else {
+                    face.clone()
+                }
+            })
+            .collect();
 
-        self.append_new_face_set(face_index.len());
+        self.append_new_face_set(side_faces.len());
+        self.face_index.extend(side_faces);
 
-        self.face_index = face_index;
-        self.points.par_iter_mut().for_each(|point| {
-            *point = (1.5 * ratio_) * *point;
-        });
-        self.points.extend(vertex_values(&new_points));
+        self.points.extend(vertex_values_as_ref(&new_points));
 
         if change_name {
             let mut params = String::new();
+            if let Some(height) = height {
+                write!(&mut params, "{:.2}", height).unwrap();
+            }
             if let Some(ratio) = ratio {
-                write!(&mut params, "{:.2}", ratio).unwrap();
+                write!(&mut params, ",{:.2}", ratio).unwrap();
+            } else {
+                write!(&mut params, ",").unwrap();
             }
-            self.name = format!("c{}{}", params, self.name);
+            if let Some(face_selector) = face_selector {
+                write!(&mut params, ",{}", face_selector).unwrap();
+            } else {
+                write!(&mut params, ",").unwrap();
+            }
+            params = params.trim_end_matches(',').to_string();
+            self.name = format!("x{}{}", params, self.name);
         }
 
         self
     }
 
-    /// Replaces each face with a vertex, and each vertex with a face.
-    pub fn dual(&mut self, change_name: bool) -> &mut Self {
-        let new_points = face_centers(&self.face_index, &self.points);
-        self.face_index = points_to_faces(&self.points, &self.face_index);
-        self.points = new_points;
-        // FIXME: FaceSetIndex
+    /// Inserts a smaller copy of each face, rotated so its vertices face
+    /// the edges, and connects it to the original face with an
+    /// antiprismatic band of triangles. Original edges are retained.
+    /// # Arguments
+    /// * `ratio` – How far the inner face reaches towards the edges. Will be
+    ///   clamped to `[0, 1]`. Default value is `0.5`.
+    pub fn lace(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
+        self.lace_band(ratio, LaceBand::Edge);
 
         if change_name {
-            self.name = format!("d{}", self.name);
+            let mut params = String::new();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
+            }
+            self.name = format!("L{}{}", params, self.name);
         }
 
         self
     }
 
-    pub fn expand(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
-        self.ambo(ratio, false);
-        self.ambo(ratio, false);
+    /// Like [`lace()`](Polyhedron::lace()) but the two triangles meeting at
+    /// each original edge are joined into a quadrilateral. Original edges
+    /// are discarded.
+    /// # Arguments
+    /// * `ratio` – How far the inner face reaches towards the edges. Will be
+    ///   clamped to `[0, 1]`. Default value is `0.5`.
+    pub fn joined_lace(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
+        self.lace_band(ratio, LaceBand::Joined);
 
         if change_name {
             let mut params = String::new();
             if let Some(ratio) = ratio {
                 write!(&mut params, "{:.2}", ratio).unwrap();
             }
-            self.name = format!("e{}{}", params, self.name);
+            self.name = format!("L0{}{}", params, self.name);
         }
 
         self
     }
 
-    /// Splits each edge and connects new edges at the split point to the face
-    /// centroid. Existing points are retained.
-    /// ![Gyro](https://upload.wikimedia.org/wikipedia/commons/thumb/f/f6/Conway_gC.png/200px-Conway_gC.png)
+    /// Like [`joined_lace()`](Polyhedron::joined_lace()) but each
+    /// quadrilateral is split into two triangles along the diagonal across
+    /// the original edge.
     /// # Arguments
-    /// * `ratio` – The ratio at which the adjacent edges get split.
-    /// * `height` – An offset to add to the face centroid point along the face
-    ///   normal.
-    /// * `regular_faces_only` – Only faces whose edges are 90% the same length,
-    ///   within the same face, are affected.
-    pub fn gyro(
-        &mut self,
-        ratio: Option<f32>,
-        height: Option<f32>,
-        change_name: bool,
-    ) -> &mut Self {
+    /// * `ratio` – How far the inner face reaches towards the edges. Will be
+    ///   clamped to `[0, 1]`. Default value is `0.5`.
+    pub fn opposite_lace(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
+        self.lace_band(ratio, LaceBand::Opposite);
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
+            }
+            self.name = format!("L'{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// The lace core shared by [`lace()`](Polyhedron::lace()),
+    /// [`joined_lace()`](Polyhedron::joined_lace()) and
+    /// [`opposite_lace()`](Polyhedron::opposite_lace()).
+    fn lace_band(&mut self, ratio: Option<Float>, band: LaceBand) {
         let ratio_ = match ratio {
             Some(r) => r.clamp(0.0, 1.0),
-            None => 1. / 3.,
+            None => 1. / 2.,
         };
-        let height_ = height.unwrap_or(0.);
-
-        let edges = self.to_edges();
-        let reversed_edges: Edges = edges.par_iter().map(|edge| [edge[1], edge[0]]).collect();
 
-        // Retain original points, add face centroids and directed
-        // edge points each N-face becomes N pentagons.
-        let new_points: Vec<(&FaceSlice, Point)> = self
+        // One inner point per face and edge, keyed by the face and the
+        // start of the edge.
+        let new_points: Vec<(Face, Point)> = self
             .face_index
             .par_iter()
-            .map(|face| {
-                let fp = index_as_points(face, &self.points);
-                (
-                    face.as_slice(),
-                    centroid_ref(&fp).normalized() + face_normal(&fp).unwrap() * height_,
-                )
+            .flat_map(|face| {
+                let face_points = index_as_points(face, &self.points);
+                let centroid = centroid_ref(&face_points);
+                face.iter()
+                    .zip(face_points.iter().circular_tuple_windows::<(_, _)>())
+                    .map(|(a, (point_a, point_b))| {
+                        let midpoint = (**point_a + **point_b) * 0.5;
+                        (
+                            extend![..face, *a],
+                            centroid + ratio_ * (midpoint - centroid),
+                        )
+                    })
+                    .collect::<Vec<_>>()
             })
-            .chain(edges.par_iter().enumerate().flat_map(|edge| {
-                let edge_points = index_as_points(edge.1, &self.points);
-                vec![
-                    (
-                        &edge.1[..],
-                        *edge_points[0] + ratio_ * (*edge_points[1] - *edge_points[0]),
-                    ),
-                    (
-                        &reversed_edges[edge.0][..],
-                        *edge_points[1] + ratio_ * (*edge_points[0] - *edge_points[1]),
-                    ),
-                ]
-            }))
             .collect();
 
-        let new_ids = vertex_ids_ref_ref(&new_points, self.points_len() as VertexKey);
+        let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
 
-        self.points.extend(vertex_values_as_ref(&new_points));
+        let inner = |face: &FaceSlice, a: VertexKey| -> VertexKey {
+            vertex(&extend![..face.to_vec(), a], &new_ids).unwrap()
+        };
 
-        self.face_index = self
+        // The inner faces replace the original ones in-place so existing
+        // face sets stay valid.
+        let inner_faces: Faces = self
+            .face_index
+            .par_iter()
+            .map(|face| face.iter().map(|a| inner(face, *a)).collect())
+            .collect();
+
+        let vertex_faces: Faces = self
             .face_index
             .par_iter()
             .flat_map(|face| {
                 face.iter()
-                    .cycle()
-                    .skip(face.len() - 1)
-                    .tuple_windows::<(_, _, _)>()
-                    .take(face.len())
-                    .map(|v| {
-                        let a = *v.1;
-                        let b = *v.2;
-                        let z = *v.0;
-                        let eab = vertex(&[a, b], &new_ids).unwrap();
-                        let eza = vertex(&[z, a], &new_ids).unwrap();
-                        let eaz = vertex(&[a, z], &new_ids).unwrap();
-                        let centroid = vertex(face, &new_ids).unwrap();
-                        vec![a, eab, centroid, eza, eaz]
-                    })
+                    .circular_tuple_windows::<(_, _)>()
+                    .map(|(a, b)| vec![*b, inner(face, *b), inner(face, *a)])
                     .collect::<Faces>()
             })
             .collect();
 
+        let edge_faces: Faces = self
+            .to_edges()
+            .par_iter()
+            .flat_map(|edge| {
+                let face = face_with_edge(edge, &self.face_index);
+                let opposite_face = face_with_edge(&[edge[1], edge[0]], &self.face_index);
+                let (a, b) = (edge[0], edge[1]);
+                match (face.is_empty(), opposite_face.is_empty()) {
+                    (false, false) => {
+                        let inner_ab = inner(&face, a);
+                        let inner_ba = inner(&opposite_face, b);
+                        match band {
+                            LaceBand::Edge => vec![vec![a, b, inner_ab], vec![b, a, inner_ba]],
+                            LaceBand::Joined => vec![vec![a, inner_ba, b, inner_ab]],
+                            LaceBand::Opposite => {
+                                vec![vec![a, inner_ba, inner_ab], vec![inner_ba, b, inner_ab]]
+                            }
+                        }
+                    }
+                    // Edges on the boundary of open meshes.
+                    (false, true) => vec![vec![a, b, inner(&face, a)]],
+                    (true, false) => vec![vec![b, a, inner(&opposite_face, b)]],
+                    (true, true) => Vec::new(),
+                }
+            })
+            .collect();
+
+        self.face_index = inner_faces;
+        self.append_new_face_set(vertex_faces.len());
+        self.face_index.extend(vertex_faces);
+        self.append_new_face_set(edge_faces.len());
+        self.face_index.extend(edge_faces);
+
+        self.points.extend(vertex_values(&new_points));
+    }
+
+    /// Applies [`kis()`](Polyhedron::kis()), [`dual()`](Polyhedron::dual())
+    /// and [`ambo()`](Polyhedron::ambo()), in that order.
+    /// # Arguments
+    /// * `face_selector` – Only selected faces will be affected by the
+    ///   [`kis()`](Polyhedron::kis()).
+    pub fn medial(
+        &mut self,
+        ratio: Option<Float>,
+        height: Option<Float>,
+        face_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        self.kis(height, face_selector.clone(), false);
+        self.dual(false);
+        self.ambo(ratio, false);
+
         if change_name {
             let mut params = String::new();
             if let Some(ratio) = ratio {
@@ -641,149 +4021,169 @@ impl Polyhedron {
             }
             if let Some(height) = height {
                 write!(&mut params, ",{:.2}", height).unwrap();
+            } else {
+                write!(&mut params, ",").unwrap();
             }
-            self.name = format!("g{}{}", params, self.name);
+            if let Some(face_selector) = &face_selector {
+                write!(&mut params, ",{}", face_selector).unwrap();
+            }
+            params = params.trim_end_matches(',').to_string();
+            self.name = format!("M{}{}", params, self.name);
         }
 
         self
     }
 
-    /// Creates quadrilateral faces around each original edge. Original
-    /// edges are discarded.
+    /// Applies [`kis()`](Polyhedron::kis()) and
+    /// [`join()`](Polyhedron::join()), in that order.
     /// # Arguments
-    /// * `ratio` – The ratio at which the adjacent edges get split. Will be
-    ///   clamped to `[0, 1]`. Default value is `0.5`.
-    pub fn join(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
-        self.dual(false);
-        self.ambo(ratio, false);
-        self.dual(false);
+    /// * `face_selector` – Only selected faces will be affected by the
+    ///   [`kis()`](Polyhedron::kis()). Default is to select triangles.
+    pub fn meta(
+        &mut self,
+        join_height: Option<Float>,
+        height: Option<Float>,
+        face_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        self.kis(
+            height,
+            Some(
+                face_selector
+                    .clone()
+                    .unwrap_or_else(|| FaceSelector::Arity(vec![3])),
+            ),
+            false,
+        );
+        self.join(None, join_height, false);
 
         if change_name {
             let mut params = String::new();
-            if let Some(ratio) = ratio {
-                write!(&mut params, "{:.2}", ratio).unwrap();
+            if let Some(join_height) = join_height {
+                write!(&mut params, "{:.2}", join_height).unwrap();
             }
-            self.name = format!("j{}{}", params, self.name);
+            if let Some(height) = height {
+                write!(&mut params, ",{:.2}", height).unwrap();
+            } else {
+                write!(&mut params, ",").unwrap();
+            }
+            if let Some(face_selector) = &face_selector {
+                write!(&mut params, ",{}", face_selector).unwrap();
+            }
+            params = params.trim_end_matches(',').to_string();
+            self.name = format!("m{}{}", params, self.name);
         }
 
         self
     }
 
-    /// Splits each face into triangles, one for each edge, which
-    /// extend to the face centroid. Existing points are retained.
+    /// Splits each face of the dual into triangles meeting at the face
+    /// centroid, i.e. each original vertex becomes a fan of triangles.
+    ///
     /// # Arguments
-    /// * `height` - An offset to add to the face centroid point along the face
-    ///   normal.
-    /// * `face_arity` - Only faces matching the given arities will be affected.
-    /// * `regular_faces_only` - Only faces whose edges are 90% the same length,
-    ///   within the same face, are affected.
-    pub fn kis(
+    /// * `vertex_selector` – Only selected vertices will be affected. See
+    ///   [`FaceSelector`].
+    pub fn needle(
         &mut self,
         height: Option<Float>,
-        face_arity: Option<Vec<usize>>,
-        regular_faces_only: Option<bool>,
+        vertex_selector: Option<FaceSelector>,
         change_name: bool,
     ) -> &mut Self {
-        let new_points: Vec<(&FaceSlice, Point)> = self
-            .face_index
-            .par_iter()
-            .filter_map(|face| {
-                if selected_face(face, face_arity.as_ref()) && !regular_faces_only.unwrap_or(false)
-                    || ((face_irregularity(face, &self.points) - 1.0).abs() < 0.1)
-                {
-                    let face_points = index_as_points(face, &self.points);
-                    Some((
-                        face.as_slice(),
-                        centroid_ref(&face_points)
-                            + face_normal(&face_points).unwrap() * height.unwrap_or(0.),
-                    ))
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        let new_ids = vertex_ids_ref_ref(&new_points, self.points.len() as VertexKey);
-
-        self.points.extend(vertex_values_as_ref(&new_points));
-
-        self.face_index = self
-            .face_index
-            .par_iter()
-            .flat_map(|face: &Face| match vertex(face, &new_ids) {
-                Some(centroid) => face
-                    .iter()
-                    .cycle()
-                    .tuple_windows::<(&VertexKey, _)>()
-                    .map(|v| vec![*v.0, *v.1, centroid as VertexKey])
-                    .take(face.len())
-                    .collect(),
-                None => vec![face.clone()],
-            })
-            .collect();
+        self.dual(false);
+        // The faces of the dual have the same index as the original vertices.
+        let selected = self.selected_faces(vertex_selector.as_ref());
+        self.kis_faces(&KisApex::Normal(height.unwrap_or(0.)), &selected);
 
         if change_name {
             let mut params = String::new();
             if let Some(height) = height {
                 write!(&mut params, "{:.2}", height).unwrap();
             }
-            if let Some(face_arity) = face_arity {
-                write!(&mut params, ",{:.2}", format_vec(&face_arity)).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
-            }
-            if let Some(regular_faces_only) = regular_faces_only {
-                if regular_faces_only {
-                    params.push_str(",{t}");
-                }
-            } else {
-                write!(&mut params, ",").unwrap();
+            if let Some(vertex_selector) = &vertex_selector {
+                write!(&mut params, ",{}", vertex_selector).unwrap();
             }
             params = params.trim_end_matches(',').to_string();
-            self.name = format!("k{}{}", params, self.name);
+            self.name = format!("n{}{}", params, self.name);
         }
 
         self
     }
 
-    // Inset faces by `distance` from the original edges.
-    pub fn inset(
+    /// Applies [`join()`](Polyhedron::join()) twice.
+    /// # Arguments
+    /// * `ratio` – The ratio of the first join pass.
+    /// * `second_ratio` – The ratio of the second join pass. Defaults to
+    ///   `ratio`.
+    /// * `height` – The height of the first join pass.
+    /// * `second_height` – The height of the second join pass. Defaults to
+    ///   `height`.
+    pub fn ortho(
         &mut self,
-        distance: Option<Float>,
-        face_arity: Option<Vec<usize>>,
+        ratio: Option<Float>,
+        second_ratio: Option<Float>,
+        height: Option<Float>,
+        second_height: Option<Float>,
         change_name: bool,
     ) -> &mut Self {
+        self.join(ratio, height, false);
+        self.join(second_ratio.or(ratio), second_height.or(height), false);
+
         if change_name {
             let mut params = String::new();
-            if let Some(distance) = distance {
-                write!(&mut params, "{:.2}", distance).unwrap();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
             }
-            if let Some(face_arity) = &face_arity {
-                write!(&mut params, ",{}", format_vec(&face_arity)).unwrap();
+            if let Some(second_ratio) = second_ratio {
+                write!(&mut params, ",{:.2}", second_ratio).unwrap();
+            } else {
+                write!(&mut params, ",").unwrap();
             }
-            self.name = format!("i{}{}", params, self.name);
+            if let Some(height) = height {
+                write!(&mut params, ",{:.2}", height).unwrap();
+            } else {
+                write!(&mut params, ",").unwrap();
+            }
+            if let Some(second_height) = second_height {
+                write!(&mut params, ",{:.2}", second_height).unwrap();
+            }
+            params = params.trim_end_matches(',').to_string();
+            self.name = format!("o{}{}", params, self.name);
         }
 
-        self.extrude(Some(0.0), Some(distance.unwrap_or(0.3)), face_arity, false);
-
         self
     }
 
-    // Extrudes faces by `height` and shrinks the extruded faces by `distance` from
-    // the original edges.
-    pub fn extrude(
+    /// Cuts a hole into each selected face. The hole is a scaled copy of the
+    /// face, the remaining frame is made up of one quadrilateral per edge.
+    ///
+    /// Unlike hollow, no inner shell is generated. The result is an open
+    /// mesh.
+    ///
+    /// The frames go into a new face set. The other faces keep their face
+    /// sets.
+    /// # Arguments
+    /// * `ratio` – The size of the hole, relative to the face. Will be clamped
+    ///   to `[0, 1]`. Default value is `0.5`.
+    /// * `face_selector` – Only selected faces will be affected.
+    pub fn perforate(
         &mut self,
-        height: Option<Float>,
-        distance: Option<Float>,
-        face_arity: Option<Vec<usize>>,
+        ratio: Option<Float>,
+        face_selector: Option<FaceSelector>,
         change_name: bool,
     ) -> &mut Self {
+        let ratio_ = match ratio {
+            Some(r) => r.clamp(0.0, 1.0),
+            None => 1. / 2.,
+        };
+
+        let selected = self.selected_faces(face_selector.as_ref());
 
         let new_points = self
             .face_index
             .par_iter()
-            .filter(|face| selected_face(face, face_arity.as_ref()))
-            .flat_map(|face| {
+            .zip(selected.par_iter())
+            .filter(|(_, selected)| **selected)
+            .flat_map(|(face, _)| {
                 let face_points = index_as_points(face, &self.points);
                 let centroid = centroid_ref(&face_points);
                 face.iter()
@@ -791,9 +4191,7 @@ impl Polyhedron {
                     .map(|face_vertex_point| {
                         (
                             extend![..face, *face_vertex_point.0],
-                            **face_vertex_point.1
-                                + distance.unwrap_or(0.0) * (centroid - **face_vertex_point.1)
-                                + face_normal(&face_points).unwrap() * height.unwrap_or(0.3),
+                            centroid + ratio_ * (**face_vertex_point.1 - centroid),
                         )
                     })
                     .collect::<Vec<_>>()
@@ -802,66 +4200,344 @@ impl Polyhedron {
 
         let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
 
-        self.face_index = self
+        let frame_faces: Faces = self
+            .face_index
+            .par_iter()
+            .zip(selected.par_iter())
+            .filter(|(_, selected)| **selected)
+            .flat_map(|(face, _)| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .map(|v| {
+                        let inset_a = vertex(&extend![..face, *v.0], &new_ids).unwrap();
+                        let inset_b = vertex(&extend![..face, *v.1], &new_ids).unwrap();
+                        vec![*v.0, *v.1, inset_b, inset_a]
+                    })
+                    .collect::<Faces>()
+            })
+            .collect();
+
+        self.points.extend(vertex_values_as_ref(&new_points));
+
+        // The frames go into a new face set. The perforated faces are then
+        // removed from their face sets.
+        self.append_new_face_set(frame_faces.len());
+        self.face_index.extend(frame_faces);
+        let mut removed = selected;
+        removed.resize(self.face_index.len(), false);
+        self.remove_selected_faces(&removed);
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
+            }
+            if let Some(face_selector) = face_selector {
+                write!(&mut params, ",{}", face_selector).unwrap();
+            }
+            self.name = format!("f{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Creates a central, rotated copy of each face and a quadrilateral
+    /// “blade” for each original vertex of the face. Existing points are
+    /// retained.
+    /// # Arguments
+    /// * `ratio` – The ratio at which the adjacent edges get split. Will be
+    ///   clamped to `[0, 1]`. Default value is `1/3`.
+    /// * `order` – The number of concentric rotated rings generated per face.
+    ///   Default value is `1`.
+    ///
+    ///   Each additional ring is a band of quadrilaterals between the previous
+    ///   central face and a smaller copy of it, rotated by `ratio`.
+    pub fn propeller(
+        &mut self,
+        ratio: Option<Float>,
+        order: Option<usize>,
+        change_name: bool,
+    ) -> &mut Self {
+        let ratio_ = match ratio {
+            Some(r) => r.clamp(0.0, 1.0),
+            None => 1. / 3.,
+        };
+        let order_ = order.unwrap_or(1).max(1);
+
+        let edges = self.to_edges();
+        let reversed_edges: Edges = edges.iter().map(|edge| [edge[1], edge[0]]).collect();
+
+        let new_points = edges
+            .iter()
+            .zip(reversed_edges.iter())
+            .flat_map(|(edge, reversed_edge)| {
+                let edge_points = index_as_points(edge, &self.points);
+                vec![
+                    (
+                        edge,
+                        *edge_points[0] + ratio_ * (*edge_points[1] - *edge_points[0]),
+                    ),
+                    (
+                        reversed_edge,
+                        *edge_points[1] + ratio_ * (*edge_points[0] - *edge_points[1]),
+                    ),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let new_ids = vertex_ids_edge_ref_ref(&new_points, self.points_len() as VertexKey);
+
+        // The vertices of the inner rings, keyed by face, the face vertex
+        // starting the ring edge they split and the ring number.
+        let ring_points: Vec<(Face, Point)> = self
             .face_index
             .par_iter()
             .flat_map(|face| {
-                if selected_face(face, face_arity.as_ref()) {
-                    face.iter()
-                        .enumerate()
-                        .flat_map(|index_vertex| {
-                            let a = *index_vertex.1;
-                            let inset_a = vertex(&extend![..face, a], &new_ids).unwrap();
-                            let b = face[(index_vertex.0 + 1) % face.len()];
-                            let inset_b = vertex(&extend![..face, b], &new_ids).unwrap();
-                            vec![vec![a, b, inset_b, inset_a]]
-                        })
-                        .chain(vec![face
+                let centroid = centroid_ref(&index_as_points(face, &self.points));
+                let mut ring = face
+                    .iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .map(|v| *vertex_edge_point(&[*v.0, *v.1], &new_points).unwrap())
+                    .collect::<Vec<_>>();
+
+                (2..=order_)
+                    .flat_map(|j| {
+                        let shrink = 1.0 / (order_ - j + 2) as Float;
+                        ring = ring
                             .iter()
-                            .map(|v| vertex(&extend![..face, *v], &new_ids).unwrap())
-                            .collect::<Vec<_>>()])
-                        .collect::<Vec<_>>()
+                            .circular_tuple_windows::<(_, _)>()
+                            .map(|p| {
+                                let edge_point = *p.0 + ratio_ * (*p.1 - *p.0);
+                                edge_point + shrink * (centroid - edge_point)
+                            })
+                            .collect();
+                        face.iter()
+                            .zip(ring.iter())
+                            .map(|(v, p)| (extend![..face, *v, j as VertexKey], *p))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let ring_ids = vertex_ids_ref(
+            &ring_points,
+            (self.points_len() + new_points.len()) as VertexKey,
+        );
+
+        let ring_vertex = |face: &FaceSlice, i: usize, j: usize| -> VertexKey {
+            if 1 == j {
+                vertex_edge(&[face[i], face[(i + 1) % face.len()]], &new_ids).unwrap()
+            } else {
+                vertex(
+                    &extend![..face.to_vec(), face[i], j as VertexKey],
+                    &ring_ids,
+                )
+                .unwrap()
+            }
+        };
+
+        self.face_index = self
+            .face_index
+            .par_iter()
+            .map(|face| {
+                (0..face.len())
+                    .map(|i| ring_vertex(face, i, order_))
+                    .collect()
+            })
+            .chain(self.face_index.par_iter().flat_map(|face| {
+                (0..face.len())
+                    .map(|j| {
+                        let a = face[j];
+                        let b = face[(j + 1) % face.len()];
+                        let z = face[(j + face.len() - 1) % face.len()];
+                        let eab = vertex_edge(&[a, b], &new_ids).unwrap();
+                        let eaz = vertex_edge(&[a, z], &new_ids).unwrap();
+                        let eza = vertex_edge(&[z, a], &new_ids).unwrap();
+                        vec![eza, eaz, a, eab]
+                    })
+                    .chain((2..=order_).flat_map(|j| {
+                        (0..face.len())
+                            .map(|i| {
+                                vec![
+                                    ring_vertex(face, i, j - 1),
+                                    ring_vertex(face, (i + 1) % face.len(), j - 1),
+                                    ring_vertex(face, i, j),
+                                    ring_vertex(face, (i + face.len() - 1) % face.len(), j),
+                                ]
+                            })
+                            .collect::<Faces>()
+                    }))
+                    .collect::<Faces>()
+            }))
+            .collect::<Faces>();
+
+        self.points.extend(vertex_values_as_ref(&new_points));
+        self.points.extend(vertex_values(&ring_points));
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
+            }
+            if let Some(order) = order {
+                write!(&mut params, ",{}", order).unwrap();
+            }
+            self.name = format!("p{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Splits each face into a smaller copy of itself and one pentagon per
+    /// vertex.
+    /// # Arguments
+    /// * `ratio` – Where the new vertices land along each edge, from its
+    ///   start in the winding order of the first face that has the edge.
+    ///   Will be clamped to `[0, 1]`. Default value is `0.5` (the edge
+    ///   midpoint).
+    /// * `height` – Scales the new edge vertices away from the center. Will
+    ///   be clamped to non-negative values. Default value is `0.5`, which
+    ///   leaves them on the edge.
+    pub fn quinto(
+        &mut self,
+        ratio: Option<Float>,
+        height: Option<Float>,
+        change_name: bool,
+    ) -> &mut Self {
+        let ratio_ = match ratio {
+            Some(r) => r.clamp(0.0, 1.0),
+            None => 1. / 2.,
+        };
+        let height_ = match height {
+            Some(h) => {
+                if h < 0.0 {
+                    0.0
                 } else {
-                    vec![face.clone()]
+                    h
                 }
+            }
+            None => 0.5,
+        };
+
+        // Each edge is shared by two faces that wind around it in opposite
+        // directions. The first face decides which end `ratio` starts at.
+        let mut visited_edges = HashSet::new();
+        let mut new_points: Vec<(Face, Point)> = self
+            .face_index
+            .iter()
+            .flat_map(|face| face.iter().circular_tuple_windows::<(_, _)>())
+            .filter(|(a, b)| visited_edges.insert(distinct_edge(&[**a, **b])))
+            .map(|(a, b)| {
+                let (a_point, b_point) = (self.points[*a as usize], self.points[*b as usize]);
+                (
+                    distinct_edge(&[*a, *b]).to_vec(),
+                    2.0 * height_ * ((1.0 - ratio_) * a_point + ratio_ * b_point),
+                )
             })
             .collect();
 
+        new_points.extend(
+            self.face_index
+                .par_iter()
+                .flat_map(|face| {
+                    let edge_points = index_as_points(face, &self.points);
+                    let centroid = centroid_ref(&edge_points);
+                    (0..face.len())
+                        .map(|i| {
+                            (
+                                extend![..face, i as VertexKey],
+                                (*edge_points[i] + *edge_points[(i + 1) % face.len()] + centroid)
+                                    / 3.,
+                            )
+                        })
+                        .collect::<Vec<(Face, Point)>>()
+                })
+                .collect::<Vec<(Face, Point)>>(),
+        );
+
+        let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
+
         self.points.extend(vertex_values_as_ref(&new_points));
 
+        self.face_index = self
+            .face_index
+            .par_iter()
+            .map(|face| {
+                (0..face.len())
+                    .map(|face_vertex| {
+                        vertex(&extend![..face, face_vertex as VertexKey], &new_ids).unwrap()
+                    })
+                    .collect()
+            })
+            .chain(self.face_index.par_iter().flat_map(|face| {
+                (0..face.len())
+                    .map(|i| {
+                        let v = face[i];
+                        let e0 = [face[(i + face.len() - 1) % face.len()], face[i]];
+                        let e1 = [face[i], face[(i + 1) % face.len()]];
+                        let e0p = vertex(&distinct_edge(&e0), &new_ids).unwrap();
+                        let e1p = vertex(&distinct_edge(&e1), &new_ids).unwrap();
+                        let iv0 = vertex(
+                            &extend![..face, ((i + face.len() - 1) % face.len()) as VertexKey],
+                            &new_ids,
+                        )
+                        .unwrap();
+                        let iv1 = vertex(&extend![..face, i as VertexKey], &new_ids).unwrap();
+                        vec![v, e1p, iv1, iv0, e0p]
+                    })
+                    .collect::<Faces>()
+            }))
+            .collect::<Faces>();
+
         if change_name {
             let mut params = String::new();
-            if let Some(height) = height {
-                write!(&mut params, "{:.2}", height).unwrap();
-            }
-            if let Some(distance) = distance {
-                write!(&mut params, ",{:.2}", distance).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
+            if let Some(ratio) = ratio {
+                write!(&mut params, "{:.2}", ratio).unwrap();
             }
-            if let Some(face_arity) = face_arity {
-                write!(&mut params, ",{}", format_vec(&face_arity)).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
+            if let Some(height) = height {
+                write!(&mut params, ",{:.2}", height).unwrap();
             }
-            params = params.trim_end_matches(',').to_string();
-            self.name = format!("x{}{}", params, self.name);
+            self.name = format!("q{}{}", params, self.name);
         }
 
         self
     }
 
-    pub fn medial(
+    /// Mirrors the polyhedron across the *xz* plane.
+    ///
+    /// This is the same as
+    /// `reflect_plane(Point::zero(), Vector::unit_y(), change_name)`.
+    pub fn reflect(&mut self, change_name: bool) -> &mut Self {
+        self.reflect_plane(Point::zero(), Vector::unit_y(), change_name)
+    }
+
+    /// Mirrors the polyhedron across the plane through `point` with the
+    /// given `normal`. The winding order of the faces is reversed to keep
+    /// them facing outwards.
+    pub fn reflect_plane(&mut self, point: Point, normal: Vector, change_name: bool) -> &mut Self {
+        let normal = normal.normalized();
+        self.points.par_iter_mut().for_each(|v| {
+            *v -= 2.0 * (*v - point).dot(normal) * normal;
+        });
+        self.reverse();
+
+        if change_name {
+            self.name = format!("r{}", self.name);
+        }
+
+        self
+    }
+
+    pub fn snub(
         &mut self,
         ratio: Option<Float>,
         height: Option<Float>,
-        vertex_valence: Option<Vec<usize>>,
-        regular_faces_only: Option<bool>,
         change_name: bool,
     ) -> &mut Self {
         self.dual(false);
-        self.truncate(height, vertex_valence.clone(), regular_faces_only, false);
-        self.ambo(ratio, false);
+        self.gyro(ratio, height, None, false);
+        self.dual(false);
 
         if change_name {
             let mut params = String::new();
@@ -870,400 +4546,731 @@ impl Polyhedron {
             }
             if let Some(height) = height {
                 write!(&mut params, ",{:.2}", height).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
-            }
-            if let Some(vertex_valence) = vertex_valence {
-                write!(&mut params, ",{}", format_vec(&vertex_valence)).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
-            }
-            if let Some(regular_faces_only) = regular_faces_only {
-                if regular_faces_only {
-                    params.push_str(",{t}");
-                }
-            } else {
-                write!(&mut params, ",").unwrap();
             }
-            params = params.trim_end_matches(',').to_string();
-            self.name = format!("M{}{}", params, self.name);
+            self.name = format!("s{}{}", params, self.name);
         }
 
         self
     }
 
-    pub fn meta(
+    /// Displaces each point by a pseudo-random offset of up to `amplitude`
+    /// (default: 0.1).
+    ///
+    /// The offsets only depend on `seed` (default: 0) and the index of the
+    /// point. The same seed always gives the same result.
+    ///
+    /// A point at the origin has no direction towards the origin. For
+    /// [`Radial`](JitterDirection::Radial) and
+    /// [`Tangential`](JitterDirection::Tangential) jitter it uses the
+    /// z-axis instead.
+    pub fn jitter(
         &mut self,
-        ratio: Option<Float>,
-        height: Option<Float>,
-        vertex_valence: Option<Vec<usize>>,
-        regular_faces_only: Option<bool>,
+        amplitude: Option<Float>,
+        seed: Option<u64>,
+        direction: Option<JitterDirection>,
         change_name: bool,
     ) -> &mut Self {
-        self.kis(
-            height,
-            match vertex_valence {
-                // By default meta works on vertices of valence three.
-                None => Some(vec![3]),
-                _ => vertex_valence.clone(),
-            },
-            regular_faces_only,
-            false,
-        );
-        self.join(ratio, false);
+        let amplitude_ = amplitude.unwrap_or(0.1);
+        let seed_ = seed.unwrap_or(0);
+        let direction_ = direction.unwrap_or(JitterDirection::All);
+
+        self.points
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(index, point)| {
+                let offset = random_in_unit_ball(seed_, index as u64);
+                let radial = if EPSILON < point.mag_sq() {
+                    point.normalized()
+                } else {
+                    Vector::unit_z()
+                };
+                *point += amplitude_
+                    * match direction_ {
+                        JitterDirection::All => offset,
+                        JitterDirection::Radial => offset.x * radial,
+                        JitterDirection::Tangential => offset - offset.dot(radial) * radial,
+                    };
+            });
 
         if change_name {
             let mut params = String::new();
-            if let Some(ratio) = ratio {
-                write!(&mut params, "{:.2}", ratio).unwrap();
-            }
-            if let Some(height) = height {
-                write!(&mut params, ",{:.2}", height).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
+            if let Some(amplitude) = amplitude {
+                write!(&mut params, "{:.2}", amplitude).unwrap();
             }
-            if let Some(vertex_valence) = vertex_valence {
-                write!(&mut params, ",{}", format_vec(&vertex_valence)).unwrap();
+            if let Some(seed) = seed {
+                write!(&mut params, ",{}", seed).unwrap();
             } else {
                 write!(&mut params, ",").unwrap();
             }
-            if let Some(regular_faces_only) = regular_faces_only {
-                if regular_faces_only {
-                    params.push_str(",{t}");
-                }
-            } else {
-                write!(&mut params, ",").unwrap();
+            match direction {
+                Some(JitterDirection::Radial) => write!(&mut params, ",r").unwrap(),
+                Some(JitterDirection::Tangential) => write!(&mut params, ",t").unwrap(),
+                _ => (),
             }
             params = params.trim_end_matches(',').to_string();
-            self.name = format!("m{}{}", params, self.name);
+            self.name = format!("N{}{}", params, self.name);
         }
 
         self
     }
 
-    pub fn needle(
-        &mut self,
-        height: Option<Float>,
-        vertex_valence: Option<Vec<usize>>,
-        regular_faces_only: Option<bool>,
-        change_name: bool,
-    ) -> &mut Self {
-        self.dual(false);
-        self.truncate(height, vertex_valence.clone(), regular_faces_only, false);
+    /// Blends all points between their current position (at `strength`
+    /// `0.0`) and their projection on the circumscribed sphere (at
+    /// `strength` `1.0`, the default).
+    ///
+    /// If `strength` is zero this is a no-op and will neither change the
+    /// geometry nor the name. Even if `change_name` is `true`.
+    pub fn spherize(&mut self, strength: Option<Float>, change_name: bool) -> &mut Self {
+        let strength_ = strength.unwrap_or(1.0);
 
-        if change_name {
-            let mut params = String::new();
-            if let Some(height) = height {
-                write!(&mut params, "{:.2}", height).unwrap();
-            }
-            if let Some(vertex_valence) = vertex_valence {
-                write!(&mut params, ",{}", format_vec(&vertex_valence)).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
-            }
-            if let Some(regular_faces_only) = regular_faces_only {
-                if regular_faces_only {
-                    params.push_str(",{t}");
+        if 0.0 != strength_ {
+            let radius = max_magnitude(&self.points);
+            project_on_sphere(&mut self.points, radius, strength_);
+
+            if change_name {
+                let mut params = String::new();
+                if let Some(strength) = strength {
+                    write!(&mut params, "{:.2}", strength).unwrap();
                 }
-            } else {
-                write!(&mut params, ",").unwrap();
+                self.name = format!("S{}{}", params, self.name);
             }
-            params = params.trim_end_matches(',').to_string();
-            self.name = format!("n{}{}", params, self.name);
         }
 
         self
     }
 
-    pub fn ortho(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
-        self.join(ratio, false);
-        self.join(ratio, false);
+    /// Splits each edge into `order` segments.
+    ///
+    /// Triangles are subdivided into a grid of `order`² triangles and
+    /// quadrilaterals into a grid of `order`² quadrilaterals. Faces of any
+    /// other arity keep their shape and just get the new edge vertices
+    /// inserted into their boundary.
+    /// # Arguments
+    /// * `order` – The number of segments each edge is split into. Default
+    ///   value is `2`.
+    pub fn subdivide(&mut self, order: Option<usize>, change_name: bool) -> &mut Self {
+        let n = order.unwrap_or(2).max(1);
+
+        if 1 < n {
+            let edges = self.to_edges();
+
+            let new_points: Vec<(Face, Point)> = edges
+                .par_iter()
+                .flat_map(|edge| {
+                    let edge_points = index_as_points(edge, &self.points);
+                    (1..n)
+                        .map(|i| {
+                            (
+                                vec![edge[0], edge[1], i as VertexKey],
+                                *edge_points[0]
+                                    + (i as Float / n as Float)
+                                        * (*edge_points[1] - *edge_points[0]),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .chain(self.face_index.par_iter().flat_map(|face| {
+                    let face_points = index_as_points(face, &self.points);
+                    match face.len() {
+                        3 => (1..n)
+                            .flat_map(|i| (1..n - i).map(move |j| (i, j)))
+                            .map(|(i, j)| {
+                                (
+                                    extend![..face, i as VertexKey, j as VertexKey],
+                                    *face_points[0]
+                                        + (i as Float / n as Float)
+                                            * (*face_points[1] - *face_points[0])
+                                        + (j as Float / n as Float)
+                                            * (*face_points[2] - *face_points[0]),
+                                )
+                            })
+                            .collect::<Vec<_>>(),
+                        4 => (1..n)
+                            .flat_map(|i| (1..n).map(move |j| (i, j)))
+                            .map(|(i, j)| {
+                                let s = i as Float / n as Float;
+                                let t = j as Float / n as Float;
+                                let bottom =
+                                    *face_points[0] + s * (*face_points[1] - *face_points[0]);
+                                let top = *face_points[3] + s * (*face_points[2] - *face_points[3]);
+                                (
+                                    extend![..face, i as VertexKey, j as VertexKey],
+                                    bottom + t * (top - bottom),
+                                )
+                            })
+                            .collect::<Vec<_>>(),
+                        _ => Vec::new(),
+                    }
+                }))
+                .collect();
+
+            let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
+
+            // The i-th of the n - 1 new vertices on the edge from a to b.
+            let edge_vertex = |a: VertexKey, b: VertexKey, i: usize| -> VertexKey {
+                if 0 == i {
+                    a
+                } else if n == i {
+                    b
+                } else if a < b {
+                    vertex(&[a, b, i as VertexKey], &new_ids).unwrap()
+                } else {
+                    vertex(&[b, a, (n - i) as VertexKey], &new_ids).unwrap()
+                }
+            };
+
+            self.face_index = self
+                .face_index
+                .par_iter()
+                .flat_map(|face| match face.len() {
+                    3 => {
+                        // Vertex at v0 + i/n (v1 - v0) + j/n (v2 - v0).
+                        let grid_vertex = |i: usize, j: usize| -> VertexKey {
+                            if 0 == j {
+                                edge_vertex(face[0], face[1], i)
+                            } else if 0 == i {
+                                edge_vertex(face[0], face[2], j)
+                            } else if n == i + j {
+                                edge_vertex(face[1], face[2], j)
+                            } else {
+                                vertex(&extend![..face, i as VertexKey, j as VertexKey], &new_ids)
+                                    .unwrap()
+                            }
+                        };
+
+                        (0..n)
+                            .flat_map(|i| (0..n - i).map(move |j| (i, j)))
+                            .flat_map(|(i, j)| {
+                                let mut triangles = vec![vec![
+                                    grid_vertex(i, j),
+                                    grid_vertex(i + 1, j),
+                                    grid_vertex(i, j + 1),
+                                ]];
+                                if i + j + 1 < n {
+                                    triangles.push(vec![
+                                        grid_vertex(i + 1, j),
+                                        grid_vertex(i + 1, j + 1),
+                                        grid_vertex(i, j + 1),
+                                    ]);
+                                }
+                                triangles
+                            })
+                            .collect::<Faces>()
+                    }
+                    4 => {
+                        // Vertex at the bilinear coordinates (i/n, j/n).
+                        let grid_vertex = |i: usize, j: usize| -> VertexKey {
+                            if 0 == j {
+                                edge_vertex(face[0], face[1], i)
+                            } else if n == i {
+                                edge_vertex(face[1], face[2], j)
+                            } else if n == j {
+                                edge_vertex(face[3], face[2], i)
+                            } else if 0 == i {
+                                edge_vertex(face[0], face[3], j)
+                            } else {
+                                vertex(&extend![..face, i as VertexKey, j as VertexKey], &new_ids)
+                                    .unwrap()
+                            }
+                        };
+
+                        (0..n)
+                            .flat_map(|i| (0..n).map(move |j| (i, j)))
+                            .map(|(i, j)| {
+                                vec![
+                                    grid_vertex(i, j),
+                                    grid_vertex(i + 1, j),
+                                    grid_vertex(i + 1, j + 1),
+                                    grid_vertex(i, j + 1),
+                                ]
+                            })
+                            .collect::<Faces>()
+                    }
+                    _ => vec![face
+                        .iter()
+                        .circular_tuple_windows::<(_, _)>()
+                        .flat_map(|v| (0..n).map(move |i| edge_vertex(*v.0, *v.1, i)))
+                        .collect()],
+                })
+                .collect();
+
+            self.points.extend(vertex_values(&new_points));
+        }
 
         if change_name {
             let mut params = String::new();
-            if let Some(ratio) = ratio {
-                write!(&mut params, "{:.2}", ratio).unwrap();
+            if let Some(order) = order {
+                write!(&mut params, "{}", order).unwrap();
             }
-            self.name = format!("o{}{}", params, self.name);
+            self.name = format!("u{}{}", params, self.name);
         }
 
         self
     }
 
-    pub fn propeller(&mut self, ratio: Option<Float>, change_name: bool) -> &mut Self {
-        let ratio_ = match ratio {
-            Some(r) => r.clamp(0.0, 1.0),
+    /// Cuts off each vertex at `depth` (default: 1/3) times the length of
+    /// its incident edges.
+    ///
+    /// Each truncated vertex is replaced with a face connecting the cut
+    /// points. E.g. a depth of `1 / (2 + √2)` yields the Archimedean
+    /// truncated cube. The `depth` is clamped to `[0, 0.5]`.
+    ///
+    /// # Arguments
+    /// * `vertex_selector` – Only selected vertices will be truncated. See
+    ///   [`FaceSelector`].
+    pub fn truncate(
+        &mut self,
+        depth: Option<Float>,
+        vertex_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        let depth_ = match depth {
+            Some(d) => d.clamp(0.0, 0.5),
             None => 1. / 3.,
         };
 
-        let edges = self.to_edges();
-        let reversed_edges: Edges = edges.iter().map(|edge| [edge[1], edge[0]]).collect();
-
-        let new_points = edges
-            .iter()
-            .zip(reversed_edges.iter())
-            .flat_map(|(edge, reversed_edge)| {
-                let edge_points = index_as_points(edge, &self.points);
-                vec![
-                    (
-                        edge,
-                        *edge_points[0] + ratio_ * (*edge_points[1] - *edge_points[0]),
-                    ),
-                    (
-                        reversed_edge,
-                        *edge_points[1] + ratio_ * (*edge_points[0] - *edge_points[1]),
-                    ),
-                ]
+        // The neighbors of each vertex in order and whether the vertex gets
+        // truncated.
+        let vertices = (0..self.points.len() as VertexKey)
+            .into_par_iter()
+            .zip(self.selected_vertices(vertex_selector.as_ref()))
+            .map(|(v, selected)| {
+                let neighbors = ordered_vertex_edges(v, &vertex_faces(v, &self.face_index))
+                    .iter()
+                    .map(|edge| edge[1])
+                    .collect::<Vec<_>>();
+                (neighbors, selected)
             })
             .collect::<Vec<_>>();
 
-        let new_ids = vertex_ids_edge_ref_ref(&new_points, self.points_len() as VertexKey);
+        let new_points: Vec<(Face, Point)> = vertices
+            .par_iter()
+            .enumerate()
+            .flat_map(|(v, (neighbors, selected))| {
+                let point = self.points[v];
+                if *selected {
+                    neighbors
+                        .iter()
+                        .map(|w| {
+                            (
+                                vec![v as VertexKey, *w],
+                                point + depth_ * (self.points[*w as usize] - point),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                } else {
+                    vec![(vec![v as VertexKey], point)]
+                }
+            })
+            .collect();
+
+        let new_ids = vertex_ids_ref(&new_points, 0);
 
         self.face_index = self
             .face_index
             .par_iter()
             .map(|face| {
-                face
+                face.iter()
+                    .circular_tuple_windows::<(_, _, _)>()
+                    .flat_map(|(u, v, w)| {
+                        if vertices[*v as usize].1 {
+                            vec![
+                                vertex(&[*v, *u], &new_ids).unwrap(),
+                                vertex(&[*v, *w], &new_ids).unwrap(),
+                            ]
+                        } else {
+                            vec![vertex(&[*v], &new_ids).unwrap()]
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let vertex_faces: Faces = vertices
+            .par_iter()
+            .enumerate()
+            .filter(|(_, (_, selected))| *selected)
+            .map(|(v, (neighbors, _))| {
+                neighbors
                     .iter()
-                    .circular_tuple_windows::<(_, _)>()
-                    .map(|f| vertex_edge(&[*f.0, *f.1], &new_ids).unwrap())
+                    .map(|w| vertex(&[v as VertexKey, *w], &new_ids).unwrap())
                     .collect()
+            })
+            .collect();
+
+        self.append_new_face_set(vertex_faces.len());
+        self.face_index.extend(vertex_faces);
+
+        self.points = vertex_values(&new_points);
+
+        if change_name {
+            let mut params = String::new();
+            if let Some(depth) = depth {
+                write!(&mut params, "{:.2}", depth).unwrap();
+            }
+            if let Some(vertex_selector) = &vertex_selector {
+                write!(&mut params, ",{}", vertex_selector).unwrap();
+            }
+            params = params.trim_end_matches(',').to_string();
+            self.name = format!("t{}{}", params, self.name);
+        }
+
+        self
+    }
+
+    /// Lifts each selected face along its normal, rotates it about its
+    /// normal and stitches it to its original boundary with an
+    /// antiprismatic band of triangles.
+    /// # Arguments
+    /// * `angle` – The rotation in radians, counter-clockwise when looking
+    ///   at the face from outside. Default is half the angle between two
+    ///   vertices of a face, *π/n*, which turns the band into the sides of
+    ///   an antiprism for regular faces.
+    /// * `height` – The distance the faces are moved along their normal.
+    ///   Default value is `0.3`.
+    /// * `face_selector` – Only selected faces will be affected.
+    pub fn twist(
+        &mut self,
+        angle: Option<Float>,
+        height: Option<Float>,
+        face_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        let height_ = height.unwrap_or(0.3);
 
-                /*(0..face.len())
-                    .map(|j| vertex_edge(&[face[j], face[(j + 1) % face.len()]], &new_ids).unwrap())
-                    .collect()*/
+        let selected = self.selected_faces(face_selector.as_ref());
+
+        let new_points = self
+            .face_index
+            .par_iter()
+            .zip(selected.par_iter())
+            .filter(|(_, selected)| **selected)
+            .flat_map(|(face, _)| {
+                let face_points = index_as_points(face, &self.points);
+                let centroid = centroid_ref(&face_points);
+                // Degenerate faces have no normal to rotate about and are
+                // left as they are.
+                let normal = face_normal(&face_points)
+                    .filter(|normal| EPSILON < normal.mag_sq())
+                    .map(|normal| normal.normalized());
+                let angle_ = angle.unwrap_or(Float::PI() / face.len() as Float);
+                let (sin, cos) = angle_.sin_cos();
+                face.iter()
+                    .zip(&face_points)
+                    .map(|(v, point)| {
+                        let arm = **point - centroid;
+                        (
+                            extend![..face, *v],
+                            match normal {
+                                // Rodrigues' rotation about the face normal.
+                                Some(normal) => {
+                                    centroid
+                                        + arm * cos
+                                        + normal.cross(arm) * sin
+                                        + normal * (normal.dot(arm) * (1.0 - cos) + height_)
+                                }
+                                None => **point,
+                            },
+                        )
+                    })
+                    .collect::<Vec<_>>()
             })
-            .chain(self.face_index.par_iter().flat_map(|face| {
-                (0..face.len())
-                    .map(|j| {
-                        let a = face[j];
-                        let b = face[(j + 1) % face.len()];
-                        let z = face[(j + face.len() - 1) % face.len()];
-                        let eab = vertex_edge(&[a, b], &new_ids).unwrap();
-                        let eba = vertex_edge(&[b, a], &new_ids).unwrap();
-                        let eza = vertex_edge(&[z, a], &new_ids).unwrap();
-                        vec![eba, eab, eza, a]
-                        //vec![eza, eab, eba, a]
+            .collect::<Vec<_>>();
+
+        let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
+
+        let side_faces: Faces = self
+            .face_index
+            .par_iter()
+            .zip(selected.par_iter())
+            .filter(|(_, selected)| **selected)
+            .flat_map(|(face, _)| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .flat_map(|v| {
+                        let twisted_a = vertex(&extend![..face, *v.0], &new_ids).unwrap();
+                        let twisted_b = vertex(&extend![..face, *v.1], &new_ids).unwrap();
+                        vec![
+                            vec![*v.0, *v.1, twisted_b],
+                            vec![*v.0, twisted_b, twisted_a],
+                        ]
                     })
                     .collect::<Faces>()
-            }))
-            .collect::<Faces>();
+            })
+            .collect();
+
+        // The twisted faces replace the original ones in-place so existing
+        // face sets stay valid.
+        self.face_index = self
+            .face_index
+            .par_iter()
+            .zip(selected.par_iter())
+            .map(|(face, selected)| {
+                if *selected {
+                    face.iter()
+                        .map(|v| vertex(&extend![..face, *v], &new_ids).unwrap())
+                        .collect()
+                } else {
+                    face.clone()
+                }
+            })
+            .collect();
+
+        self.append_new_face_set(side_faces.len());
+        self.face_index.extend(side_faces);
 
         self.points.extend(vertex_values_as_ref(&new_points));
 
         if change_name {
             let mut params = String::new();
-            if let Some(ratio) = ratio {
-                write!(&mut params, "{:.2}", ratio).unwrap();
+            if let Some(angle) = angle {
+                write!(&mut params, "{:.2}", angle).unwrap();
             }
-            self.name = format!("p{}{}", params, self.name);
+            if let Some(height) = height {
+                write!(&mut params, ",{:.2}", height).unwrap();
+            } else {
+                write!(&mut params, ",").unwrap();
+            }
+            if let Some(face_selector) = face_selector {
+                write!(&mut params, ",{}", face_selector).unwrap();
+            } else {
+                write!(&mut params, ",").unwrap();
+            }
+            params = params.trim_end_matches(',').to_string();
+            self.name = format!("tw{}{}", params, self.name);
         }
 
         self
     }
 
-    pub fn quinto(&mut self, height: Option<Float>, change_name: bool) -> &mut Self {
-        let height_ = match height {
-            Some(h) => {
-                if h < 0.0 {
-                    0.0
-                } else {
-                    h
-                }
-            }
-            None => 0.5,
+    /// Divides each selected face into a grid cell: a smaller copy of the
+    /// face in the center, a quadrilateral along each edge and a
+    /// quadrilateral at each corner.
+    ///
+    /// Faces that are not selected but share an edge with a selected face
+    /// get the new edge vertices inserted so the mesh stays closed.
+    /// # Arguments
+    /// * `ratio` – Where the grid lines split the edges, as a fraction of
+    ///   the edge length. Will be clamped to `[0, 0.5]`. Default value is
+    ///   `1/3`.
+    /// * `face_selector` – Only selected faces will be affected.
+    pub fn waffle(
+        &mut self,
+        ratio: Option<Float>,
+        face_selector: Option<FaceSelector>,
+        change_name: bool,
+    ) -> &mut Self {
+        let ratio_ = match ratio {
+            Some(r) => r.clamp(0.0, 0.5),
+            None => 1. / 3.,
         };
 
-        let mut new_points: Vec<(Face, Point)> = self
-            .to_edges()
-            .par_iter()
+        let selected = self.selected_faces(face_selector.as_ref());
+
+        // Two points per edge, keyed by the directed edge and sitting
+        // closer to the edge's start. Only edges of selected faces are
+        // split.
+        let edge_points: Vec<(Face, Point)> = self
+            .face_index
+            .iter()
+            .zip(&selected)
+            .filter(|(_, selected)| **selected)
+            .flat_map(|(face, _)| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .flat_map(|(a, b)| vec![[*a, *b], [*b, *a]])
+                    .collect::<Vec<_>>()
+            })
+            .unique()
             .map(|edge| {
-                let edge_points = index_as_points(edge, &self.points);
-                (edge.to_vec(), height_ * (*edge_points[0] + *edge_points[1]))
+                let a = self.points[edge[0] as usize];
+                let b = self.points[edge[1] as usize];
+                (edge.to_vec(), a + ratio_ * (b - a))
             })
             .collect();
 
-        new_points.extend(
-            self.face_index
-                .par_iter()
-                .flat_map(|face| {
-                    let edge_points = index_as_points(face, &self.points);
-                    let centroid = centroid_ref(&edge_points);
-                    (0..face.len())
-                        .map(|i| {
-                            (
-                                extend![..face, i as VertexKey],
-                                (*edge_points[i] + *edge_points[(i + 1) % face.len()] + centroid)
-                                    / 3.,
-                            )
-                        })
-                        .collect::<Vec<(Face, Point)>>()
-                })
-                .collect::<Vec<(Face, Point)>>(),
-        );
+        // One inner point per selected face and corner.
+        let corner_points: Vec<(Face, Point)> = self
+            .face_index
+            .par_iter()
+            .zip(&selected)
+            .filter(|(_, selected)| **selected)
+            .flat_map(|(face, _)| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _, _)>()
+                    .map(|(p, a, b)| {
+                        let point_a = self.points[*a as usize];
+                        (
+                            extend![..face, *a],
+                            point_a
+                                + ratio_ * (self.points[*b as usize] - point_a)
+                                + ratio_ * (self.points[*p as usize] - point_a),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
 
+        let new_points: Vec<(Face, Point)> = edge_points.into_iter().chain(corner_points).collect();
         let new_ids = vertex_ids_ref(&new_points, self.points_len() as VertexKey);
 
-        self.points.extend(vertex_values_as_ref(&new_points));
+        let edge = |a: VertexKey, b: VertexKey| vertex(&[a, b], &new_ids);
+        let corner = |face: &FaceSlice, a: VertexKey| -> VertexKey {
+            vertex(&extend![..face.to_vec(), a], &new_ids).unwrap()
+        };
 
-        self.face_index = self
+        // Center faces of selected faces replace them in-place so existing
+        // face sets stay valid. Unselected faces get the new edge points.
+        let center_faces: Faces = self
             .face_index
             .par_iter()
-            .map(|face| {
-                (0..face.len())
-                    .map(|face_vertex| {
-                        vertex(&extend![..face, face_vertex as VertexKey], &new_ids).unwrap()
-                    })
-                    .collect()
+            .zip(&selected)
+            .map(|(face, selected)| {
+                if *selected {
+                    face.iter().map(|a| corner(face, *a)).collect()
+                } else {
+                    face.iter()
+                        .circular_tuple_windows::<(_, _)>()
+                        .flat_map(|(a, b)| {
+                            std::iter::once(*a).chain(edge(*a, *b)).chain(edge(*b, *a))
+                        })
+                        .collect()
+                }
             })
-            .chain(self.face_index.par_iter().flat_map(|face| {
-                (0..face.len())
-                    .map(|i| {
-                        let v = face[i];
-                        let e0 = [face[(i + face.len() - 1) % face.len()], face[i]];
-                        let e1 = [face[i], face[(i + 1) % face.len()]];
-                        let e0p = vertex(&distinct_edge(&e0), &new_ids).unwrap();
-                        let e1p = vertex(&distinct_edge(&e1), &new_ids).unwrap();
-                        let iv0 = vertex(
-                            &extend![..face, ((i + face.len() - 1) % face.len()) as VertexKey],
-                            &new_ids,
-                        )
-                        .unwrap();
-                        let iv1 = vertex(&extend![..face, i as VertexKey], &new_ids).unwrap();
-                        vec![v, e1p, iv1, iv0, e0p]
+            .collect();
+
+        let edge_faces: Faces = self
+            .face_index
+            .par_iter()
+            .zip(&selected)
+            .filter(|(_, selected)| **selected)
+            .flat_map(|(face, _)| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _)>()
+                    .map(|(a, b)| {
+                        vec![
+                            edge(*a, *b).unwrap(),
+                            edge(*b, *a).unwrap(),
+                            corner(face, *b),
+                            corner(face, *a),
+                        ]
                     })
                     .collect::<Faces>()
-            }))
-            .collect::<Faces>();
-
-        if change_name {
-            let mut params = String::new();
-            if let Some(h) = height {
-                write!(&mut params, "{:.2}", h).unwrap();
-            }
-            self.name = format!("q{}{}", params, self.name);
-        }
-
-        self
-    }
+            })
+            .collect();
 
-    pub fn reflect(&mut self, change_name: bool) -> &mut Self {
-        self.points = self
-            .points
+        let corner_faces: Faces = self
+            .face_index
             .par_iter()
-            .map(|v| Point::new(v.x, -v.y, v.z))
+            .zip(&selected)
+            .filter(|(_, selected)| **selected)
+            .flat_map(|(face, _)| {
+                face.iter()
+                    .circular_tuple_windows::<(_, _, _)>()
+                    .map(|(p, a, b)| {
+                        vec![
+                            *a,
+                            edge(*a, *b).unwrap(),
+                            corner(face, *a),
+                            edge(*a, *p).unwrap(),
+                        ]
+                    })
+                    .collect::<Faces>()
+            })
             .collect();
-        self.reverse();
-
-        if change_name {
-            self.name = format!("r{}", self.name);
-        }
 
-        self
-    }
+        self.face_index = center_faces;
+        self.append_new_face_set(edge_faces.len());
+        self.face_index.extend(edge_faces);
+        self.append_new_face_set(corner_faces.len());
+        self.face_index.extend(corner_faces);
 
-    pub fn snub(
-        &mut self,
-        ratio: Option<Float>,
-        height: Option<Float>,
-        change_name: bool,
-    ) -> &mut Self {
-        self.dual(false);
-        self.gyro(ratio, height, false);
-        self.dual(false);
+        self.points.extend(vertex_values(&new_points));
 
         if change_name {
             let mut params = String::new();
             if let Some(ratio) = ratio {
                 write!(&mut params, "{:.2}", ratio).unwrap();
             }
-            if let Some(height) = height {
-                write!(&mut params, ",{:.2}", height).unwrap();
+            if let Some(face_selector) = &face_selector {
+                write!(&mut params, ",{}", face_selector).unwrap();
             }
-            self.name = format!("s{}{}", params, self.name);
+            self.name = format!("W{}{}", params, self.name);
         }
 
         self
     }
 
-    /// Projects all points on the unit sphere (at `strength` `1.0`).
+    /// Gyro followed by truncation of vertices centered on original faces.
+    /// This creates two new hexagons for every original edge.
+    /// # Arguments
+    /// * `ratio` – The ratio at which the adjacent edges get split.
+    /// * `height` – An offset to add to the face centroid point along the face
+    ///   normal.
+    /// * `order` – The order *n* of the whirl. Default value is `1`.
     ///
-    /// If `strength` is zero this is a no-op and will neither change the
-    /// geometry nor the name. Even if `change_name` is `true`.
-    pub fn spherize(&mut self, strength: Option<Float>, change_name: bool) -> &mut Self {
-        let strength_ = strength.unwrap_or(1.0);
-
-        if 0.0 != strength_ {
-            self.points.par_iter_mut().for_each(|point| {
-                *point = (1.0 - strength_) * *point + strength_ * point.normalized();
-            });
-
-            if change_name {
-                let mut params = String::new();
-                if let Some(strength) = strength {
-                    write!(&mut params, "{:.2}", strength).unwrap();
-                }
-                self.name = format!("S{}{}", params, self.name);
-            }
-        }
-
-        self
-    }
-
-    pub fn truncate(
+    ///   The whirl of order *n* is the Goldberg–Coxeter operation
+    ///   *GC(n + 1, n)*, see
+    ///   [`goldberg_coxeter()`](Polyhedron::goldberg_coxeter()). Order `1`
+    ///   has the same faces as *GC(2, 1)*, for any polyhedron. `ratio` and
+    ///   `height` are ignored for orders above `1`, which leave open meshes
+    ///   unchanged.
+    pub fn whirl(
         &mut self,
+        ratio: Option<Float>,
         height: Option<Float>,
-        vertex_valence: Option<Vec<usize>>,
-        regular_faces_only: Option<bool>,
+        order: Option<usize>,
         change_name: bool,
     ) -> &mut Self {
-        self.dual(false);
-        self.kis(height, vertex_valence.clone(), regular_faces_only, false);
-        self.dual(false);
+        let order_ = order.unwrap_or(1).max(1);
 
-        if change_name {
-            let mut params = String::new();
-            if let Some(height) = height {
-                write!(&mut params, "{:.2}", height).unwrap();
-            }
-            if let Some(vertex_valence) = vertex_valence {
-                write!(&mut params, ",{}", format_vec(&vertex_valence)).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
+        if 1 < order_ {
+            self.goldberg_coxeter(order_ + 1, order_, false);
+
+            if change_name {
+                self.name = format!("w{}{}", order_, self.name);
             }
-            if let Some(regular_faces_only) = regular_faces_only {
-                if regular_faces_only {
-                    params.push_str(",{t}");
+        } else {
+            let height_ = height.unwrap_or(0.);
+            self.whirl_with_heights(ratio, &|_, _, _| height_, false);
+
+            if change_name {
+                let mut params = String::new();
+                if let Some(ratio) = ratio {
+                    write!(&mut params, "{:.2}", ratio).unwrap();
                 }
-            } else {
-                write!(&mut params, ",").unwrap();
+                if let Some(height) = height {
+                    write!(&mut params, ",{:.2}", height).unwrap();
+                }
+                self.name = format!("w{}{}", params, self.name);
             }
-            params = params.trim_end_matches(',').to_string();
-            self.name = format!("t{}{}", params, self.name);
         }
 
         self
     }
 
-    pub fn whirl(
+    /// Like [`whirl()`](Polyhedron::whirl()) but the offset of each face
+    /// center is computed per face by `height`.
+    pub fn whirl_with_heights(
         &mut self,
         ratio: Option<Float>,
-        height: Option<Float>,
+        height: &FaceParameterFn<'_>,
         change_name: bool,
     ) -> &mut Self {
         let ratio_ = match ratio {
             Some(r) => r.clamp(0.0, 1.0),
             None => 1. / 3.,
         };
-        let height_ = height.unwrap_or(0.);
 
         let new_points: Vec<(Face, Point)> = self
             .face_index
             .par_iter()
-            .flat_map(|face| {
+            .enumerate()
+            .flat_map(|(index, face)| {
                 let face_points = index_as_points(face, &self.points);
-                let center =
-                    centroid_ref(&face_points) + face_normal(&face_points).unwrap() * height_;
+                let centroid = centroid_ref(&face_points);
+                let center = centroid
+                    + face_normal(&face_points).unwrap() * height(index, face.len(), &centroid);
                 face.iter()
                     .enumerate()
                     .map(|v| {
@@ -1293,9 +5300,7 @@ impl Polyhedron {
 
         self.points.extend(vertex_values(&new_points));
 
-        let old_face_index_len = self.face_index.len();
-
-        self.face_index = self
+        let hexagons: Faces = self
             .face_index
             .par_iter()
             .flat_map(|face| {
@@ -1315,7 +5320,14 @@ impl Polyhedron {
                     })
                     .collect::<Faces>()
             })
-            .chain(self.face_index.par_iter().map(|face| {
+            .collect();
+
+        // The center faces replace the original ones in-place so existing
+        // face sets stay valid.
+        self.face_index = self
+            .face_index
+            .par_iter()
+            .map(|face| {
                 let mut new_face = face.clone();
                 face.iter()
                     .map(|a| {
@@ -1325,51 +5337,46 @@ impl Polyhedron {
                         result
                     })
                     .collect()
-            }))
-            .collect::<Faces>();
+            })
+            .collect();
 
-        self.append_new_face_set(self.face_index.len() - old_face_index_len);
+        self.append_new_face_set(hexagons.len());
+        self.face_index.extend(hexagons);
 
         if change_name {
             let mut params = String::new();
             if let Some(ratio) = ratio {
                 write!(&mut params, "{:.2}", ratio).unwrap();
             }
-            if let Some(height) = height {
-                write!(&mut params, ",{:.2}", height).unwrap();
-            }
+            // The closure is marked like `KisApex::NormalPerFace`.
+            write!(&mut params, ",{{n..}}").unwrap();
             self.name = format!("w{}{}", params, self.name);
         }
 
         self
     }
 
+    /// Applies [`dual()`](Polyhedron::dual()) and
+    /// [`kis()`](Polyhedron::kis()), in that order.
+    /// # Arguments
+    /// * `vertex_selector` – Only selected vertices will be affected. See
+    ///   [`FaceSelector`].
     pub fn zip(
         &mut self,
         height: Option<Float>,
-        vertex_valence: Option<Vec<usize>>,
-        regular_faces_only: Option<bool>,
+        vertex_selector: Option<FaceSelector>,
         change_name: bool,
     ) -> &mut Self {
         self.dual(false);
-        self.kis(height, vertex_valence.clone(), regular_faces_only, false);
+        self.kis(height, vertex_selector.clone(), false);
 
         if change_name {
             let mut params = String::new();
             if let Some(height) = height {
                 write!(&mut params, "{:.2}", height).unwrap();
             }
-            if let Some(vertex_valence) = vertex_valence {
-                write!(&mut params, ",{}", format_vec(&vertex_valence)).unwrap();
-            } else {
-                write!(&mut params, ",").unwrap();
-            }
-            if let Some(regular_faces_only) = regular_faces_only {
-                if regular_faces_only {
-                    params.push_str(",{t}");
-                }
-            } else {
-                write!(&mut params, ",").unwrap();
+            if let Some(vertex_selector) = &vertex_selector {
+                write!(&mut params, ",{}", vertex_selector).unwrap();
             }
             params = params.trim_end_matches(',').to_string();
             self.name = format!("z{}{}", params, self.name);
@@ -1389,21 +5396,43 @@ impl Polyhedron {
         self
     }
 
-    /// Returns the name of this polyhedron. This can be used to reconstruct the
-    /// polyhedron using `Polyhedron::from<&str>()`.
+    /// Returns the name of this polyhedron. This can be used to reconstruct the
+    /// polyhedron using `Polyhedron::from<&str>()`.
+    #[inline]
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    #[inline]
+    pub fn points_len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[inline]
+    pub fn points(&self) -> &Points {
+        &self.points
+    }
+
+    /// Returns the points as arrays of their coordinates, without copying.
     #[inline]
-    pub fn name(&self) -> &String {
-        &self.name
+    pub fn points_as_arrays(&self) -> &[[Float; 3]] {
+        bytemuck::cast_slice(&self.points)
     }
 
+    /// Returns the points as [`glam`](https://crates.io/crates/glam)
+    /// vectors, without copying.
+    #[cfg(feature = "glam")]
     #[inline]
-    pub fn points_len(&self) -> usize {
-        self.points.len()
+    pub fn points_as_glam(&self) -> &[glam::Vec3] {
+        bytemuck::cast_slice(&self.points)
     }
 
+    /// Returns the points as [`nalgebra`](https://crates.io/crates/nalgebra)
+    /// points, without copying.
+    #[cfg(feature = "nalgebra")]
     #[inline]
-    pub fn points(&self) -> &Points {
-        &self.points
+    pub fn points_as_nalgebra(&self) -> &[nalgebra::Point3<Float>] {
+        bytemuck::cast_slice(&self.points)
     }
 
     pub fn faces(&self) -> &Faces {
@@ -1417,26 +5446,76 @@ impl Polyhedron {
         self
     }
 
+    /// Scales the polyhedron about the origin so the average length of its
+    /// edges becomes one.
+    ///
+    /// For seeds whose edges all have the same length, like the Platonic
+    /// solids, this yields unit edges.
+    pub fn normalize_edge_length(&mut self) -> &mut Self {
+        let edges = self.to_edges();
+        let length = edges
+            .par_iter()
+            .map(|edge| (self.points[edge[1] as usize] - self.points[edge[0] as usize]).mag())
+            .sum::<Float>()
+            / edges.len() as Float;
+
+        if EPSILON < length {
+            self.points
+                .par_iter_mut()
+                .for_each(|point| *point /= length);
+        }
+        self
+    }
+
     /// Compute the edges of the polyhedron.
     #[inline]
     pub fn to_edges(&self) -> Edges {
-        let edges = self.face_index
+        let edges = self
+            .face_index
             .par_iter()
-            .map(|face| {
+            .flat_map(|face| {
                 face.iter()
                     // Grab two index entries.
                     .circular_tuple_windows::<(_, _)>()
-                    .filter(|t| t.0 < t.1)
-                    // Create an edge from them.
-                    .map(|t| [*t.0, *t.1])
+                    // Create an edge from them. Each edge is kept once,
+                    // including those on the boundary of open meshes.
+                    .map(|t| distinct_edge(&[*t.0, *t.1]))
                     .collect::<Vec<_>>()
             })
-            .flatten()
             .collect::<Vec<_>>();
 
         edges.into_iter().unique().collect()
     }
 
+    /// Compute the edges of the polyhedron as pairs of vertex indices.
+    ///
+    /// See [`to_edges()`](Polyhedron::to_edges()).
+    pub fn to_edge_list(&self) -> Vec<(VertexKey, VertexKey)> {
+        self.to_edges()
+            .iter()
+            .map(|edge| (edge[0], edge[1]))
+            .collect()
+    }
+
+    /// Returns the vertices and edges of the polyhedron as an undirected
+    /// graph in [Graphviz](https://graphviz.org/)’ DOT language.
+    ///
+    /// Vertices are named after their index and the graph after the
+    /// polyhedron.
+    pub fn to_dot(&self) -> String {
+        format!(
+            "graph \"{}\" {{\n{}{}}}\n",
+            self.name.replace('\\', "\\\\").replace('"', "\\\""),
+            (0..self.points.len())
+                .map(|vertex| format!("  {};\n", vertex))
+                .join(""),
+            self.to_edges()
+                .iter()
+                .map(|edge| format!("  {} -- {};\n", edge[0], edge[1]))
+                .join("")
+        )
+    }
+
     /// Returns a flat [`u32`] triangle index buffer and two matching point and
     /// normal buffers.
     ///
@@ -1526,6 +5605,143 @@ impl Polyhedron {
         (triangle_face_index, points, normals)
     }
 
+    /// Returns one [`u32`] triangle index buffer per face set and two
+    /// matching point and normal buffers.
+    ///
+    /// Each face is in the index buffer of the first face set it is in. If
+    /// there are faces in no face set, they are in an additional, last
+    /// index buffer. Faces are triangulated as fans and keep their winding
+    /// order.
+    ///
+    /// With [`NormalType::Flat`] the points are duplicated for each face
+    /// they belong to. With [`NormalType::Smooth`] the point buffer is the
+    /// same as [`points()`](Polyhedron::points()).
+    pub fn to_triangle_mesh_buffers_by_face_set(
+        &self,
+        normal_type: NormalType,
+    ) -> (Vec<Vec<u32>>, Points, Normals) {
+        let face_or_point_normals = self.normals(normal_type);
+
+        let first_face_sets = self.first_face_sets();
+        let mut index = vec![
+            Vec::new();
+            match first_face_sets.contains(&self.face_set_index.len()) {
+                true => self.face_set_index.len() + 1,
+                false => self.face_set_index.len(),
+            }
+        ];
+
+        match normal_type {
+            NormalType::Flat => {
+                let mut points = Points::new();
+                let mut normals = Normals::new();
+                self.face_index
+                    .iter()
+                    .zip(face_or_point_normals.iter())
+                    .zip(first_face_sets.iter())
+                    .for_each(|((face, normal), set)| {
+                        let base = points.len() as u32;
+                        points.extend(face.iter().map(|v| self.points[*v as usize]));
+                        normals.resize(points.len(), *normal);
+                        index[*set].extend(
+                            (1..face.len() as u32 - 1)
+                                .flat_map(|i| vec![base, base + i, base + i + 1]),
+                        );
+                    });
+                (index, points, normals)
+            }
+            NormalType::Smooth => {
+                self.face_index
+                    .iter()
+                    .zip(first_face_sets.iter())
+                    .for_each(|(face, set)| {
+                        index[*set].extend(
+                            (1..face.len() - 1).flat_map(|i| vec![face[0], face[i], face[i + 1]]),
+                        );
+                    });
+                (index, self.points.clone(), face_or_point_normals)
+            }
+        }
+    }
+
+    /// Returns a vertex buffer and a matching [`u32`] triangle index buffer,
+    /// ready to be uploaded to a GPU.
+    ///
+    /// Each vertex has a position and a normal, followed by texture
+    /// coordinates and a face set index, if asked for in the `options`.
+    /// These take three, three, two and one [`f32`] respectively. Face set
+    /// indices are as in
+    /// [`to_triangle_mesh_buffers_by_face_set()`](Polyhedron::to_triangle_mesh_buffers_by_face_set()).
+    /// Points that belong to faces in different face sets are duplicated
+    /// for each set.
+    ///
+    /// Faces are triangulated as fans and, by default, made
+    /// counter-clockwise, as most graphics APIs expect.
+    pub fn to_gpu_buffers(&self, options: &GpuBufferOptions) -> (Vec<f32>, Vec<u32>) {
+        let (index, points, normals) =
+            self.to_triangle_mesh_buffers_by_face_set(options.normal_type);
+
+        // The point and face set of each vertex.
+        let mut vertices = Vec::new();
+        let mut vertex_map = HashMap::new();
+        let mut triangle_index = Vec::new();
+        for (set, set_index) in index.iter().enumerate() {
+            triangle_index.extend(set_index.iter().map(|point| {
+                let set = match options.face_set_ids {
+                    true => set,
+                    false => 0,
+                };
+                *vertex_map.entry((*point, set)).or_insert_with(|| {
+                    vertices.push((*point as usize, set));
+                    vertices.len() as u32 - 1
+                })
+            }));
+        }
+
+        if options.reverse_winding {
+            triangle_index
+                .chunks_exact_mut(3)
+                .for_each(|triangle| triangle.swap(1, 2));
+        }
+
+        let attributes = |(point, set): (usize, usize)| {
+            let (position, normal) = (points[point], normals[point]);
+            let mut values = vec![
+                vec![position.x, position.y, position.z],
+                vec![normal.x, normal.y, normal.z],
+            ];
+            if options.uvs {
+                values.push(spherical_uv(&position).to_vec());
+            }
+            if options.face_set_ids {
+                values.push(vec![set as f32]);
+            }
+            values
+        };
+
+        let vertex_buffer = match options.layout {
+            VertexLayout::Interleaved => vertices
+                .iter()
+                .flat_map(|vertex| attributes(*vertex).into_iter().flatten())
+                .collect(),
+            VertexLayout::Planar => {
+                let attributes = vertices
+                    .iter()
+                    .map(|vertex| attributes(*vertex))
+                    .collect::<Vec<_>>();
+                (0..attributes.first().map_or(0, |values| values.len()))
+                    .flat_map(|attribute| {
+                        attributes
+                            .iter()
+                            .flat_map(move |values| values[attribute].iter().copied())
+                    })
+                    .collect()
+            }
+        };
+
+        (vertex_buffer, triangle_index)
+    }
+
     #[inline]
     pub fn triangulate(&mut self, shortest: Option<bool>) -> &mut Self {
         self.face_index = self
@@ -1581,30 +5797,23 @@ impl Polyhedron {
     }
 
     /// Sends the polyhedron to the specified
-    /// [ɴsɪ](https:://crates.io/crates/nsi) context.
-    /// # Arguments
-    /// * `handle` – Handle of the node being created. If omitted, the name of
-    ///   the polyhedron will be used as a handle.
+    /// [ɴsɪ](https:://crates.io/crates/nsi) context as a mesh node with the
+    /// given `handle`.
     ///
-    /// * `crease_hardness` - The hardness of edges (default: 10).
+    /// If the polyhedron has face sets, the index of the first face set each
+    /// face is in, or `-1`, is set as the per-face integer attribute
+    /// `faceset`.
     ///
-    /// * `corner_hardness` - The hardness of vertices (default: 0).
+    /// If the `options` have a
+    /// [`transform`](NsiOptions::transform), the mesh is connected to a new
+    /// transform node, `{handle}-transform`.
     ///
-    /// * `smooth_corners` - Whether to keep corners smooth, where more than two
-    ///   edges meet. When set to `false` these automatically form a hard corner
-    ///   with the same hardness as `crease_hardness`.
+    /// Returns the handle of the node to connect to the scene: the transform
+    /// node, if there is one, the mesh node otherwise.
     #[cfg(feature = "nsi")]
-    pub fn to_nsi(
-        &self,
-        ctx: &nsi::Context,
-        handle: Option<&str>,
-        crease_hardness: Option<f32>,
-        corner_hardness: Option<f32>,
-        smooth_corners: Option<bool>,
-    ) -> String {
-        let handle = handle.unwrap_or_else(|| self.name.as_str()).to_string();
+    pub fn to_nsi(&self, ctx: &nsi::Context, handle: &str, options: &NsiOptions) -> String {
         // Create a new mesh node.
-        ctx.create(handle.clone(), nsi::NodeType::Mesh, &[]);
+        ctx.create(handle, nsi::NodeType::Mesh, &[]);
 
         // Flatten point vector.
         // Fast, unsafe version. May exploce on some platforms.
@@ -1621,22 +5830,19 @@ impl Polyhedron {
             .collect();
         */
 
+        let indices = self
+            .face_index
+            .par_iter()
+            .flat_map(|face| face.clone())
+            .collect::<Vec<_>>();
+
         ctx.set_attribute(
-            handle.clone(),
+            handle,
             &[
                 // Positions.
                 nsi::points!("P", positions),
                 // VertexKey into the position array.
-                nsi::integers!(
-                    "P.indices",
-                    bytemuck::cast_slice(
-                        &self
-                            .face_index
-                            .par_iter()
-                            .flat_map(|face| face.clone())
-                            .collect::<Vec<_>>()
-                    )
-                ),
+                nsi::integers!("P.indices", bytemuck::cast_slice(&indices)),
                 // Arity of each face.
                 nsi::integers!(
                     "nvertices",
@@ -1646,36 +5852,254 @@ impl Polyhedron {
                         .map(|face| face.len() as i32)
                         .collect::<Vec<_>>()
                 ),
-                // Render this as a C-C subdivison surface.
-                nsi::string!("subdivision.scheme", "catmull-clark"),
                 // This saves us from having to reverse the mesh ourselves.
                 nsi::integer!("clockwisewinding", true as _),
             ],
         );
 
+        // The first face set of each face, or -1, as a uniform attribute
+        // shaders can use to tell the faces created by each operator apart.
+        // Per-face attributes are flagged as such. Otherwise ɴsɪ goes by
+        // their count, which is ambiguous when there are as many faces as
+        // points, e.g. for a tetrahedron.
+        if !self.face_set_index.is_empty() {
+            let face_sets = self
+                .first_face_sets()
+                .iter()
+                .map(|set| match *set < self.face_set_index.len() {
+                    true => *set as i32,
+                    false => -1,
+                })
+                .collect::<Vec<_>>();
+            ctx.set_attribute(handle, &[nsi::integers!("faceset", &face_sets).per_face()]);
+        }
+
+        if options.st {
+            let st = self
+                .points
+                .iter()
+                .flat_map(|point| spherical_uv(point).to_vec())
+                .collect::<Vec<_>>();
+            ctx.set_attribute(
+                handle,
+                &[
+                    nsi::floats!("st", &st).array_len(2),
+                    nsi::integers!("st.indices", bytemuck::cast_slice(&indices)),
+                ],
+            );
+        }
+
+        if !options.palette.is_empty() {
+            let colors = self
+                .face_set_colors(&options.palette)
+                .iter()
+                .flat_map(|color| color.iter().map(|channel| *channel as f32 / 255.0))
+                .collect::<Vec<_>>();
+            ctx.set_attribute(handle, &[nsi::colors!("Cs", &colors).per_face()]);
+        }
+
+        match options.normal_type {
+            // A plain polygon mesh.
+            Some(normal_type) => {
+                let normal_indices = match normal_type {
+                    NormalType::Flat => self
+                        .face_index
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(index, face)| vec![index as u32; face.len()])
+                        .collect(),
+                    NormalType::Smooth => indices,
+                };
+                let normals = self.normals(normal_type);
+                ctx.set_attribute(
+                    handle,
+                    &[
+                        nsi::normals!("N", bytemuck::cast_slice(&normals)),
+                        nsi::integers!("N.indices", bytemuck::cast_slice(&normal_indices)),
+                    ],
+                );
+            }
+            None => self.set_nsi_subdivision(ctx, handle, options),
+        }
+
+        // Place the mesh under a transform, if asked to.
+        match options.transform {
+            Some(transform) => {
+                let transform_handle = format!("{}-transform", handle);
+                ctx.create(transform_handle.clone(), nsi::NodeType::Transform, &[]);
+                ctx.set_attribute(
+                    transform_handle.clone(),
+                    &[nsi::double_matrix!("transformationmatrix", &transform)],
+                );
+                ctx.connect(handle, "", transform_handle.clone(), "objects", &[]);
+                transform_handle
+            }
+            None => handle.to_string(),
+        }
+    }
+
+    /// Sends the polyhedron to the specified
+    /// [ɴsɪ](https:://crates.io/crates/nsi) context as a ball-and-stick
+    /// model: a sphere at each point and a cylinder along each edge.
+    ///
+    /// The sphere and the cylinder are sent once, as the mesh nodes
+    /// `{handle}-sphere` and `{handle}-cylinder`, and instanced by an
+    /// instances node with the given `handle`, which is returned.
+    /// # Arguments
+    /// * `vertex_radius` - The radius of the spheres (default: a tenth of
+    ///   the mean edge length).
+    ///
+    /// * `edge_radius` - The radius of the cylinders (default: half the
+    ///   `vertex_radius`).
+    #[cfg(feature = "nsi")]
+    pub fn to_nsi_wireframe(
+        &self,
+        ctx: &nsi::Context,
+        handle: &str,
+        vertex_radius: Option<Float>,
+        edge_radius: Option<Float>,
+    ) -> String {
+        let edges = self.to_edges();
+        let vertex_radius = vertex_radius.unwrap_or_else(|| {
+            edges
+                .iter()
+                .map(|edge| (self.points[edge[1] as usize] - self.points[edge[0] as usize]).mag())
+                .sum::<Float>()
+                / edges.len().max(1) as Float
+                / 10.0
+        });
+        let edge_radius = edge_radius.unwrap_or(vertex_radius / 2.0);
+
+        let sphere = Polyhedron::icosphere(2).to_nsi(
+            ctx,
+            &format!("{}-sphere", handle),
+            &NsiOptions {
+                crease_hardness: Some(0.0),
+                smooth_corners: Some(true),
+                ..Default::default()
+            },
+        );
+        // Along the y axis, from -0.5 to 0.5. The caps are hidden inside the
+        // spheres.
+        let cylinder = Polyhedron::cylinder(16, 1, Some(1.0)).to_nsi(
+            ctx,
+            &format!("{}-cylinder", handle),
+            &NsiOptions {
+                normal_type: Some(NormalType::Smooth),
+                ..Default::default()
+            },
+        );
+
+        // Rows are the images of the axes, the last one is the translation.
+        let matrix = |x: Vector, y: Vector, z: Vector, translation: Point| {
+            [x, y, z, translation]
+                .iter()
+                .zip([0., 0., 0., 1.].iter())
+                .flat_map(|(row, w)| vec![row.x as f64, row.y as f64, row.z as f64, *w])
+                .collect::<Vec<_>>()
+        };
+
+        let spheres = self.points.iter().map(|point| {
+            matrix(
+                Vector::unit_x() * vertex_radius,
+                Vector::unit_y() * vertex_radius,
+                Vector::unit_z() * vertex_radius,
+                *point,
+            )
+        });
+        let cylinders = edges.iter().map(|edge| {
+            let (a, b) = (self.points[edge[0] as usize], self.points[edge[1] as usize]);
+            let direction = (b - a).normalized();
+            let helper = match direction.x.abs() < 0.9 {
+                true => Vector::unit_x(),
+                false => Vector::unit_y(),
+            };
+            let x = direction.cross(helper).normalized();
+            matrix(
+                x * edge_radius,
+                b - a,
+                x.cross(direction) * edge_radius,
+                (a + b) / 2.0,
+            )
+        });
+        let matrices = spheres.chain(cylinders).flatten().collect::<Vec<_>>();
+        // The sphere is model 0, the cylinder model 1.
+        let mut model_indices = vec![0; self.points.len()];
+        model_indices.resize(self.points.len() + edges.len(), 1);
+
+        ctx.create(handle, nsi::NodeType::Instances, &[]);
+        ctx.set_attribute(
+            handle,
+            &[
+                nsi::double_matrices!("transformationmatrices", &matrices),
+                nsi::integers!("modelindices", &model_indices),
+            ],
+        );
+        ctx.connect(
+            sphere,
+            "",
+            handle,
+            "sourcemodels",
+            &[nsi::integer!("index", 0)],
+        );
+        ctx.connect(
+            cylinder,
+            "",
+            handle,
+            "sourcemodels",
+            &[nsi::integer!("index", 1)],
+        );
+
+        handle.to_string()
+    }
+
+    /// Sets up the mesh node with the given `handle` as a Catmull-Clark
+    /// subdivision surface with creases.
+    #[cfg(feature = "nsi")]
+    fn set_nsi_subdivision(&self, ctx: &nsi::Context, handle: &str, options: &NsiOptions) {
+        // Render this as a C-C subdivison surface.
+        ctx.set_attribute(
+            handle,
+            &[nsi::string!("subdivision.scheme", "catmull-clark")],
+        );
+
         // Default: semi sharp creases.
-        let crease_hardness = crease_hardness.unwrap_or(10.);
+        let crease_hardness = options.crease_hardness.unwrap_or(10.);
+
+        // Each edge gets the highest hardness of the face sets of its faces.
+        let first_face_sets = self.first_face_sets();
+        let edge_faces = self.edge_faces();
+        let (edges, hardnesses): (Vec<_>, Vec<_>) = self
+            .to_edges()
+            .into_iter()
+            .map(|edge| {
+                let hardness = edge_faces[&edge]
+                    .iter()
+                    .map(|face| {
+                        options
+                            .face_set_crease_hardness
+                            .get(&first_face_sets[*face])
+                            .copied()
+                            .unwrap_or(crease_hardness)
+                    })
+                    .fold(0.0, Float::max);
+                (edge, hardness)
+            })
+            .filter(|(_, hardness)| 0.0 != *hardness)
+            .unzip();
 
         // Crease each of our edges a bit?
-        if 0.0 != crease_hardness {
-            let edges = self
-                .to_edges()
-                .into_iter()
-                .flat_map(|edge| edge.to_vec())
-                .collect::<Vec<_>>();
+        if !edges.is_empty() {
             ctx.set_attribute(
-                handle.clone(),
+                handle,
                 &[
                     nsi::integers!("subdivision.creasevertices", bytemuck::cast_slice(&edges)),
-                    nsi::floats!(
-                        "subdivision.creasesharpness",
-                        &vec![crease_hardness; edges.len() / 2]
-                    ),
+                    nsi::floats!("subdivision.creasesharpness", &hardnesses),
                 ],
             );
         }
 
-        match corner_hardness {
+        match options.corner_hardness {
             Some(hardness) => {
                 if 0.0 < hardness {
                     let corners = self
@@ -1685,7 +6109,7 @@ impl Polyhedron {
                         .map(|(i, _)| i as u32)
                         .collect::<Vec<_>>();
                     ctx.set_attribute(
-                        handle.clone(),
+                        handle,
                         &[
                             nsi::integers!(
                                 "subdivision.cornervertices",
@@ -1702,7 +6126,7 @@ impl Polyhedron {
 
             // Have the renderer semi create sharp corners automagically.
             None => ctx.set_attribute(
-                handle.clone(),
+                handle,
                 &[
                     // Disabling below flag activates the specific
                     // deRose extensions for the C-C creasing
@@ -1713,13 +6137,11 @@ impl Polyhedron {
                     // http://graphics.pixar.com/people/derose/publications/Geri/paper.pdf
                     nsi::integer!(
                         "subdivision.smoothcreasecorners",
-                        smooth_corners.unwrap_or(false) as _
+                        options.smooth_corners.unwrap_or(false) as _
                     ),
                 ],
             ),
         };
-
-        handle
     }
 
     /// Write the polyhedron to a
@@ -1736,6 +6158,9 @@ impl Polyhedron {
     ///
     /// The return value, on success, is the final, complete path of
     /// the OBJ file.
+    ///
+    /// Use [`write_obj()`](Polyhedron::write_obj()) to write to anything
+    /// else than a file named after the polyhedron.
     #[cfg(feature = "obj")]
     pub fn write_to_obj(
         &self,
@@ -1743,38 +6168,366 @@ impl Polyhedron {
         reverse_winding: bool,
     ) -> Result<PathBuf, Box<dyn Error>> {
         let path = destination.join(format!("polyhedron-{}.obj", self.name));
-        let mut file = File::create(path.clone())?;
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        self.write_obj(&mut file, reverse_winding)?;
+
+        file.flush()?;
+
+        Ok(path)
+    }
 
-        writeln!(file, "o {}", self.name)?;
+    /// Write the polyhedron as a
+    /// [Wavefront OBJ](https://en.wikipedia.org/wiki/Wavefront_.obj_file)
+    /// to the given `writer`.
+    ///
+    /// Depending on the target coordinate system (left- or right
+    /// handed) the mesh’s winding order can be reversed with the
+    /// `reverse_winding` flag.
+    #[cfg(feature = "obj")]
+    pub fn write_obj<W: IoWrite>(
+        &self,
+        writer: &mut W,
+        reverse_winding: bool,
+    ) -> Result<(), Box<dyn Error>> {
+        writeln!(writer, "o {}", self.name)?;
 
         for vertex in &self.points {
-            writeln!(file, "v {} {} {}", vertex.x, vertex.y, vertex.z)?;
+            writeln!(writer, "v {} {} {}", vertex.x, vertex.y, vertex.z)?;
         }
 
         match reverse_winding {
             true => {
                 for face in &self.face_index {
-                    write!(file, "f")?;
+                    write!(writer, "f")?;
                     for vertex_index in face.iter().rev() {
-                        write!(file, " {}", vertex_index + 1)?;
+                        write!(writer, " {}", vertex_index + 1)?;
                     }
-                    writeln!(file)?;
+                    writeln!(writer)?;
                 }
             }
             false => {
                 for face in &self.face_index {
-                    write!(file, "f")?;
+                    write!(writer, "f")?;
                     for vertex_index in face {
-                        write!(file, " {}", vertex_index + 1)?;
+                        write!(writer, " {}", vertex_index + 1)?;
                     }
-                    writeln!(file)?;
+                    writeln!(writer)?;
                 }
             }
         };
 
-        file.flush()?;
+        Ok(())
+    }
+
+    /// Write the polyhedron to a
+    /// [Wavefront OBJ](https://en.wikipedia.org/wiki/Wavefront_.obj_file)
+    /// file with normals and a group per face set, and a companion
+    /// [MTL](https://en.wikipedia.org/wiki/Wavefront_.obj_file#Material_template_library)
+    /// file with a material per face set.
+    ///
+    /// The [`name`](Polyhedron::name()) of the polyhedron is appended
+    /// to the given `destination` and postfixed with the extensions
+    /// `.obj` and `.mtl`.
+    ///
+    /// See [`write_obj_with_face_sets()`](Polyhedron::write_obj_with_face_sets())
+    /// for the arguments. Each face set’s material gets the color of the
+    /// set, cycling through the `palette`. Faces in no face set are white.
+    /// If the `palette` is empty no MTL file is written.
+    ///
+    /// The return value, on success, is the final, complete path of
+    /// the OBJ file.
+    #[cfg(feature = "obj")]
+    pub fn write_to_obj_with_face_sets(
+        &self,
+        destination: &Path,
+        reverse_winding: bool,
+        normal_type: Option<NormalType>,
+        palette: &[[u8; 3]],
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let path = destination.join(format!("polyhedron-{}.obj", self.name));
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        let material_library = match palette.is_empty() {
+            true => None,
+            false => {
+                let file_name = format!("polyhedron-{}.mtl", self.name);
+                let mut material_file = BufWriter::new(File::create(destination.join(&file_name))?);
+                self.write_mtl(&mut material_file, palette)?;
+                material_file.flush()?;
+                Some(file_name)
+            }
+        };
+
+        self.write_obj_with_face_sets(
+            &mut file,
+            reverse_winding,
+            normal_type,
+            material_library.as_deref(),
+        )?;
+
+        file.flush()?;
+
+        Ok(path)
+    }
+
+    /// Returns the name of the group and material of the face set with
+    /// the given index in OBJ and MTL files.
+    #[cfg(feature = "obj")]
+    fn obj_group_name(&self, set: usize) -> String {
+        match set < self.face_set_index.len() {
+            true => format!("face_set_{}", set),
+            false => "no_face_set".to_string(),
+        }
+    }
+
+    /// Write the polyhedron as a
+    /// [Wavefront OBJ](https://en.wikipedia.org/wiki/Wavefront_.obj_file)
+    /// to the given `writer`, with a group per face set.
+    ///
+    /// Each face is in the group of the first face set it is in. Faces in
+    /// no face set are in an additional group, `no_face_set`. The other
+    /// groups are called `face_set_` followed by the index of the set.
+    ///
+    /// Depending on the target coordinate system (left- or right
+    /// handed) the mesh’s winding order can be reversed with the
+    /// `reverse_winding` flag.
+    ///
+    /// # Arguments
+    /// * `normal_type` - The kind of normals to write, if any. With
+    ///   [`NormalType::Flat`] there is one normal per face, with
+    ///   [`NormalType::Smooth`] one per point.
+    ///
+    /// * `material_library` - The MTL file to reference. If given, each
+    ///   group uses the material of the same name. See
+    ///   [`write_mtl()`](Polyhedron::write_mtl()).
+    #[cfg(feature = "obj")]
+    pub fn write_obj_with_face_sets<W: IoWrite>(
+        &self,
+        writer: &mut W,
+        reverse_winding: bool,
+        normal_type: Option<NormalType>,
+        material_library: Option<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        if let Some(material_library) = material_library {
+            writeln!(writer, "mtllib {}", material_library)?;
+        }
+        writeln!(writer, "o {}", self.name)?;
+
+        for vertex in &self.points {
+            writeln!(writer, "v {} {} {}", vertex.x, vertex.y, vertex.z)?;
+        }
+
+        let normals = normal_type
+            .map(|normal_type| self.normals(normal_type))
+            .unwrap_or_default();
+        for normal in &normals {
+            writeln!(writer, "vn {} {} {}", normal.x, normal.y, normal.z)?;
+        }
+
+        let first_face_sets = self.first_face_sets();
+        for set in 0..=self.face_set_index.len() {
+            let faces = self
+                .face_index
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| first_face_sets[*index] == set)
+                .collect::<Vec<_>>();
+            if faces.is_empty() {
+                continue;
+            }
+
+            let group_name = self.obj_group_name(set);
+            writeln!(writer, "g {}", group_name)?;
+            if material_library.is_some() {
+                writeln!(writer, "usemtl {}", group_name)?;
+            }
+
+            for (index, face) in faces {
+                let vertices: Box<dyn Iterator<Item = &VertexKey>> = match reverse_winding {
+                    true => Box::new(face.iter().rev()),
+                    false => Box::new(face.iter()),
+                };
+                write!(writer, "f")?;
+                for vertex_index in vertices {
+                    match normal_type {
+                        None => write!(writer, " {}", vertex_index + 1)?,
+                        Some(NormalType::Flat) => {
+                            write!(writer, " {}//{}", vertex_index + 1, index + 1)?
+                        }
+                        Some(NormalType::Smooth) => {
+                            write!(writer, " {}//{}", vertex_index + 1, vertex_index + 1)?
+                        }
+                    }
+                }
+                writeln!(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write a
+    /// [MTL](https://en.wikipedia.org/wiki/Wavefront_.obj_file#Material_template_library)
+    /// material library for
+    /// [`write_obj_with_face_sets()`](Polyhedron::write_obj_with_face_sets())
+    /// to the given `writer`.
+    ///
+    /// Each face set’s material gets the color of the set, cycling through
+    /// the `palette`. The material for faces in no face set is white.
+    #[cfg(feature = "obj")]
+    pub fn write_mtl<W: IoWrite>(
+        &self,
+        writer: &mut W,
+        palette: &[[u8; 3]],
+    ) -> Result<(), Box<dyn Error>> {
+        for set in 0..=self.face_set_index.len() {
+            let color = match set < self.face_set_index.len() && !palette.is_empty() {
+                true => palette[set % palette.len()],
+                false => [255; 3],
+            };
+            writeln!(writer, "newmtl {}", self.obj_group_name(set))?;
+            writeln!(
+                writer,
+                "Kd {} {} {}",
+                color[0] as f32 / 255.0,
+                color[1] as f32 / 255.0,
+                color[2] as f32 / 255.0
+            )?;
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a polyhedron from a
+    /// [Wavefront OBJ](https://en.wikipedia.org/wiki/Wavefront_.obj_file)
+    /// file.
+    ///
+    /// Only vertex positions and polygonal faces are read. Normals, texture
+    /// coordinates, groups and materials are ignored. The name is taken from
+    /// the first `o` statement. All faces are put into a single face set.
+    ///
+    /// Depending on the source coordinate system (left- or right
+    /// handed) the mesh’s winding order can be reversed with the
+    /// `reverse_winding` flag.
+    ///
+    /// If `merge_epsilon` is given, points closer to each other than this
+    /// are merged with [`merge_points()`](Polyhedron::merge_points()). This
+    /// welds meshes whose vertices were split, e.g. along texture seams.
+    #[cfg(feature = "obj")]
+    pub fn read_from_obj(
+        mut reader: impl Read,
+        merge_epsilon: Option<Float>,
+        reverse_winding: bool,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+
+        let mut name = String::new();
+        let mut points = Points::new();
+        let mut face_index = Faces::new();
+
+        for line in text.lines() {
+            let mut tokens = line
+                .split('#')
+                .next()
+                .unwrap_or_default()
+                .split_whitespace();
+            match tokens.next() {
+                Some("o") if name.is_empty() => name = tokens.join(" "),
+                Some("v") => {
+                    let coordinates = tokens
+                        .take(3)
+                        .map(str::parse)
+                        .collect::<Result<Vec<Float>, _>>()?;
+                    if coordinates.len() < 3 {
+                        return Err(format!("expected three coordinates: {}", line).into());
+                    }
+                    points.push(Point::new(coordinates[0], coordinates[1], coordinates[2]));
+                }
+                Some("f") => {
+                    let mut face = tokens
+                        .map(|vertex| {
+                            // Only the position of `v/vt/vn` is used.
+                            let index: i64 =
+                                vertex.split('/').next().unwrap_or_default().parse()?;
+                            // Negative indices count from the last point.
+                            let index = if index < 0 {
+                                points.len() as i64 + index
+                            } else {
+                                index - 1
+                            };
+                            if 0 <= index && index < points.len() as i64 {
+                                Ok(index as VertexKey)
+                            } else {
+                                Err(format!("vertex index out of range: {}", line).into())
+                            }
+                        })
+                        .collect::<Result<Face, Box<dyn Error>>>()?;
+                    if reverse_winding {
+                        face.reverse();
+                    }
+                    face_index.push(face);
+                }
+                _ => (),
+            }
+        }
+
+        let mut polyhedron = Self::from(&name, points, face_index, None);
+        polyhedron.face_set_index = vec![(0..polyhedron.face_index.len() as VertexKey).collect()];
+        if merge_epsilon.is_some() {
+            polyhedron.merge_points(merge_epsilon);
+        }
+        polyhedron.remove_unused_points();
 
-        Ok(path)
+        Ok(polyhedron)
+    }
+
+    /// Creates a polyhedron from the [convex
+    /// hull](https://en.wikipedia.org/wiki/Convex_hull) of `points`, e.g. a
+    /// scan or a set of random samples.
+    ///
+    /// Coplanar triangles of the hull are merged into polygons and points
+    /// inside the hull or on its edges are dropped. The faces are put into
+    /// face sets by their number of vertices.
+    ///
+    /// If there are fewer than four points or they are all coplanar the
+    /// result is empty.
+    pub fn from_points_hull(points: &[Point]) -> Self {
+        let face_index = convex_hull(points);
+        let mut polyhedron = Self::from("Hull", points.to_vec(), face_index, None);
+        polyhedron.merge_coplanar_faces(None);
+
+        // Points on the edges of the hull are not corners.
+        let points = &polyhedron.points;
+        polyhedron.face_index.iter_mut().for_each(|face| {
+            *face = face
+                .iter()
+                .circular_tuple_windows::<(_, _, _)>()
+                .filter(|(a, b, c)| {
+                    let incoming = (points[**b as usize] - points[**a as usize]).normalized();
+                    let outgoing = (points[**c as usize] - points[**b as usize]).normalized();
+                    1e-4 < incoming.cross(outgoing).mag()
+                })
+                .map(|(_, b, _)| *b)
+                .collect();
+        });
+        polyhedron.remove_unused_points();
+        polyhedron.face_set_index = polyhedron.face_sets_by_arity();
+
+        polyhedron
+    }
+
+    /// Creates a polyhedron named `name` from the convex hull of `points`
+    /// and centers it on its centroid. See
+    /// [`from_points_hull()`](Polyhedron::from_points_hull()).
+    fn from_hull(name: &str, points: Points) -> Self {
+        let mut polyhedron = Self::from_points_hull(&points);
+        polyhedron.name = name.to_string();
+        center_on_centroid(&mut polyhedron.points);
+
+        polyhedron
     }
 
     pub fn tetrahedron() -> Self {
@@ -1820,136 +6573,691 @@ impl Polyhedron {
                 vec![5, 4, 6, 7],
                 vec![3, 1, 5, 7],
             ],
-            face_set_index: vec![(0..6).collect()],
-            name: String::from("C"),
+            face_set_index: vec![(0..6).collect()],
+            name: String::from("C"),
+        }
+    }
+
+    pub fn octahedron() -> Self {
+        let c0 = f64::FRAC_1_SQRT_2() as Float;
+
+        Self {
+            points: vec![
+                Point::new(0.0, 0.0, c0),
+                Point::new(0.0, 0.0, -c0),
+                Point::new(c0, 0.0, 0.0),
+                Point::new(-c0, 0.0, 0.0),
+                Point::new(0.0, c0, 0.0),
+                Point::new(0.0, -c0, 0.0),
+            ],
+            face_index: vec![
+                vec![4, 2, 0],
+                vec![3, 4, 0],
+                vec![5, 3, 0],
+                vec![2, 5, 0],
+                vec![5, 2, 1],
+                vec![3, 5, 1],
+                vec![4, 3, 1],
+                vec![2, 4, 1],
+            ],
+            face_set_index: vec![(0..8).collect()],
+            name: String::from("O"),
+        }
+    }
+
+    pub fn dodecahedron() -> Self {
+        let c0 = (0.5 * PHI) as Float;
+        let c1 = (0.5 * PHI * PHI) as Float;
+
+        Self {
+            points: vec![
+                Point::new(0.0, 0.5, c1),
+                Point::new(0.0, 0.5, -c1),
+                Point::new(0.0, -0.5, c1),
+                Point::new(0.0, -0.5, -c1),
+                Point::new(c1, 0.0, 0.5),
+                Point::new(c1, 0.0, -0.5),
+                Point::new(-c1, 0.0, 0.5),
+                Point::new(-c1, 0.0, -0.5),
+                Point::new(0.5, c1, 0.0),
+                Point::new(0.5, -c1, 0.0),
+                Point::new(-0.5, c1, 0.0),
+                Point::new(-0.5, -c1, 0.0),
+                Point::new(c0, c0, c0),
+                Point::new(c0, c0, -c0),
+                Point::new(c0, -c0, c0),
+                Point::new(c0, -c0, -c0),
+                Point::new(-c0, c0, c0),
+                Point::new(-c0, c0, -c0),
+                Point::new(-c0, -c0, c0),
+                Point::new(-c0, -c0, -c0),
+            ],
+            face_index: vec![
+                vec![12, 4, 14, 2, 0],
+                vec![16, 10, 8, 12, 0],
+                vec![2, 18, 6, 16, 0],
+                vec![17, 10, 16, 6, 7],
+                vec![19, 3, 1, 17, 7],
+                vec![6, 18, 11, 19, 7],
+                vec![15, 3, 19, 11, 9],
+                vec![14, 4, 5, 15, 9],
+                vec![11, 18, 2, 14, 9],
+                vec![8, 10, 17, 1, 13],
+                vec![5, 4, 12, 8, 13],
+                vec![1, 3, 15, 5, 13],
+            ],
+            face_set_index: vec![(0..12).collect()],
+            name: String::from("D"),
+        }
+    }
+
+    pub fn icosahedron() -> Self {
+        let c0 = (0.5 * PHI) as Float;
+
+        Self {
+            points: vec![
+                Point::new(0.5, 0.0, c0),
+                Point::new(0.5, 0.0, -c0),
+                Point::new(-0.5, 0.0, c0),
+                Point::new(-0.5, 0.0, -c0),
+                Point::new(c0, 0.5, 0.0),
+                Point::new(c0, -0.5, 0.0),
+                Point::new(-c0, 0.5, 0.0),
+                Point::new(-c0, -0.5, 0.0),
+                Point::new(0.0, c0, 0.5),
+                Point::new(0.0, c0, -0.5),
+                Point::new(0.0, -c0, 0.5),
+                Point::new(0.0, -c0, -0.5),
+            ],
+            face_index: vec![
+                vec![10, 2, 0],
+                vec![5, 10, 0],
+                vec![4, 5, 0],
+                vec![8, 4, 0],
+                vec![2, 8, 0],
+                vec![6, 8, 2],
+                vec![7, 6, 2],
+                vec![10, 7, 2],
+                vec![11, 7, 10],
+                vec![5, 11, 10],
+                vec![1, 11, 5],
+                vec![4, 1, 5],
+                vec![9, 1, 4],
+                vec![8, 9, 4],
+                vec![6, 9, 8],
+                vec![3, 9, 6],
+                vec![7, 3, 6],
+                vec![11, 3, 7],
+                vec![1, 3, 11],
+                vec![9, 3, 1],
+            ],
+            face_set_index: vec![(0..20).collect()],
+            name: String::from("I"),
+        }
+    }
+
+    /// Creates the regular convex polyhedron with the [Schläfli
+    /// symbol](https://en.wikipedia.org/wiki/Schl%C3%A4fli_symbol) *{p, q}*,
+    /// i.e. the Platonic solid whose faces are *p*-gons, *q* of them meeting
+    /// at each vertex.
+    ///
+    /// Returns an error if there is no such polyhedron.
+    pub fn from_schlafli(p: usize, q: usize) -> Result<Self, String> {
+        match (p, q) {
+            (3, 3) => Ok(Self::tetrahedron()),
+            (4, 3) => Ok(Self::hexahedron()),
+            (3, 4) => Ok(Self::octahedron()),
+            (5, 3) => Ok(Self::dodecahedron()),
+            (3, 5) => Ok(Self::icosahedron()),
+            _ => Err(format!(
+                "{{{}, {}}} is not a convex regular polyhedron",
+                p, q
+            )),
+        }
+    }
+
+    /// Creates a polyhedron from a [Schläfli
+    /// symbol](https://en.wikipedia.org/wiki/Schl%C3%A4fli_symbol) given as a
+    /// string.
+    ///
+    /// Supported are the Platonic solids, *{p, q}*, the prisms, *t{2, n}* or
+    /// *{n}×{}* (also written *{n}x{}*), and the antiprisms, *sr{2, n}* or
+    /// *s{2, 2n}*. Whitespace is ignored.
+    ///
+    /// Returns an error if the symbol can't be parsed or doesn't describe one
+    /// of these.
+    pub fn from_schlafli_symbol(symbol: &str) -> Result<Self, String> {
+        let symbol: String = symbol.chars().filter(|c| !c.is_whitespace()).collect();
+        let error = || format!("unsupported Schläfli symbol: {}", symbol);
+
+        let numbers = |braces: &str| -> Option<Vec<usize>> {
+            braces
+                .strip_prefix('{')?
+                .strip_suffix('}')?
+                .split(',')
+                .map(|number| number.parse().ok())
+                .collect()
+        };
+
+        if let Some(n) = symbol
+            .strip_suffix("×{}")
+            .or_else(|| symbol.strip_suffix("x{}"))
+        {
+            return match numbers(n).as_deref() {
+                Some([n]) if 3 <= *n => Ok(Self::prism(*n)),
+                _ => Err(error()),
+            };
+        }
+
+        let brace = symbol.find('{').ok_or_else(error)?;
+        match (&symbol[..brace], numbers(&symbol[brace..]).as_deref()) {
+            ("", Some([p, q])) => Self::from_schlafli(*p, *q),
+            ("t", Some([2, n])) if 3 <= *n => Ok(Self::prism(*n)),
+            ("sr", Some([2, n])) if 3 <= *n => Ok(Self::antiprism(*n)),
+            ("s", Some([2, n])) if 6 <= *n && 0 == n % 2 => Ok(Self::antiprism(n / 2)),
+            _ => Err(error()),
+        }
+    }
+
+    /// Creates an *n*-gonal antiprism with equilateral triangle sides and a
+    /// circumradius of one. *n* is clamped to be at least three.
+    ///
+    /// The two caps and the band of triangles are in separate face sets.
+    pub fn antiprism(n: usize) -> Self {
+        let n = if n < 3 { 3 } else { n };
+
+        // Angle.
+        let theta = f64::TAU() / n as f64;
+        // Half the distance between the two rings that makes the band
+        // triangles equilateral.
+        let h = ((theta * 0.5).sin().powi(2) - (theta * 0.25).sin().powi(2)).sqrt();
+        // Scale to unit circumradius.
+        let s = 1.0 / (1.0 + h * h).sqrt();
+
+        let mut face_index = vec![
+            (0..n).map(|i| i as VertexKey).collect::<Vec<_>>(),
+            (n..2 * n).rev().map(|i| i as VertexKey).collect::<Vec<_>>(),
+        ];
+
+        // Band.
+        face_index.extend((0..n).flat_map(|i| {
+            vec![
+                vec![
+                    i as VertexKey,
+                    (i + n) as VertexKey,
+                    ((i + 1) % n) as VertexKey,
+                ],
+                vec![
+                    (i + n) as VertexKey,
+                    ((i + 1) % n + n) as VertexKey,
+                    ((i + 1) % n) as VertexKey,
+                ],
+            ]
+        }));
+
+        Self {
+            name: format!("A{}", n),
+            points: (0..n)
+                .map(move |i| {
+                    let angle = i as f64 * theta;
+                    rounded_point(s * angle.cos(), s * h, s * angle.sin())
+                })
+                .chain((0..n).map(move |i| {
+                    let angle = (i as f64 + 0.5) * theta;
+                    rounded_point(s * angle.cos(), -s * h, s * angle.sin())
+                }))
+                .collect(),
+            face_index,
+            face_set_index: vec![vec![0, 1], (2..2 * n as VertexKey + 2).collect()],
+        }
+    }
+
+    /// Creates an *n*-gonal pyramid. The base has a circumradius of one and
+    /// the pyramid is centered on its centroid. *n* is clamped to be at
+    /// least three.
+    ///
+    /// The base and the sides are in separate face sets.
+    /// # Arguments
+    /// * `height` – The height of the apex above the base. Defaults to the
+    ///   height that makes the sides equilateral triangles. For *n > 5*,
+    ///   where this is impossible, it defaults to the length of a base edge.
+    pub fn pyramid(n: usize, height: Option<Float>) -> Self {
+        let n = if n < 3 { 3 } else { n };
+
+        // Angle.
+        let theta = f64::TAU() / n as f64;
+        // Base edge.
+        let edge = 2.0 * (theta * 0.5).sin();
+        let height = height.unwrap_or(if n < 6 {
+            (edge * edge - 1.0).sqrt() as Float
+        } else {
+            edge as Float
+        });
+
+        let mut face_index = vec![(0..n).rev().map(|i| i as VertexKey).collect::<Vec<_>>()];
+
+        // Sides.
+        face_index.extend(
+            (0..n).map(|i| vec![n as VertexKey, i as VertexKey, ((i + 1) % n) as VertexKey]),
+        );
+
+        Self {
+            name: format!("Y{}", n),
+            points: (0..n)
+                .map(move |i| {
+                    let angle = i as f64 * theta;
+                    Point::new(angle.cos() as _, -0.25 * height, angle.sin() as _)
+                })
+                .chain(once(Point::new(0.0, 0.75 * height, 0.0)))
+                .collect(),
+            face_index,
+            face_set_index: vec![vec![0], (1..n as VertexKey + 1).collect()],
+        }
+    }
+
+    /// Creates an *n*-gonal prism with square sides and a circumradius of
+    /// one. *n* is clamped to be at least three.
+    ///
+    /// The two caps and the sides are in separate face sets.
+    pub fn prism(n: usize) -> Self {
+        let n = if n < 3 { 3 } else { n };
+
+        // Angle.
+        let theta = f64::TAU() / n as f64;
+        // Half-edge.
+        let h = (theta * 0.5).sin();
+        // Scale to unit circumradius.
+        let s = 1.0 / (1.0 + h * h).sqrt();
+
+        let mut face_index = vec![
+            (0..n).map(|i| i as VertexKey).collect::<Vec<_>>(),
+            (n..2 * n).rev().map(|i| i as VertexKey).collect::<Vec<_>>(),
+        ];
+
+        // Sides.
+        face_index.extend((0..n).map(|i| {
+            vec![
+                i as VertexKey,
+                (i + n) as VertexKey,
+                ((i + 1) % n + n) as VertexKey,
+                ((i + 1) % n) as VertexKey,
+            ]
+        }));
+
+        Self {
+            name: format!("P{}", n),
+            points: (0..n)
+                .map(move |i| {
+                    let angle = i as f64 * theta;
+                    rounded_point(s * angle.cos(), s * h, s * angle.sin())
+                })
+                .chain((0..n).map(move |i| {
+                    let angle = i as f64 * theta;
+                    rounded_point(s * angle.cos(), -s * h, s * angle.sin())
+                }))
+                .collect(),
+
+            face_index,
+            face_set_index: vec![vec![0, 1], (2..n as VertexKey + 2).collect()],
+        }
+    }
+
+    /// Creates an *n*-gonal [bipyramid](https://en.wikipedia.org/wiki/Bipyramid),
+    /// the dual of an *n*-gonal prism, with a circumradius of one. *n* is
+    /// clamped to be at least three.
+    ///
+    /// The upper and the lower half are in separate face sets.
+    pub fn bipyramid(n: usize) -> Self {
+        let n = if n < 3 { 3 } else { n };
+
+        // Angle.
+        let theta = f64::TAU() / n as f64;
+
+        let top = n as VertexKey;
+        let bottom = top + 1;
+        let face_index = (0..n)
+            .map(|i| vec![top, i as VertexKey, ((i + 1) % n) as VertexKey])
+            .chain((0..n).map(|i| vec![bottom, ((i + 1) % n) as VertexKey, i as VertexKey]))
+            .collect();
+
+        Self {
+            name: format!("dP{}", n),
+            points: (0..n)
+                .map(move |i| {
+                    let angle = i as f64 * theta;
+                    rounded_point(angle.cos(), 0.0, angle.sin())
+                })
+                .chain(once(Point::new(0.0, 1.0, 0.0)))
+                .chain(once(Point::new(0.0, -1.0, 0.0)))
+                .collect(),
+            face_index,
+            face_set_index: vec![
+                (0..n as VertexKey).collect(),
+                (n as VertexKey..2 * n as VertexKey).collect(),
+            ],
+        }
+    }
+
+    /// Creates an *n*-gonal [trapezohedron](https://en.wikipedia.org/wiki/Trapezohedron),
+    /// the dual of an *n*-gonal antiprism, with its apices at a distance of
+    /// one from the center. *n* is clamped to be at least three.
+    ///
+    /// The upper and the lower half are in separate face sets.
+    pub fn trapezohedron(n: usize) -> Self {
+        let n = if n < 3 { 3 } else { n };
+
+        // Angle.
+        let theta = f64::TAU() / n as f64;
+        let c = (theta * 0.5).cos();
+        // Height of the two rings. This makes the kites planar.
+        let h = (1.0 - c) / (1.0 + c);
+        // Radius of the rings, from the polar reciprocal of the antiprism.
+        let r =
+            2.0 * ((theta * 0.5).sin().powi(2) - (theta * 0.25).sin().powi(2)).sqrt() / (1.0 + c);
+
+        let top = 2 * n as VertexKey;
+        let bottom = top + 1;
+        let face_index = (0..n)
+            .map(|i| {
+                vec![
+                    top,
+                    i as VertexKey,
+                    (i + n) as VertexKey,
+                    ((i + 1) % n) as VertexKey,
+                ]
+            })
+            .chain((0..n).map(|i| {
+                vec![
+                    bottom,
+                    ((i + 1) % n + n) as VertexKey,
+                    ((i + 1) % n) as VertexKey,
+                    (i + n) as VertexKey,
+                ]
+            }))
+            .collect();
+
+        Self {
+            name: format!("dA{}", n),
+            points: (0..n)
+                .map(move |i| {
+                    let angle = i as f64 * theta;
+                    rounded_point(r * angle.cos(), h, r * angle.sin())
+                })
+                .chain((0..n).map(move |i| {
+                    let angle = (i as f64 + 0.5) * theta;
+                    rounded_point(r * angle.cos(), -h, r * angle.sin())
+                }))
+                .chain(once(Point::new(0.0, 1.0, 0.0)))
+                .chain(once(Point::new(0.0, -1.0, 0.0)))
+                .collect(),
+            face_index,
+            face_set_index: vec![
+                (0..n as VertexKey).collect(),
+                (n as VertexKey..2 * n as VertexKey).collect(),
+            ],
         }
     }
 
-    pub fn octahedron() -> Self {
-        let c0 = 0.707_106_77;
+    /// Creates a class I [geodesic
+    /// sphere](https://en.wikipedia.org/wiki/Geodesic_polyhedron) with a
+    /// circumradius of one by subdividing each edge of an icosahedron into
+    /// `frequency` segments and projecting the new vertices onto the sphere.
+    /// `frequency` is clamped to be at least one.
+    ///
+    /// This is the same as [`geodesic()`](Polyhedron::geodesic()) applied to
+    /// an [`icosahedron()`](Polyhedron::icosahedron()).
+    pub fn icosphere(frequency: usize) -> Self {
+        let mut icosphere = Self::icosahedron();
+        icosphere
+            .geodesic(frequency.max(1), GeodesicClass::I, true)
+            .normalize();
+        icosphere
+    }
+
+    /// Creates the [Goldberg
+    /// polyhedron](https://en.wikipedia.org/wiki/Goldberg_polyhedron)
+    /// *G(m, n)* with a circumradius of one. If *m* and *n* differ and are
+    /// both non-zero the result is chiral. If both are zero *m* is set to
+    /// one, which yields a dodecahedron.
+    ///
+    /// This is the [`reciprocal()`](Polyhedron::reciprocal()) of the
+    /// [`geodesic()`](Polyhedron::geodesic()) icosahedron *(m, n)*. As the
+    /// points of the latter are on a sphere, all faces are planar. The
+    /// twelve pentagons and the hexagons are in separate face sets.
+    pub fn goldberg(m: usize, n: usize) -> Self {
+        let m = if 0 == m + n { 1 } else { m };
+
+        let mut goldberg = Self::icosahedron();
+        goldberg
+            .geodesic(m, GeodesicClass::III(n), true)
+            .reciprocal(None, true)
+            .normalize();
+        goldberg.face_set_index = goldberg.face_sets_by_arity();
+        goldberg
+    }
+
+    /// Creates a torus around the *y* axis made of quadrilaterals.
+    ///
+    /// The torus has genus one, so its Euler characteristic is zero.
+    /// # Arguments
+    /// * `major_segments` – The number of segments around the *y* axis.
+    ///   Clamped to be at least three.
+    /// * `minor_segments` – The number of segments around the tube. Clamped
+    ///   to be at least three.
+    /// * `major_radius` – The distance of the center of the tube from the
+    ///   *y* axis.
+    /// * `minor_radius` – The radius of the tube.
+    pub fn torus(
+        major_segments: usize,
+        minor_segments: usize,
+        major_radius: Float,
+        minor_radius: Float,
+    ) -> Self {
+        let major_segments = major_segments.max(3);
+        let minor_segments = minor_segments.max(3);
+
+        let index = |i: usize, j: usize| {
+            ((i % major_segments) * minor_segments + j % minor_segments) as VertexKey
+        };
 
         Self {
-            points: vec![
-                Point::new(0.0, 0.0, c0),
-                Point::new(0.0, 0.0, -c0),
-                Point::new(c0, 0.0, 0.0),
-                Point::new(-c0, 0.0, 0.0),
-                Point::new(0.0, c0, 0.0),
-                Point::new(0.0, -c0, 0.0),
-            ],
-            face_index: vec![
-                vec![4, 2, 0],
-                vec![3, 4, 0],
-                vec![5, 3, 0],
-                vec![2, 5, 0],
-                vec![5, 2, 1],
-                vec![3, 5, 1],
-                vec![4, 3, 1],
-                vec![2, 4, 1],
-            ],
-            face_set_index: vec![(0..8).collect()],
-            name: String::from("O"),
+            name: format!("Tor{},{}", major_segments, minor_segments),
+            points: (0..major_segments)
+                .flat_map(|i| {
+                    let u = i as Float * Float::TAU() / major_segments as Float;
+                    (0..minor_segments).map(move |j| {
+                        let v = j as Float * Float::TAU() / minor_segments as Float;
+                        let distance = major_radius + minor_radius * v.cos();
+                        Point::new(
+                            distance * u.cos(),
+                            minor_radius * v.sin(),
+                            distance * u.sin(),
+                        )
+                    })
+                })
+                .collect(),
+            face_index: (0..major_segments)
+                .flat_map(|i| {
+                    (0..minor_segments).map(move |j| {
+                        vec![
+                            index(i, j),
+                            index(i + 1, j),
+                            index(i + 1, j + 1),
+                            index(i, j + 1),
+                        ]
+                    })
+                })
+                .collect(),
+            face_set_index: vec![(0..(major_segments * minor_segments) as VertexKey).collect()],
         }
     }
 
-    pub fn dodecahedron() -> Self {
-        let c0 = 0.809_017;
-        let c1 = 1.309_017;
+    /// Creates a sphere with a radius of one made of quadrilaterals by
+    /// subdividing each face of a cube into a grid of `order`²
+    /// quadrilaterals and projecting the points onto the sphere. `order` is
+    /// clamped to be at least one.
+    pub fn quad_sphere(order: usize) -> Self {
+        let mut quad_sphere = Self::hexahedron();
+        quad_sphere
+            .subdivide(Some(order.max(1)), true)
+            .spherize(None, true)
+            .normalize();
+        quad_sphere
+    }
+
+    /// Creates a UV-sphere around the *y* axis with a radius of one.
+    ///
+    /// The faces touching the poles are triangles, all other faces are
+    /// quadrilaterals. The two are in separate face sets.
+    /// # Arguments
+    /// * `segments` – The number of segments around the *y* axis. Clamped to
+    ///   be at least three.
+    /// * `rings` – The number of segments from pole to pole. Clamped to be
+    ///   at least two.
+    pub fn uv_sphere(segments: usize, rings: usize) -> Self {
+        let segments = segments.max(3);
+        let rings = rings.max(2);
+
+        // Index of the `i`th point of ring `k`, counted from one at the
+        // north pole.
+        let ring = |k: usize, i: usize| (1 + (k - 1) * segments + i % segments) as VertexKey;
+        let south_pole = ((rings - 1) * segments + 1) as VertexKey;
+
+        let mut face_index: Faces = (0..segments)
+            .map(|i| vec![0, ring(1, i), ring(1, i + 1)])
+            .chain(
+                (0..segments).map(|i| vec![south_pole, ring(rings - 1, i + 1), ring(rings - 1, i)]),
+            )
+            .collect();
+        face_index.extend((1..rings - 1).flat_map(|k| {
+            (0..segments).map(move |i| {
+                vec![
+                    ring(k, i),
+                    ring(k + 1, i),
+                    ring(k + 1, i + 1),
+                    ring(k, i + 1),
+                ]
+            })
+        }));
 
         Self {
-            points: vec![
-                Point::new(0.0, 0.5, c1),
-                Point::new(0.0, 0.5, -c1),
-                Point::new(0.0, -0.5, c1),
-                Point::new(0.0, -0.5, -c1),
-                Point::new(c1, 0.0, 0.5),
-                Point::new(c1, 0.0, -0.5),
-                Point::new(-c1, 0.0, 0.5),
-                Point::new(-c1, 0.0, -0.5),
-                Point::new(0.5, c1, 0.0),
-                Point::new(0.5, -c1, 0.0),
-                Point::new(-0.5, c1, 0.0),
-                Point::new(-0.5, -c1, 0.0),
-                Point::new(c0, c0, c0),
-                Point::new(c0, c0, -c0),
-                Point::new(c0, -c0, c0),
-                Point::new(c0, -c0, -c0),
-                Point::new(-c0, c0, c0),
-                Point::new(-c0, c0, -c0),
-                Point::new(-c0, -c0, c0),
-                Point::new(-c0, -c0, -c0),
-            ],
-            face_index: vec![
-                vec![12, 4, 14, 2, 0],
-                vec![16, 10, 8, 12, 0],
-                vec![2, 18, 6, 16, 0],
-                vec![17, 10, 16, 6, 7],
-                vec![19, 3, 1, 17, 7],
-                vec![6, 18, 11, 19, 7],
-                vec![15, 3, 19, 11, 9],
-                vec![14, 4, 5, 15, 9],
-                vec![11, 18, 2, 14, 9],
-                vec![8, 10, 17, 1, 13],
-                vec![5, 4, 12, 8, 13],
-                vec![1, 3, 15, 5, 13],
+            name: format!("UV{},{}", segments, rings),
+            points: once(Point::new(0.0, 1.0, 0.0))
+                .chain((1..rings).flat_map(|k| {
+                    let theta = k as Float * Float::PI() / rings as Float;
+                    (0..segments).map(move |i| {
+                        let phi = i as Float * Float::TAU() / segments as Float;
+                        Point::new(
+                            theta.sin() * phi.cos(),
+                            theta.cos(),
+                            theta.sin() * phi.sin(),
+                        )
+                    })
+                }))
+                .chain(once(Point::new(0.0, -1.0, 0.0)))
+                .collect(),
+            face_set_index: vec![
+                (0..2 * segments as VertexKey).collect(),
+                (2 * segments as VertexKey..face_index.len() as VertexKey).collect(),
             ],
-            face_set_index: vec![(0..12).collect()],
-            name: String::from("D"),
+            face_index,
         }
     }
 
-    pub fn icosahedron() -> Self {
-        let c0 = 0.809_017;
+    /// Creates a capped cylinder around the *y* axis with a radius of one,
+    /// centered on the origin.
+    ///
+    /// The two caps and the sides are in separate face sets.
+    /// # Arguments
+    /// * `segments` – The number of segments around the *y* axis. Clamped to
+    ///   be at least three.
+    /// * `rings` – The number of segments along the *y* axis. Clamped to be
+    ///   at least one.
+    /// * `height` – The height of the cylinder. Default value is `2.0`.
+    pub fn cylinder(segments: usize, rings: usize, height: Option<Float>) -> Self {
+        let segments = segments.max(3);
+        let rings = rings.max(1);
+        let height = height.unwrap_or(2.0);
+
+        let ring = |k: usize, i: usize| (k * segments + i % segments) as VertexKey;
+
+        let mut face_index = vec![
+            (0..segments).map(|i| ring(0, i)).collect::<Vec<_>>(),
+            (0..segments)
+                .rev()
+                .map(|i| ring(rings, i))
+                .collect::<Vec<_>>(),
+        ];
+
+        // Sides.
+        face_index.extend((0..rings).flat_map(|k| {
+            (0..segments).map(move |i| {
+                vec![
+                    ring(k, i),
+                    ring(k + 1, i),
+                    ring(k + 1, i + 1),
+                    ring(k, i + 1),
+                ]
+            })
+        }));
 
         Self {
-            points: vec![
-                Point::new(0.5, 0.0, c0),
-                Point::new(0.5, 0.0, -c0),
-                Point::new(-0.5, 0.0, c0),
-                Point::new(-0.5, 0.0, -c0),
-                Point::new(c0, 0.5, 0.0),
-                Point::new(c0, -0.5, 0.0),
-                Point::new(-c0, 0.5, 0.0),
-                Point::new(-c0, -0.5, 0.0),
-                Point::new(0.0, c0, 0.5),
-                Point::new(0.0, c0, -0.5),
-                Point::new(0.0, -c0, 0.5),
-                Point::new(0.0, -c0, -0.5),
-            ],
-            face_index: vec![
-                vec![10, 2, 0],
-                vec![5, 10, 0],
-                vec![4, 5, 0],
-                vec![8, 4, 0],
-                vec![2, 8, 0],
-                vec![6, 8, 2],
-                vec![7, 6, 2],
-                vec![10, 7, 2],
-                vec![11, 7, 10],
-                vec![5, 11, 10],
-                vec![1, 11, 5],
-                vec![4, 1, 5],
-                vec![9, 1, 4],
-                vec![8, 9, 4],
-                vec![6, 9, 8],
-                vec![3, 9, 6],
-                vec![7, 3, 6],
-                vec![11, 3, 7],
-                vec![1, 3, 11],
-                vec![9, 3, 1],
-            ],
-            face_set_index: vec![(0..20).collect()],
-            name: String::from("I"),
+            name: format!("Cyl{},{}", segments, rings),
+            points: (0..=rings)
+                .flat_map(|k| {
+                    let y = height * (0.5 - k as Float / rings as Float);
+                    (0..segments).map(move |i| {
+                        let phi = i as Float * Float::TAU() / segments as Float;
+                        Point::new(phi.cos(), y, phi.sin())
+                    })
+                })
+                .collect(),
+            face_set_index: vec![vec![0, 1], (2..face_index.len() as VertexKey).collect()],
+            face_index,
         }
     }
 
-    pub fn prism(n: usize) -> Self {
-        let n = if n < 3 { 3 } else { n };
+    /// Creates an *n*-gonal [frustum](https://en.wikipedia.org/wiki/Frustum)
+    /// around the *y* axis, centered on the origin.
+    ///
+    /// The two caps and the sides are in separate face sets.
+    /// # Arguments
+    /// * `n` – The number of sides. Clamped to be at least three.
+    /// * `top_radius` – The circumradius of the top cap.
+    /// * `bottom_radius` – The circumradius of the bottom cap.
+    /// * `height` – The distance between the caps.
+    ///
+    /// The bottom radius should be positive. If the top radius is zero the
+    /// top cap collapses into the apex of a pyramid. The base and the sides
+    /// are then in separate face sets, as for
+    /// [`pyramid()`](Polyhedron::pyramid()).
+    pub fn frustum(n: usize, top_radius: Float, bottom_radius: Float, height: Float) -> Self {
+        let n = n.max(3);
+
+        let ring = |radius: Float, y: Float| {
+            (0..n).map(move |i| {
+                let phi = i as Float * Float::TAU() / n as Float;
+                Point::new(radius * phi.cos(), y, radius * phi.sin())
+            })
+        };
 
-        // Angle.
-        let theta = f32::TAU() / n as f32;
-        // Half-edge.
-        let h = (theta * 0.5).sin();
+        if top_radius.abs() < EPSILON {
+            let mut face_index = vec![(0..n).rev().map(|i| i as VertexKey).collect::<Vec<_>>()];
+
+            // Sides.
+            face_index.extend(
+                (0..n).map(|i| vec![n as VertexKey, i as VertexKey, ((i + 1) % n) as VertexKey]),
+            );
+
+            return Self {
+                name: format!("Fru{}", n),
+                points: ring(bottom_radius, -0.5 * height)
+                    .chain(once(Point::new(0.0, 0.5 * height, 0.0)))
+                    .collect(),
+                face_index,
+                face_set_index: vec![vec![0], (1..n as VertexKey + 1).collect()],
+            };
+        }
 
         let mut face_index = vec![
             (0..n).map(|i| i as VertexKey).collect::<Vec<_>>(),
@@ -1967,27 +7275,139 @@ impl Polyhedron {
         }));
 
         Self {
-            name: format!("P{}", n),
-            points: (0..n)
-                .map(move |i| {
-                    Point::new(
-                        (i as f32 * theta).cos() as _,
-                        h,
-                        (i as f32 * theta).sin() as _,
-                    )
-                })
-                .chain((0..n).map(move |i| {
-                    Point::new(
-                        (i as f32 * theta).cos() as _,
-                        -h,
-                        (i as f32 * theta).sin() as _,
-                    )
-                }))
+            name: format!("Fru{}", n),
+            points: ring(top_radius, 0.5 * height)
+                .chain(ring(bottom_radius, -0.5 * height))
                 .collect(),
-
             face_index,
-            face_set_index: Vec::new(),
+            face_set_index: vec![vec![0, 1], (2..n as VertexKey + 2).collect()],
+        }
+    }
+
+    /// Creates a [Waterman
+    /// polyhedron](https://en.wikipedia.org/wiki/Waterman_polyhedron), the
+    /// convex hull of the points of the face-centered cubic lattice inside a
+    /// sphere around the origin.
+    ///
+    /// Faces are grouped into face sets by arity.
+    /// # Arguments
+    /// * `radius` – The circumradius of the result.
+    /// * `root` – The Waterman root. The sphere has a radius of √(2 ·
+    ///   `root`) in lattice units, where nearest lattice points are √2
+    ///   apart. Clamped to be at least one, which yields a cuboctahedron.
+    pub fn waterman(radius: Float, root: usize) -> Self {
+        let root = root.max(1) as i32;
+        let bound = ((2 * root) as Float).sqrt() as i32;
+
+        let points: Points = (-bound..=bound)
+            .flat_map(|x| {
+                (-bound..=bound).flat_map(move |y| (-bound..=bound).map(move |z| [x, y, z]))
+            })
+            .filter(|[x, y, z]| 0 == (x + y + z) % 2 && x * x + y * y + z * z <= 2 * root)
+            .map(|[x, y, z]| Point::new(x as Float, y as Float, z as Float))
+            .collect();
+
+        let mut waterman = Self::from_hull(&format!("Wat{}", root), points);
+        let scale = radius / max_magnitude(&waterman.points);
+        waterman.points.iter_mut().for_each(|point| *point *= scale);
+        waterman
+    }
+
+    /// Creates the [zonohedron](https://en.wikipedia.org/wiki/Zonohedron)
+    /// generated by `vectors`, i.e. the Minkowski sum of the line segments
+    /// from `-vector / 2` to `vector / 2`, centered on the origin.
+    ///
+    /// All faces are zonogons. E.g. the six axes of the icosahedron generate
+    /// a rhombic triacontahedron. Faces are grouped into face sets by arity.
+    ///
+    /// If `vectors` do not span all three dimensions the result is empty.
+    pub fn zonohedron(vectors: &[Vector]) -> Self {
+        let name = format!("Z{}", vectors.len());
+
+        // Start with three vectors that span a volume so the hull of the
+        // partial sums can be used to prune points after each step.
+        let mut vectors = vectors.to_vec();
+        if let Some((a, b, c)) = (0..vectors.len())
+            .tuple_combinations()
+            .find(|(a, b, c)| EPSILON < vectors[*a].cross(vectors[*b]).dot(vectors[*c]).abs())
+        {
+            vectors.swap(0, a);
+            vectors.swap(1, b);
+            vectors.swap(2, c);
+        } else {
+            return Self::from(&name, Points::new(), Faces::new(), None);
+        }
+
+        let points = vectors.iter().fold(vec![Point::zero()], |points, vector| {
+            let points: Points = points
+                .iter()
+                .flat_map(|point| vec![*point - 0.5 * *vector, *point + 0.5 * *vector])
+                .collect();
+            let hull = convex_hull(&points);
+            if hull.is_empty() {
+                points
+            } else {
+                hull.iter()
+                    .flatten()
+                    .unique()
+                    .map(|v| points[*v as usize])
+                    .collect()
+            }
+        });
+
+        Self::from_hull(&name, points)
+    }
+
+    /// Creates the [spherical Voronoi
+    /// diagram](https://en.wikipedia.org/wiki/Voronoi_diagram) of `sites`
+    /// projected onto the unit sphere. Each face is the cell of one site and
+    /// all points lie on the unit sphere.
+    ///
+    /// If there are fewer than four sites or they all lie on a common great
+    /// circle the result is empty.
+    /// # Arguments
+    /// * `sites` – The sites. Duplicates are ignored.
+    /// * `lloyd_iterations` – The number of steps of [Lloyd
+    ///   relaxation](https://en.wikipedia.org/wiki/Lloyd%27s_algorithm) that
+    ///   are applied first. Each step moves the sites to the centroids of
+    ///   their cells, making the cells more uniform.
+    pub fn spherical_voronoi(sites: &[Point], lloyd_iterations: usize) -> Self {
+        let name = format!("V{}", sites.len());
+        let mut sites: Points = sites.iter().map(|site| site.normalized()).collect();
+        let mut voronoi = Self::from(&name, Points::new(), Faces::new(), None);
+
+        for _ in 0..=lloyd_iterations {
+            // The convex hull of the sites is their Delaunay triangulation.
+            // Sites on a common circle end up in a single face.
+            let face_index = convex_hull(&sites);
+            if face_index.is_empty() {
+                break;
+            }
+            let mut delaunay = Self::from(&name, sites, face_index, None);
+            delaunay.merge_coplanar_faces(None);
+
+            // The circumcenter of each face of the triangulation is a corner
+            // of the cells.
+            let points: Points = delaunay
+                .face_index
+                .par_iter()
+                .map(|face| -newell_normal(&index_as_points(face, &delaunay.points)).normalized())
+                .collect();
+            let face_index = points_to_faces(&delaunay.points, &delaunay.face_index);
+
+            sites = face_index
+                .par_iter()
+                .map(|face| centroid_ref(&index_as_points(face, &points)).normalized())
+                .collect();
+            voronoi = Self::from(
+                &name,
+                points,
+                face_index,
+                Some(vec![(0..sites.len() as VertexKey).collect()]),
+            );
         }
+
+        voronoi
     }
 }
 
@@ -2030,3 +7450,97 @@ impl From<Polyhedron> for Mesh {
         mesh
     }
 }
+
+#[cfg(feature = "bevy")]
+impl From<&Polyhedron> for Mesh {
+    fn from(polyhedron: &Polyhedron) -> Self {
+        Mesh::from(polyhedron.clone())
+    }
+}
+
+#[cfg(feature = "kiss3d")]
+use std::convert::TryFrom;
+
+/// Converts the polyhedron into a mesh with flat normals.
+///
+/// Fails if the mesh needs more than 65536 points. Each face gets its own
+/// points, so this happens well before the polyhedron has that many.
+#[cfg(feature = "kiss3d")]
+impl TryFrom<&Polyhedron> for kiss3d::resource::Mesh {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(polyhedron: &Polyhedron) -> Result<Self, Self::Error> {
+        use kiss3d::nalgebra as na;
+
+        let (index, points, normals) =
+            polyhedron.to_triangle_mesh_buffers_by_face_set(NormalType::Flat);
+
+        Ok(kiss3d::resource::Mesh::new(
+            points
+                .par_iter()
+                .map(|p| na::Point3::new(p.x, p.y, p.z))
+                .collect(),
+            // kiss3d expects counter-clockwise faces.
+            index
+                .iter()
+                .flatten()
+                .tuples::<(_, _, _)>()
+                .map(|(a, b, c)| -> Result<_, Self::Error> {
+                    Ok(na::Point3::new(
+                        u16::try_from(*a)?,
+                        u16::try_from(*c)?,
+                        u16::try_from(*b)?,
+                    ))
+                })
+                .collect::<Result<_, _>>()?,
+            Some(
+                normals
+                    .par_iter()
+                    .map(|n| na::Vector3::new(n.x, n.y, n.z))
+                    .collect(),
+            ),
+            None,
+            false,
+        ))
+    }
+}
+
+#[cfg(feature = "kiss3d")]
+impl TryFrom<Polyhedron> for kiss3d::resource::Mesh {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(polyhedron: Polyhedron) -> Result<Self, Self::Error> {
+        Self::try_from(&polyhedron)
+    }
+}
+
+#[cfg(feature = "three-d")]
+impl From<&Polyhedron> for three_d::CPUMesh {
+    fn from(polyhedron: &Polyhedron) -> Self {
+        let (index, points, normals) =
+            polyhedron.to_triangle_mesh_buffers_by_face_set(NormalType::Flat);
+
+        three_d::CPUMesh {
+            name: polyhedron.name.clone(),
+            positions: points.iter().flat_map(|p| vec![p.x, p.y, p.z]).collect(),
+            // three-d expects counter-clockwise faces.
+            indices: Some(three_d::Indices::U32(
+                index
+                    .iter()
+                    .flatten()
+                    .tuples::<(_, _, _)>()
+                    .flat_map(|(a, b, c)| vec![*a, *c, *b])
+                    .collect(),
+            )),
+            normals: Some(normals.iter().flat_map(|n| vec![n.x, n.y, n.z]).collect()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "three-d")]
+impl From<Polyhedron> for three_d::CPUMesh {
+    fn from(polyhedron: Polyhedron) -> Self {
+        Self::from(&polyhedron)
+    }
+}
@@ -0,0 +1,93 @@
+//! Parsing of [Conway
+//! notation](https://en.wikipedia.org/wiki/Conway_polyhedron_notation)
+//! strings, e.g. `"gapcD"`.
+use crate::*;
+
+/// Applies an operator, with its default parameters.
+type Operator = fn(&mut Polyhedron);
+
+/// The operators given by their letter.
+const OPERATORS: [(char, Operator); 17] = [
+    ('a', |p| {
+        p.ambo(None, true);
+    }),
+    ('b', |p| {
+        p.bevel(None, None, None, true);
+    }),
+    ('c', |p| {
+        p.chamfer(None, None, None, true);
+    }),
+    ('d', |p| {
+        p.dual(true);
+    }),
+    ('e', |p| {
+        p.expand(None, None, true);
+    }),
+    ('g', |p| {
+        p.gyro(None, None, None, true);
+    }),
+    ('j', |p| {
+        p.join(None, None, true);
+    }),
+    ('k', |p| {
+        p.kis(None, None, true);
+    }),
+    ('m', |p| {
+        p.meta(None, None, None, true);
+    }),
+    ('n', |p| {
+        p.needle(None, None, true);
+    }),
+    ('o', |p| {
+        p.ortho(None, None, None, None, true);
+    }),
+    ('p', |p| {
+        p.propeller(None, None, true);
+    }),
+    ('q', |p| {
+        p.quinto(None, None, true);
+    }),
+    ('r', |p| {
+        p.reflect(true);
+    }),
+    ('s', |p| {
+        p.snub(None, None, true);
+    }),
+    ('t', |p| {
+        p.truncate(None, None, true);
+    }),
+    ('z', |p| {
+        p.zip(None, None, true);
+    }),
+];
+
+impl Polyhedron {
+    /// Creates a polyhedron from its [Conway
+    /// notation](https://en.wikipedia.org/wiki/Conway_polyhedron_notation),
+    /// e.g. `"gapcD"`.
+    ///
+    /// The notation is a seed, as understood by
+    /// [`from_name()`](Polyhedron::from_name()), prefixed by any number of
+    /// operators. These are applied right to left, with their default
+    /// parameters. The operators are `a`mbo, `b`evel, `c`hamfer, `d`ual,
+    /// `e`xpand, `g`yro, `j`oin, `k`is, `m`eta, `n`eedle, `o`rtho,
+    /// `p`ropeller, `q`uinto, `r`eflect, `s`nub, `t`runcate and `z`ip.
+    ///
+    /// The longest suffix that names a seed is used as the seed, so `"tI"`
+    /// is the truncated icosahedron seed and not `t` applied to `I`.
+    ///
+    /// Returns `None` if the notation does not end with a seed or contains
+    /// an unknown operator.
+    pub fn from_notation(notation: &str) -> Option<Self> {
+        let (operators, mut polyhedron) = notation.char_indices().find_map(|(i, _)| {
+            Polyhedron::from_name(&notation[i..]).map(|seed| (&notation[..i], seed))
+        })?;
+
+        for letter in operators.chars().rev() {
+            let (_, operator) = OPERATORS.iter().find(|(name, _)| letter == *name)?;
+            operator(&mut polyhedron);
+        }
+
+        Some(polyhedron)
+    }
+}
@@ -0,0 +1,180 @@
+//! Reading and writing of the [OFF](https://en.wikipedia.org/wiki/OFF_(file_format))
+//! file format used by e.g. [Antiprism](https://www.antiprism.com/) and
+//! [polyHédronisme](https://levskaya.github.io/polyhedronisme/).
+use crate::*;
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+impl Polyhedron {
+    /// Reads a polyhedron from an [OFF](https://en.wikipedia.org/wiki/OFF_(file_format))
+    /// file.
+    ///
+    /// Comments, colors, normals and texture coordinates are ignored. The
+    /// `OFF` header is optional, as in most readers.
+    ///
+    /// Depending on the source of the file the mesh’s winding order can be
+    /// reversed with the `reverse_winding` flag.
+    ///
+    /// All faces are put into a single face set.
+    /// # Example
+    /// ```
+    /// # use polyhedron_ops::Polyhedron;
+    /// let file = std::fs::File::open("tetrahedron.off");
+    /// # let file: Result<&[u8], ()> = Ok(b"OFF\n4 4 6\n1 1 1\n1 -1 -1\n-1 1 -1\n-1 -1 1\n3 2 1 0\n3 3 2 0\n3 1 3 0\n3 2 3 1\n");
+    /// let tetrahedron = Polyhedron::read_from_off(file.unwrap(), false).unwrap();
+    /// ```
+    pub fn read_from_off(
+        mut reader: impl Read,
+        reverse_winding: bool,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+
+        // Lines split into tokens, with comments and empty lines removed.
+        let mut lines = text
+            .lines()
+            .map(|line| {
+                line.split('#')
+                    .next()
+                    .unwrap_or_default()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+            })
+            .filter(|tokens| !tokens.is_empty());
+        let mut next_line = |what: &str| {
+            lines
+                .next()
+                .ok_or_else(|| format!("unexpected end of file, expected {}", what))
+        };
+
+        let mut counts = next_line("header")?;
+        if counts[0].ends_with("OFF") {
+            if counts[0].contains('4') || counts[0].contains('n') {
+                return Err(format!("unsupported OFF variant: {}", counts[0]).into());
+            }
+            counts.remove(0);
+            if counts.is_empty() {
+                counts = next_line("number of vertices and faces")?;
+            }
+        }
+        if counts.len() < 2 {
+            return Err("expected number of vertices and faces".into());
+        }
+        let points_len: usize = counts[0].parse()?;
+        let faces_len: usize = counts[1].parse()?;
+
+        let mut points = Points::with_capacity(points_len);
+        for _ in 0..points_len {
+            let vertex = next_line("vertex")?;
+            if vertex.len() < 3 {
+                return Err(format!("expected three coordinates: {}", vertex.join(" ")).into());
+            }
+            points.push(Point::new(
+                vertex[0].parse()?,
+                vertex[1].parse()?,
+                vertex[2].parse()?,
+            ));
+        }
+
+        let mut face_index = Faces::with_capacity(faces_len);
+        for _ in 0..faces_len {
+            let tokens = next_line("face")?;
+            let arity: usize = tokens[0].parse()?;
+            if tokens.len() <= arity {
+                return Err(
+                    format!("expected {} vertex indices: {}", arity, tokens.join(" ")).into(),
+                );
+            }
+            let mut face = tokens[1..=arity]
+                .iter()
+                .map(|token| token.parse::<VertexKey>())
+                .collect::<Result<Face, _>>()?;
+            if let Some(index) = face.iter().find(|index| points_len <= **index as usize) {
+                return Err(format!("vertex index out of range: {}", index).into());
+            }
+            if reverse_winding {
+                face.reverse();
+            }
+            face_index.push(face);
+        }
+
+        Ok(Self::from(
+            "",
+            points,
+            face_index,
+            Some(vec![(0..faces_len as VertexKey).collect()]),
+        ))
+    }
+
+    /// Write the polyhedron to an [OFF](https://en.wikipedia.org/wiki/OFF_(file_format))
+    /// file.
+    ///
+    /// The [`name`](Polyhedron::name()) of the polyhedron is appended
+    /// to the given `destination` and postfixed with the extension
+    /// `.off`.
+    ///
+    /// If the `palette` is not empty, each face gets the RGB color of the
+    /// first face set it is in, cycling through the `palette`. Faces that
+    /// are in no face set are white.
+    ///
+    /// Depending on the target coordinate system (left- or right
+    /// handed) the mesh’s winding order can be reversed with the
+    /// `reverse_winding` flag.
+    ///
+    /// The return value, on success, is the final, complete path of
+    /// the OFF file.
+    pub fn write_to_off(
+        &self,
+        destination: &Path,
+        palette: &[[u8; 3]],
+        reverse_winding: bool,
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let path = destination.join(format!("polyhedron-{}.off", self.name));
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        writeln!(file, "OFF")?;
+        writeln!(file, "# {}", self.name)?;
+        writeln!(
+            file,
+            "{} {} {}",
+            self.points.len(),
+            self.face_index.len(),
+            self.to_edges().len()
+        )?;
+
+        for vertex in &self.points {
+            writeln!(file, "{} {} {}", vertex.x, vertex.y, vertex.z)?;
+        }
+
+        let colors = if palette.is_empty() {
+            Vec::new()
+        } else {
+            self.face_set_colors(palette)
+        };
+
+        for (index, face) in self.face_index.iter().enumerate() {
+            write!(file, "{}", face.len())?;
+            if reverse_winding {
+                for vertex_index in face.iter().rev() {
+                    write!(file, " {}", vertex_index)?;
+                }
+            } else {
+                for vertex_index in face {
+                    write!(file, " {}", vertex_index)?;
+                }
+            }
+            if let Some(color) = colors.get(index) {
+                write!(file, " {} {} {}", color[0], color[1], color[2])?;
+            }
+            writeln!(file)?;
+        }
+
+        file.flush()?;
+
+        Ok(path)
+    }
+}
@@ -0,0 +1,107 @@
+//! Optimization of GPU buffers with
+//! [meshoptimizer](https://github.com/zeux/meshoptimizer).
+use crate::*;
+
+impl Polyhedron {
+    /// Returns the buffers of [`to_gpu_buffers()`](Polyhedron::to_gpu_buffers()),
+    /// optimized for rendering with a GPU.
+    ///
+    /// The triangles are reordered for the post-transform vertex cache and
+    /// to reduce overdraw. The vertices are reordered in the order they are
+    /// used by the triangles.
+    ///
+    /// # Arguments
+    /// * `overdraw_threshold` - How much worse vertex cache efficiency may
+    ///   get to reduce overdraw. E.g. `1.05` allows it to get 5% worse
+    ///   (default: 1.05).
+    pub fn to_optimized_gpu_buffers(
+        &self,
+        options: &GpuBufferOptions,
+        overdraw_threshold: Option<f32>,
+    ) -> (Vec<f32>, Vec<u32>) {
+        let (vertices, mut index) = self.to_gpu_buffers(options);
+        let vertex_count = vertices.len() / options.vertex_size();
+
+        index = meshopt::optimize_vertex_cache(&index, vertex_count);
+
+        // Positions come first in either layout.
+        let stride = match options.layout {
+            VertexLayout::Interleaved => options.vertex_size(),
+            VertexLayout::Planar => 3,
+        };
+        let vertex_data = meshopt::VertexDataAdapter::new(
+            bytemuck::cast_slice(&vertices[..stride * vertex_count]),
+            stride * std::mem::size_of::<f32>(),
+            0,
+        )
+        .unwrap();
+        meshopt::optimize_overdraw_in_place(
+            &index,
+            &vertex_data,
+            overdraw_threshold.unwrap_or(1.05),
+        );
+
+        // Number the vertices in the order of their first use.
+        let mut remap = vec![u32::MAX; vertex_count];
+        let mut order = Vec::with_capacity(vertex_count);
+        index.iter_mut().for_each(|vertex| {
+            if u32::MAX == remap[*vertex as usize] {
+                remap[*vertex as usize] = order.len() as u32;
+                order.push(*vertex as usize);
+            }
+            *vertex = remap[*vertex as usize];
+        });
+
+        let vertices = match options.layout {
+            VertexLayout::Interleaved => order
+                .iter()
+                .flat_map(|vertex| {
+                    vertices[vertex * stride..(vertex + 1) * stride]
+                        .iter()
+                        .copied()
+                })
+                .collect(),
+            VertexLayout::Planar => options
+                .attribute_sizes()
+                .iter()
+                .scan(0, |start, size| {
+                    let attribute = &vertices[*start..*start + size * vertex_count];
+                    *start += size * vertex_count;
+                    Some(
+                        order
+                            .iter()
+                            .flat_map(|vertex| {
+                                attribute[vertex * size..(vertex + 1) * size]
+                                    .iter()
+                                    .copied()
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .flatten()
+                .collect(),
+        };
+
+        (vertices, index)
+    }
+
+    /// Returns the buffers of
+    /// [`to_optimized_gpu_buffers()`](Polyhedron::to_optimized_gpu_buffers())
+    /// with all attributes of vertices quantized to half precision floats.
+    ///
+    /// This halves the size of the vertex buffer.
+    pub fn to_quantized_gpu_buffers(
+        &self,
+        options: &GpuBufferOptions,
+        overdraw_threshold: Option<f32>,
+    ) -> (Vec<u16>, Vec<u32>) {
+        let (vertices, index) = self.to_optimized_gpu_buffers(options, overdraw_threshold);
+        (
+            vertices
+                .iter()
+                .map(|value| meshopt::quantize_half(*value))
+                .collect(),
+            index,
+        )
+    }
+}
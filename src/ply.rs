@@ -0,0 +1,85 @@
+//! Support for the [PLY](https://en.wikipedia.org/wiki/PLY_(file_format))
+//! file format used by e.g. [MeshLab](https://www.meshlab.net/).
+use crate::*;
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+impl Polyhedron {
+    /// Write the polyhedron to an ASCII
+    /// [PLY](https://en.wikipedia.org/wiki/PLY_(file_format)) file.
+    ///
+    /// The [`name`](Polyhedron::name()) of the polyhedron is appended
+    /// to the given `destination` and postfixed with the extension
+    /// `.ply`.
+    ///
+    /// If the `palette` is not empty, each face gets the RGB color of the
+    /// first face set it is in, cycling through the `palette`. Faces that
+    /// are in no face set are white.
+    ///
+    /// Depending on the target coordinate system (left- or right
+    /// handed) the mesh’s winding order can be reversed with the
+    /// `reverse_winding` flag.
+    ///
+    /// The return value, on success, is the final, complete path of
+    /// the PLY file.
+    pub fn write_to_ply(
+        &self,
+        destination: &Path,
+        palette: &[[u8; 3]],
+        reverse_winding: bool,
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let path = destination.join(format!("polyhedron-{}.ply", self.name));
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        writeln!(file, "ply")?;
+        writeln!(file, "format ascii 1.0")?;
+        writeln!(file, "comment {}", self.name)?;
+        writeln!(file, "element vertex {}", self.points.len())?;
+        writeln!(file, "property float x")?;
+        writeln!(file, "property float y")?;
+        writeln!(file, "property float z")?;
+        writeln!(file, "element face {}", self.face_index.len())?;
+        writeln!(file, "property list uint uint vertex_indices")?;
+        if !palette.is_empty() {
+            writeln!(file, "property uchar red")?;
+            writeln!(file, "property uchar green")?;
+            writeln!(file, "property uchar blue")?;
+        }
+        writeln!(file, "end_header")?;
+
+        for vertex in &self.points {
+            writeln!(file, "{} {} {}", vertex.x, vertex.y, vertex.z)?;
+        }
+
+        let colors = if palette.is_empty() {
+            Vec::new()
+        } else {
+            self.face_set_colors(palette)
+        };
+
+        for (index, face) in self.face_index.iter().enumerate() {
+            write!(file, "{}", face.len())?;
+            if reverse_winding {
+                for vertex_index in face.iter().rev() {
+                    write!(file, " {}", vertex_index)?;
+                }
+            } else {
+                for vertex_index in face {
+                    write!(file, " {}", vertex_index)?;
+                }
+            }
+            if let Some(color) = colors.get(index) {
+                write!(file, " {} {} {}", color[0], color[1], color[2])?;
+            }
+            writeln!(file)?;
+        }
+
+        file.flush()?;
+
+        Ok(path)
+    }
+}
@@ -0,0 +1,278 @@
+//! [Python](https://www.python.org/) bindings via [PyO3](https://pyo3.rs/).
+//!
+//! The module is called `polyhedron_ops` and contains a single class,
+//! `Polyhedron`. It can be built and installed with
+//! [maturin](https://github.com/PyO3/maturin), e.g. `maturin develop`.
+//!
+//! ```python
+//! from polyhedron_ops import Polyhedron
+//!
+//! polyhedron = Polyhedron("D").chamfer().propeller().ambo()
+//! print(polyhedron.name, len(polyhedron.faces))
+//! ```
+use crate::*;
+#[cfg(any(feature = "gltf", feature = "obj", feature = "off", feature = "ply"))]
+use pyo3::exceptions::PyIOError;
+use pyo3::{exceptions::PyValueError, prelude::*};
+#[cfg(any(feature = "gltf", feature = "obj", feature = "off", feature = "ply"))]
+use std::path::Path;
+
+/// A polyhedron. Operators change it in place and return it, so they can
+/// be chained.
+#[pyclass(name = "Polyhedron")]
+#[derive(Clone)]
+struct PyPolyhedron(Polyhedron);
+
+#[pymethods]
+impl PyPolyhedron {
+    /// Creates the seed with the given name, e.g. `"D"` or `"P5"`. See
+    /// [`from_name()`](Polyhedron::from_name()).
+    #[new]
+    fn new(name: &str) -> PyResult<Self> {
+        Polyhedron::from_name(name)
+            .map(PyPolyhedron)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown polyhedron {}", name)))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Polyhedron('{}')", self.0.name())
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.0.name().clone()
+    }
+
+    #[getter]
+    fn points(&self) -> Vec<[Float; 3]> {
+        self.0.points_as_arrays().to_vec()
+    }
+
+    #[getter]
+    fn faces(&self) -> Faces {
+        self.0.faces().clone()
+    }
+
+    #[getter]
+    fn face_sets(&self) -> Vec<FaceSet> {
+        self.0.face_set_index.clone()
+    }
+
+    #[getter]
+    fn edges(&self) -> Edges {
+        self.0.to_edges()
+    }
+
+    fn copy(&self) -> Self {
+        self.clone()
+    }
+
+    #[pyo3(signature = (ratio = None))]
+    fn ambo(mut slf: PyRefMut<'_, Self>, ratio: Option<Float>) -> PyRefMut<'_, Self> {
+        slf.0.ambo(ratio, true);
+        slf
+    }
+
+    #[pyo3(signature = (ratio = None, height = None))]
+    fn bevel(
+        mut slf: PyRefMut<'_, Self>,
+        ratio: Option<Float>,
+        height: Option<Float>,
+    ) -> PyRefMut<'_, Self> {
+        slf.0.bevel(ratio, height, None, true);
+        slf
+    }
+
+    #[pyo3(signature = (ratio = None))]
+    fn chamfer(mut slf: PyRefMut<'_, Self>, ratio: Option<Float>) -> PyRefMut<'_, Self> {
+        slf.0.chamfer(ratio, None, None, true);
+        slf
+    }
+
+    fn dual(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf.0.dual(true);
+        slf
+    }
+
+    #[pyo3(signature = (ratio = None))]
+    fn expand(mut slf: PyRefMut<'_, Self>, ratio: Option<Float>) -> PyRefMut<'_, Self> {
+        slf.0.expand(ratio, None, true);
+        slf
+    }
+
+    #[pyo3(signature = (ratio = None, height = None))]
+    fn gyro(
+        mut slf: PyRefMut<'_, Self>,
+        ratio: Option<Float>,
+        height: Option<Float>,
+    ) -> PyRefMut<'_, Self> {
+        slf.0.gyro(ratio, height, None, true);
+        slf
+    }
+
+    #[pyo3(signature = (ratio = None, height = None))]
+    fn join(
+        mut slf: PyRefMut<'_, Self>,
+        ratio: Option<Float>,
+        height: Option<Float>,
+    ) -> PyRefMut<'_, Self> {
+        slf.0.join(ratio, height, true);
+        slf
+    }
+
+    #[pyo3(signature = (height = None))]
+    fn kis(mut slf: PyRefMut<'_, Self>, height: Option<Float>) -> PyRefMut<'_, Self> {
+        slf.0.kis(height, None, true);
+        slf
+    }
+
+    #[pyo3(signature = (height = None))]
+    fn meta(mut slf: PyRefMut<'_, Self>, height: Option<Float>) -> PyRefMut<'_, Self> {
+        slf.0.meta(None, height, None, true);
+        slf
+    }
+
+    #[pyo3(signature = (height = None))]
+    fn needle(mut slf: PyRefMut<'_, Self>, height: Option<Float>) -> PyRefMut<'_, Self> {
+        slf.0.needle(height, None, true);
+        slf
+    }
+
+    #[pyo3(signature = (ratio = None, height = None))]
+    fn ortho(
+        mut slf: PyRefMut<'_, Self>,
+        ratio: Option<Float>,
+        height: Option<Float>,
+    ) -> PyRefMut<'_, Self> {
+        slf.0.ortho(ratio, None, height, None, true);
+        slf
+    }
+
+    #[pyo3(signature = (ratio = None))]
+    fn propeller(mut slf: PyRefMut<'_, Self>, ratio: Option<Float>) -> PyRefMut<'_, Self> {
+        slf.0.propeller(ratio, None, true);
+        slf
+    }
+
+    #[pyo3(signature = (ratio = None, height = None))]
+    fn quinto(
+        mut slf: PyRefMut<'_, Self>,
+        ratio: Option<Float>,
+        height: Option<Float>,
+    ) -> PyRefMut<'_, Self> {
+        slf.0.quinto(ratio, height, true);
+        slf
+    }
+
+    fn reflect(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf.0.reflect(true);
+        slf
+    }
+
+    #[pyo3(signature = (ratio = None, height = None))]
+    fn snub(
+        mut slf: PyRefMut<'_, Self>,
+        ratio: Option<Float>,
+        height: Option<Float>,
+    ) -> PyRefMut<'_, Self> {
+        slf.0.snub(ratio, height, true);
+        slf
+    }
+
+    #[pyo3(signature = (depth = None))]
+    fn truncate(mut slf: PyRefMut<'_, Self>, depth: Option<Float>) -> PyRefMut<'_, Self> {
+        slf.0.truncate(depth, None, true);
+        slf
+    }
+
+    #[pyo3(signature = (height = None))]
+    fn zip(mut slf: PyRefMut<'_, Self>, height: Option<Float>) -> PyRefMut<'_, Self> {
+        slf.0.zip(height, None, true);
+        slf
+    }
+
+    #[pyo3(signature = (iterations = 100))]
+    fn canonicalize(mut slf: PyRefMut<'_, Self>, iterations: usize) -> PyRefMut<'_, Self> {
+        slf.0.canonicalize(iterations);
+        slf
+    }
+
+    fn normalize(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf.0.normalize();
+        slf
+    }
+
+    fn planarize(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf.0.planarize(None, None);
+        slf
+    }
+
+    fn triangulate(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf.0.triangulate(None);
+        slf
+    }
+
+    /// Returns an interleaved vertex buffer, with positions and flat
+    /// normals, and a counter-clockwise triangle index buffer. See
+    /// [`to_gpu_buffers()`](Polyhedron::to_gpu_buffers()).
+    fn to_gpu_buffers(&self) -> (Vec<f32>, Vec<u32>) {
+        self.0.to_gpu_buffers(&GpuBufferOptions::default())
+    }
+
+    #[cfg(feature = "gltf")]
+    #[pyo3(signature = (destination, smooth = false))]
+    fn write_to_gltf(&self, destination: &str, smooth: bool) -> PyResult<String> {
+        let normal_type = match smooth {
+            true => NormalType::Smooth,
+            false => NormalType::Flat,
+        };
+        self.0
+            .write_to_gltf(Path::new(destination), normal_type)
+            .map(|path| path.to_string_lossy().into_owned())
+            .map_err(|error| PyIOError::new_err(error.to_string()))
+    }
+
+    #[cfg(feature = "obj")]
+    #[pyo3(signature = (destination, reverse_winding = false))]
+    fn write_to_obj(&self, destination: &str, reverse_winding: bool) -> PyResult<String> {
+        self.0
+            .write_to_obj(Path::new(destination), reverse_winding)
+            .map(|path| path.to_string_lossy().into_owned())
+            .map_err(|error| PyIOError::new_err(error.to_string()))
+    }
+
+    #[cfg(feature = "off")]
+    #[pyo3(signature = (destination, palette = Vec::new(), reverse_winding = false))]
+    fn write_to_off(
+        &self,
+        destination: &str,
+        palette: Vec<[u8; 3]>,
+        reverse_winding: bool,
+    ) -> PyResult<String> {
+        self.0
+            .write_to_off(Path::new(destination), &palette, reverse_winding)
+            .map(|path| path.to_string_lossy().into_owned())
+            .map_err(|error| PyIOError::new_err(error.to_string()))
+    }
+
+    #[cfg(feature = "ply")]
+    #[pyo3(signature = (destination, palette = Vec::new(), reverse_winding = false))]
+    fn write_to_ply(
+        &self,
+        destination: &str,
+        palette: Vec<[u8; 3]>,
+        reverse_winding: bool,
+    ) -> PyResult<String> {
+        self.0
+            .write_to_ply(Path::new(destination), &palette, reverse_winding)
+            .map(|path| path.to_string_lossy().into_owned())
+            .map_err(|error| PyIOError::new_err(error.to_string()))
+    }
+}
+
+/// The `polyhedron_ops` Python module.
+#[pymodule]
+fn polyhedron_ops(_py: Python<'_>, module: &PyModule) -> PyResult<()> {
+    module.add_class::<PyPolyhedron>()?;
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! Support for [OpenSCAD](https://openscad.org/)’s `polyhedron()`.
+use crate::*;
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufWriter, Write as IoWrite},
+    path::{Path, PathBuf},
+};
+
+impl Polyhedron {
+    /// Write the polyhedron to an [OpenSCAD](https://openscad.org/) file
+    /// containing a call to
+    /// [`polyhedron()`](https://en.wikibooks.org/wiki/OpenSCAD_User_Manual/Primitive_Solids#polyhedron).
+    ///
+    /// The [`name`](Polyhedron::name()) of the polyhedron is appended
+    /// to the given `destination` and postfixed with the extension
+    /// `.scad`.
+    ///
+    /// OpenSCAD expects faces to be ordered clockwise seen from the
+    /// outside, like they are here, so the winding order is kept.
+    ///
+    /// The return value, on success, is the final, complete path of
+    /// the SCAD file.
+    pub fn write_to_scad(&self, destination: &Path) -> Result<PathBuf, Box<dyn Error>> {
+        let path = destination.join(format!("polyhedron-{}.scad", self.name));
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        writeln!(file, "// {}", self.name)?;
+        writeln!(file, "polyhedron(")?;
+        writeln!(file, "    points = [")?;
+        for point in &self.points {
+            writeln!(file, "        [{}, {}, {}],", point.x, point.y, point.z)?;
+        }
+        writeln!(file, "    ],")?;
+        writeln!(file, "    faces = [")?;
+        for face in &self.face_index {
+            writeln!(file, "        [{}],", face.iter().join(", "))?;
+        }
+        writeln!(file, "    ],")?;
+        writeln!(file, "    convexity = 10")?;
+        writeln!(file, ");")?;
+
+        file.flush()?;
+
+        Ok(path)
+    }
+}
@@ -0,0 +1,77 @@
+//! Lookup of seeds by the name they have in [Conway
+//! notation](https://en.wikipedia.org/wiki/Conway_polyhedron_notation), e.g.
+//! `"D"`, `"tI"` or `"A7"`.
+use crate::*;
+
+/// Creates a seed.
+type Seed = fn() -> Polyhedron;
+
+/// The seeds given by their name alone.
+const SEEDS: [(&str, Seed); 18] = [
+    ("T", Polyhedron::tetrahedron),
+    ("C", Polyhedron::hexahedron),
+    ("O", Polyhedron::octahedron),
+    ("D", Polyhedron::dodecahedron),
+    ("I", Polyhedron::icosahedron),
+    ("tT", Polyhedron::truncated_tetrahedron),
+    ("aC", Polyhedron::cuboctahedron),
+    ("tC", Polyhedron::truncated_cube),
+    ("tO", Polyhedron::truncated_octahedron),
+    ("eC", Polyhedron::rhombicuboctahedron),
+    ("bC", Polyhedron::truncated_cuboctahedron),
+    ("sC", Polyhedron::snub_cube),
+    ("aD", Polyhedron::icosidodecahedron),
+    ("tD", Polyhedron::truncated_dodecahedron),
+    ("tI", Polyhedron::truncated_icosahedron),
+    ("eD", Polyhedron::rhombicosidodecahedron),
+    ("bD", Polyhedron::truncated_icosidodecahedron),
+    ("sD", Polyhedron::snub_dodecahedron),
+];
+
+/// Creates the member of a family of seeds with the given number, if it
+/// exists.
+type SeedFamily = fn(usize) -> Option<Polyhedron>;
+
+/// The families of seeds given by a letter followed by a number.
+const SEED_FAMILIES: [(&str, SeedFamily); 6] = [
+    ("P", |n| Some(n).filter(|n| 3 <= *n).map(Polyhedron::prism)),
+    ("A", |n| {
+        Some(n).filter(|n| 3 <= *n).map(Polyhedron::antiprism)
+    }),
+    ("Y", |n| {
+        Some(n)
+            .filter(|n| 3 <= *n)
+            .map(|n| Polyhedron::pyramid(n, None))
+    }),
+    ("dP", |n| {
+        Some(n).filter(|n| 3 <= *n).map(Polyhedron::bipyramid)
+    }),
+    ("dA", |n| {
+        Some(n).filter(|n| 3 <= *n).map(Polyhedron::trapezohedron)
+    }),
+    ("J", Polyhedron::johnson),
+];
+
+impl Polyhedron {
+    /// Creates the seed with the given name.
+    ///
+    /// These are the Platonic solids, `"T"`, `"C"`, `"O"`, `"D"` and `"I"`,
+    /// the Archimedean solids by the name of the operation that derives them
+    /// from a Platonic solid, e.g. `"tI"` or `"sD"`, and the families of
+    /// prisms, `"P5"`, antiprisms, `"A7"`, pyramids, `"Y4"`, bipyramids,
+    /// `"dP5"`, trapezohedra, `"dA7"`, and Johnson solids, `"J38"`.
+    ///
+    /// Returns `None` if there is no seed with this name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        if let Some((_, seed)) = SEEDS.iter().find(|(seed_name, _)| name == *seed_name) {
+            return Some(seed());
+        }
+
+        SEED_FAMILIES.iter().find_map(|(letter, seed)| {
+            name.strip_prefix(letter)
+                .filter(|n| n.chars().all(|c| c.is_ascii_digit()))
+                .and_then(|n| n.parse().ok())
+                .and_then(seed)
+        })
+    }
+}
@@ -0,0 +1,56 @@
+//! Stand-ins for the parts of [`rayon`](https://crates.io/crates/rayon)'s
+//! prelude this crate uses, for when the `parallel` feature is disabled,
+//! e.g. on `wasm32-unknown-unknown`.
+//!
+//! The `par_*` methods simply return the standard, sequential iterators.
+
+pub(crate) trait IntoParallelIterator {
+    type Iter: Iterator;
+
+    fn into_par_iter(self) -> Self::Iter;
+}
+
+impl<T: IntoIterator> IntoParallelIterator for T {
+    type Iter = T::IntoIter;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_iter()
+    }
+}
+
+pub(crate) trait IntoParallelRefIterator<'a> {
+    type Iter: Iterator;
+
+    fn par_iter(&'a self) -> Self::Iter;
+}
+
+impl<'a, T: 'a + ?Sized> IntoParallelRefIterator<'a> for T
+where
+    &'a T: IntoIterator,
+{
+    type Iter = <&'a T as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn par_iter(&'a self) -> Self::Iter {
+        self.into_iter()
+    }
+}
+
+pub(crate) trait IntoParallelRefMutIterator<'a> {
+    type Iter: Iterator;
+
+    fn par_iter_mut(&'a mut self) -> Self::Iter;
+}
+
+impl<'a, T: 'a + ?Sized> IntoParallelRefMutIterator<'a> for T
+where
+    &'a mut T: IntoIterator,
+{
+    type Iter = <&'a mut T as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn par_iter_mut(&'a mut self) -> Self::Iter {
+        self.into_iter()
+    }
+}
@@ -0,0 +1,486 @@
+//! Drawings of polyhedra as [SVG](https://en.wikipedia.org/wiki/Scalable_Vector_Graphics).
+use crate::*;
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fs::File,
+    io::{BufWriter, Write as IoWrite},
+    path::Path,
+};
+
+/// A face laid out in the plane.
+type Polygon = Vec<[Float; 2]>;
+type PolygonSlice = [[Float; 2]];
+/// A line in the plane.
+type Line = [[Float; 2]; 2];
+/// The faces each edge belongs to.
+type EdgeFaces = HashMap<Edge, Vec<usize>>;
+
+/// The width and height of the drawing.
+const SIZE: Float = 1000.0;
+/// The space between the drawing and the border of the image.
+const MARGIN: Float = 20.0;
+/// The color of glue tabs.
+const TAB_COLOR: [u8; 3] = [224, 224, 224];
+
+/// Returns a function that scales and translates points so `points` fit into
+/// the image, keeping their aspect ratio. The *y* axis is flipped to point
+/// down, like in SVG.
+fn fit(points: &[[Float; 2]]) -> impl Fn(&[Float; 2]) -> [Float; 2] {
+    let (min, max) = points
+        .iter()
+        .fold(([Float::MAX; 2], [Float::MIN; 2]), |(min, max), point| {
+            (
+                [min[0].min(point[0]), min[1].min(point[1])],
+                [max[0].max(point[0]), max[1].max(point[1])],
+            )
+        });
+    let extent = (max[0] - min[0]).max(max[1] - min[1]);
+    let scale = if EPSILON < extent {
+        (SIZE - 2.0 * MARGIN) / extent
+    } else {
+        1.0
+    };
+    let offset = [
+        0.5 * (SIZE - scale * (max[0] - min[0])),
+        0.5 * (SIZE - scale * (max[1] - min[1])),
+    ];
+    move |point| {
+        [
+            offset[0] + scale * (point[0] - min[0]),
+            SIZE - offset[1] - scale * (point[1] - min[1]),
+        ]
+    }
+}
+
+/// Returns how far `point` is to the left of the line through `a` and `b`.
+fn side(a: &[Float; 2], b: &[Float; 2], point: &[Float; 2]) -> Float {
+    let (x, y) = (b[0] - a[0], b[1] - a[1]);
+    (x * (point[1] - a[1]) - y * (point[0] - a[0])) / x.hypot(y)
+}
+
+/// Returns `true` if the lines `a` and `b` cross each other. Lines that
+/// merely touch, closer than `tolerance`, do not cross.
+fn cross(a: &Line, b: &Line, tolerance: Float) -> bool {
+    let apart =
+        |x: Float, y: Float| (tolerance < x && y < -tolerance) || (x < -tolerance && tolerance < y);
+    apart(side(&b[0], &b[1], &a[0]), side(&b[0], &b[1], &a[1]))
+        && apart(side(&a[0], &a[1], &b[0]), side(&a[0], &a[1], &b[1]))
+}
+
+/// Returns `true` if `point` is inside the `polygon`.
+fn inside(polygon: &PolygonSlice, point: &[Float; 2]) -> bool {
+    polygon
+        .iter()
+        .circular_tuple_windows::<(_, _)>()
+        .filter(|(a, b)| {
+            (point[1] < a[1]) != (point[1] < b[1])
+                && point[0] < a[0] + (point[1] - a[1]) / (b[1] - a[1]) * (b[0] - a[0])
+        })
+        .count()
+        % 2
+        == 1
+}
+
+/// Returns the average of the corners of the `polygon`.
+fn polygon_centroid(polygon: &PolygonSlice) -> [Float; 2] {
+    let sum = polygon.iter().fold([0.0, 0.0], |sum, point| {
+        [sum[0] + point[0], sum[1] + point[1]]
+    });
+    [
+        sum[0] / polygon.len() as Float,
+        sum[1] / polygon.len() as Float,
+    ]
+}
+
+/// Returns `true` if the polygons `a` and `b` overlap.
+fn overlap(a: &PolygonSlice, b: &PolygonSlice, tolerance: Float) -> bool {
+    inside(a, &polygon_centroid(b))
+        || inside(b, &polygon_centroid(a))
+        || a.iter().circular_tuple_windows::<(_, _)>().any(|(a0, a1)| {
+            b.iter()
+                .circular_tuple_windows::<(_, _)>()
+                .any(|(b0, b1)| cross(&[*a0, *a1], &[*b0, *b1], tolerance))
+        })
+}
+
+/// Returns the glue tab on the `edge` of the `polygon`.
+fn tab(polygon: &PolygonSlice, edge: &Line) -> Polygon {
+    let [a, b] = edge;
+    let along = [(b[0] - a[0]) / 4.0, (b[1] - a[1]) / 4.0];
+    let centroid = polygon_centroid(polygon);
+    // Away from the polygon.
+    let away = if side(a, b, &centroid) < 0.0 {
+        [-along[1], along[0]]
+    } else {
+        [along[1], -along[0]]
+    };
+    vec![
+        *a,
+        [a[0] + along[0] + away[0], a[1] + along[1] + away[1]],
+        [b[0] - along[0] + away[0], b[1] - along[1] + away[1]],
+        *b,
+    ]
+}
+
+/// Formats `lines` as SVG path data.
+fn lines(lines: &[Line]) -> String {
+    lines
+        .iter()
+        .map(|[a, b]| format!("M{},{}L{},{}", a[0], a[1], b[0], b[1]))
+        .join("")
+}
+
+/// Writes an SVG image with the `polygons` filled in the given colors, the
+/// `edges` drawn on top and the `folds` drawn dashed.
+fn write_svg(
+    path: &Path,
+    title: &str,
+    polygons: &[(Polygon, [u8; 3])],
+    edges: &[Line],
+    folds: &[Line],
+) -> Result<(), Box<dyn Error>> {
+    let mut file = BufWriter::new(File::create(path)?);
+
+    writeln!(
+        file,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{0}" viewBox="0 0 {0} {0}">"#,
+        SIZE
+    )?;
+    writeln!(file, "<title>{}</title>", xml_escape(title))?;
+    for (polygon, color) in polygons {
+        writeln!(
+            file,
+            r##"<polygon points="{}" fill="#{:02x}{:02x}{:02x}"/>"##,
+            polygon
+                .iter()
+                .map(|point| format!("{},{}", point[0], point[1]))
+                .join(" "),
+            color[0],
+            color[1],
+            color[2]
+        )?;
+    }
+    writeln!(
+        file,
+        r#"<path d="{}" fill="none" stroke="black" stroke-width="1.5" stroke-linecap="round"/>"#,
+        lines(edges)
+    )?;
+    if !folds.is_empty() {
+        writeln!(
+            file,
+            r#"<path d="{}" fill="none" stroke="black" stroke-width="1" stroke-dasharray="6,4"/>"#,
+            lines(folds)
+        )?;
+    }
+    writeln!(file, "</svg>")?;
+
+    file.flush()?;
+
+    Ok(())
+}
+
+impl Polyhedron {
+    /// Write a [Schlegel diagram](https://en.wikipedia.org/wiki/Schlegel_diagram)
+    /// of the polyhedron to an SVG file at `path`.
+    ///
+    /// The polyhedron is projected through the face with the given index
+    /// into its plane. This face becomes the outer boundary of the diagram
+    /// and all other faces lie inside it. This works for convex
+    /// polyhedra.
+    ///
+    /// If the `palette` is not empty, each face is filled with the color of
+    /// the first face set it is in, cycling through the `palette`. Faces
+    /// that are in no face set are white.
+    ///
+    /// Returns an error if there is no face with the given index.
+    pub fn write_schlegel_svg(
+        &self,
+        face_index: usize,
+        path: &Path,
+        palette: &[[u8; 3]],
+    ) -> Result<(), Box<dyn Error>> {
+        let face = self
+            .face_index
+            .get(face_index)
+            .ok_or_else(|| format!("there is no face with index {}", face_index))?;
+        let face_points = index_as_points(face, &self.points);
+        let face_centroid = centroid_ref(&face_points);
+        // Our faces are clockwise, so the Newell normal points inwards.
+        let normal = -newell_normal(&face_points).normalized();
+
+        // A basis of the plane of the face.
+        let tangent = (*face_points[0] - face_centroid).normalized();
+        let bitangent = normal.cross(tangent);
+
+        // The eye is outside the face, half way to the nearest plane of
+        // another face it would be outside of too.
+        let distance = self
+            .face_index
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != face_index)
+            .filter_map(|(_, other)| {
+                let other_points = index_as_points(other, &self.points);
+                let other_normal = -newell_normal(&other_points).normalized();
+                let cos = normal.dot(other_normal);
+                if EPSILON < cos {
+                    Some((*other_points[0] - face_centroid).dot(other_normal) / cos)
+                } else {
+                    None
+                }
+            })
+            .fold((*face_points[0] - face_centroid).mag(), Float::min);
+        let eye = face_centroid + 0.5 * distance * normal;
+
+        let mut points: Vec<[Float; 2]> = self
+            .points
+            .iter()
+            .map(|point| {
+                let v = *point - eye;
+                let depth = -v.dot(normal);
+                [v.dot(tangent) / depth, v.dot(bitangent) / depth]
+            })
+            .collect();
+        let transform = fit(&points);
+        points
+            .iter_mut()
+            .for_each(|point| *point = transform(point));
+
+        let polygons = if palette.is_empty() {
+            Vec::new()
+        } else {
+            let colors = self.face_set_colors(palette);
+            // The outer face first, as it is the background of the others.
+            once(face_index)
+                .chain((0..self.face_index.len()).filter(|index| *index != face_index))
+                .map(|index| {
+                    (
+                        self.face_index[index]
+                            .iter()
+                            .map(|v| points[*v as usize])
+                            .collect(),
+                        colors[index],
+                    )
+                })
+                .collect()
+        };
+
+        let edges: Vec<_> = self
+            .to_edges()
+            .iter()
+            .map(|edge| [points[edge[0] as usize], points[edge[1] as usize]])
+            .collect();
+
+        write_svg(path, &self.name, &polygons, &edges, &[])
+    }
+
+    /// Lays out the face with the given index in the plane, with its
+    /// vertex at `start` on `a` and the next one on the line towards `b`.
+    ///
+    /// The face is seen from the outside once the *y* axis is flipped.
+    fn lay_out_face(
+        &self,
+        face_index: usize,
+        start: usize,
+        a: [Float; 2],
+        b: [Float; 2],
+    ) -> Polygon {
+        let face = &self.face_index[face_index];
+        let face_points = index_as_points(face, &self.points);
+        let normal = -newell_normal(&face_points).normalized();
+
+        let origin = *face_points[start];
+        let x_axis = (*face_points[(start + 1) % face.len()] - origin).normalized();
+        let y_axis = normal.cross(x_axis);
+
+        let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+        let (cos, sin) = ((b[0] - a[0]) / length, (b[1] - a[1]) / length);
+
+        face_points
+            .iter()
+            .map(|point| {
+                let (x, y) = (
+                    (**point - origin).dot(x_axis),
+                    (**point - origin).dot(y_axis),
+                );
+                [a[0] + x * cos - y * sin, a[1] + x * sin + y * cos]
+            })
+            .collect()
+    }
+
+    /// Unfolds the polyhedron into the plane.
+    ///
+    /// Faces are attached to each other along a spanning tree of faces that
+    /// is grown breadth first. A face that would overlap faces already laid
+    /// out is attached along another of its edges. If that is not possible,
+    /// it starts a new piece, next to the others.
+    ///
+    /// Returns the faces laid out in the plane and the edges along which
+    /// they are attached.
+    fn unfold(&self, edge_faces: &EdgeFaces) -> (Vec<Polygon>, HashSet<Edge>) {
+        let edges = self.to_edges();
+        let gap = edges
+            .iter()
+            .map(|edge| edge_length(edge, &self.points))
+            .sum::<Float>()
+            / edges.len().max(1) as Float;
+        // Faces that are not planar do not match their neighbours exactly
+        // when laid out.
+        let tolerance = 1e-2 * gap;
+
+        let mut polygons: Vec<Option<Polygon>> = vec![None; self.face_index.len()];
+        let mut folds = HashSet::new();
+
+        for root in 0..self.face_index.len() {
+            if polygons[root].is_some() {
+                continue;
+            }
+
+            // Start a new piece to the right of what we have so far.
+            let right = polygons
+                .iter()
+                .flatten()
+                .flatten()
+                .map(|point| point[0])
+                .fold(Float::MIN, Float::max);
+            let mut polygon = self.lay_out_face(root, 0, [0.0, 0.0], [1.0, 0.0]);
+            if Float::MIN < right {
+                let left = polygon
+                    .iter()
+                    .map(|point| point[0])
+                    .fold(Float::MAX, Float::min);
+                polygon
+                    .iter_mut()
+                    .for_each(|point| point[0] += gap + right - left);
+            }
+            polygons[root] = Some(polygon);
+
+            let mut queue = VecDeque::from(vec![root]);
+            while let Some(index) = queue.pop_front() {
+                let face = &self.face_index[index];
+                for (i, (a, b)) in face.iter().circular_tuple_windows::<(_, _)>().enumerate() {
+                    let edge = distinct_edge(&[*a, *b]);
+                    for other in &edge_faces[&edge] {
+                        if polygons[*other].is_some() {
+                            continue;
+                        }
+                        // The other face runs along the edge the other way.
+                        let other_face = &self.face_index[*other];
+                        let start = match other_face.iter().position(|v| v == b) {
+                            Some(start) if other_face[(start + 1) % other_face.len()] == *a => {
+                                start
+                            }
+                            _ => continue,
+                        };
+                        let parent = polygons[index].as_ref().unwrap();
+                        let polygon = self.lay_out_face(
+                            *other,
+                            start,
+                            parent[(i + 1) % face.len()],
+                            parent[i],
+                        );
+                        if polygons
+                            .iter()
+                            .flatten()
+                            .all(|placed| !overlap(placed, &polygon, tolerance))
+                        {
+                            polygons[*other] = Some(polygon);
+                            folds.insert(edge);
+                            queue.push_back(*other);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Every face is in some piece.
+        (polygons.into_iter().flatten().collect(), folds)
+    }
+
+    /// Write an unfolded net of the polyhedron, for cutting out and folding
+    /// it from paper, to an SVG file at `path`.
+    ///
+    /// Faces are attached to each other along a spanning tree of faces. The
+    /// net can consist of several pieces, if a single one would overlap
+    /// itself. Cuts are drawn as solid lines, folds as dashed ones.
+    ///
+    /// If `tabs` is `true`, a glue tab is added to one side of every edge
+    /// that is cut.
+    ///
+    /// If the `palette` is not empty, each face is filled with the color of
+    /// the first face set it is in, cycling through the `palette`. Faces
+    /// that are in no face set are white.
+    pub fn write_net_svg(
+        &self,
+        path: &Path,
+        palette: &[[u8; 3]],
+        tabs: bool,
+    ) -> Result<(), Box<dyn Error>> {
+        let edge_faces = self.edge_faces();
+        let (polygons, folds) = self.unfold(&edge_faces);
+
+        let mut edges = Vec::new();
+        let mut fold_lines = Vec::new();
+        let mut glue_tabs = Vec::new();
+        let mut done = HashSet::new();
+
+        for (face, polygon) in self.face_index.iter().zip(&polygons) {
+            for ((a, b), (p, q)) in face
+                .iter()
+                .circular_tuple_windows::<(_, _)>()
+                .zip(polygon.iter().circular_tuple_windows::<(_, _)>())
+            {
+                let edge = distinct_edge(&[*a, *b]);
+                let line = [*p, *q];
+                if folds.contains(&edge) {
+                    // Both sides of a fold are in the same place.
+                    if done.insert(edge) {
+                        fold_lines.push(line);
+                    }
+                } else if tabs && 1 < edge_faces[&edge].len() && done.insert(edge) {
+                    let tab = tab(polygon, &line);
+                    edges.extend(tab.iter().tuple_windows().map(|(a, b)| [*a, *b]));
+                    glue_tabs.push(tab);
+                    fold_lines.push(line);
+                } else {
+                    edges.push(line);
+                }
+            }
+        }
+
+        let transform = fit(&polygons
+            .iter()
+            .chain(&glue_tabs)
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>());
+        let transform_polygon =
+            |polygon: &PolygonSlice| polygon.iter().map(&transform).collect::<Polygon>();
+        let transform_line = |line: &Line| [transform(&line[0]), transform(&line[1])];
+
+        let colors = if palette.is_empty() {
+            Vec::new()
+        } else {
+            self.face_set_colors(palette)
+        };
+        let filled = glue_tabs
+            .iter()
+            .map(|tab| (transform_polygon(tab), TAB_COLOR))
+            .chain(
+                polygons
+                    .iter()
+                    .zip(colors)
+                    .map(|(polygon, color)| (transform_polygon(polygon), color)),
+            )
+            .collect::<Vec<_>>();
+
+        write_svg(
+            path,
+            &self.name,
+            &filled,
+            &edges.iter().map(transform_line).collect::<Vec<_>>(),
+            &fold_lines.iter().map(transform_line).collect::<Vec<_>>(),
+        )
+    }
+}
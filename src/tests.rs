@@ -6,7 +6,7 @@ fn tetrahedron_to_terahedron() {
     let mut tetrahedron = Polyhedron::tetrahedron();
 
     //tetrahedron.dual();
-    tetrahedron.kis(Some(0.3), None, None, false);
+    tetrahedron.kis(Some(0.3), None, false);
 
     //let ctx = nsi::Context::new(&[nsi::string!("streamfilename",
     // "stdout")]).unwrap(); tetrahedron.to_nsi(ctx,
@@ -46,3 +46,2566 @@ fn make_pentagonal_prism() {
         .write_to_obj(&std::path::PathBuf::from("."), false)
         .unwrap();
 }
+
+#[test]
+fn cross_cube() {
+    let mut cube = Polyhedron::hexahedron();
+
+    cube.cross(Some(0.1), true);
+    assert_eq!(cube.faces().len(), 6 * 4 * 3);
+    assert_eq!(cube.points_len(), 8 + 6 * 5 + 12);
+    assert_eq!(cube.name(), "X0.10C");
+}
+
+#[test]
+fn perforate_dodecahedron() {
+    let mut dodecahedron = Polyhedron::dodecahedron();
+
+    dodecahedron.perforate(None, None, true);
+    assert_eq!(dodecahedron.faces().len(), 12 * 5);
+    assert!(dodecahedron.faces().iter().all(|face| face.len() == 4));
+    assert_eq!(
+        dodecahedron.face_set_index,
+        vec![(0..60).collect::<FaceSet>()]
+    );
+}
+
+#[test]
+fn perforate_face_sets() {
+    let mut cube = Polyhedron::cube();
+    let faces = cube.faces().clone();
+    cube.perforate(None, Some(FaceSelector::Indices(vec![0])), false);
+
+    // The other faces keep their set, the frame gets a new one.
+    assert_eq!(cube.faces()[..5], faces[1..]);
+    assert_eq!(
+        cube.face_set_index,
+        vec![(0..5).collect::<FaceSet>(), (5..9).collect()]
+    );
+}
+
+#[test]
+fn extrude_cube() {
+    let mut cube = Polyhedron::hexahedron();
+
+    cube.extrude(Some(0.5), Some(0.2), None, true);
+    assert_eq!(cube.faces().len(), 6 + 6 * 4);
+    assert_eq!(cube.points_len(), 8 + 6 * 4);
+    assert_eq!(cube.name(), "x0.50,0.20C");
+}
+
+#[test]
+fn subdivide_icosahedron() {
+    let mut icosahedron = Polyhedron::icosahedron();
+
+    icosahedron.subdivide(Some(3), true);
+    assert_eq!(icosahedron.faces().len(), 20 * 9);
+    // Euler characteristic of a closed genus-0 mesh.
+    assert_eq!(
+        icosahedron.points_len() + icosahedron.faces().len(),
+        icosahedron.to_edges().len() + 2
+    );
+    assert_eq!(icosahedron.name(), "u3I");
+}
+
+#[test]
+fn chamfer_dodecahedron_order_two() {
+    let mut dodecahedron = Polyhedron::dodecahedron();
+
+    // GC(3, 0): 12 pentagons and 80 hexagons.
+    dodecahedron.chamfer(None, Some(2), None, true);
+    assert_eq!(dodecahedron.faces().len(), 92);
+    assert_eq!(
+        dodecahedron
+            .faces()
+            .iter()
+            .filter(|face| face.len() == 5)
+            .count(),
+        12
+    );
+    assert_eq!(dodecahedron.name(), "c,2D");
+
+    // The ratio and face selector don't apply and stay out of the name.
+    let mut dodecahedron = Polyhedron::dodecahedron();
+    dodecahedron.chamfer(
+        Some(0.3),
+        Some(2),
+        Some(FaceSelector::Indices(vec![0])),
+        true,
+    );
+    assert_eq!(dodecahedron.faces().len(), 92);
+    assert_eq!(dodecahedron.name(), "c,2D");
+}
+
+#[test]
+fn chamfer_face_sets() {
+    let mut cube = Polyhedron::cube();
+    cube.chamfer(None, None, None, false);
+    // The shrunk faces stay in place, the edge hexagons get their own set.
+    assert_eq!(
+        cube.face_set_index,
+        vec![(0..6).collect::<FaceSet>(), (6..18).collect()]
+    );
+}
+
+#[test]
+fn goldberg_coxeter_open_mesh() {
+    let mut cube = Polyhedron::cube();
+    cube.remove_faces(FaceSelector::Indices(vec![0]));
+    let open = cube.clone();
+
+    cube.goldberg_coxeter(2, 1, false);
+    assert_eq!(cube.faces(), open.faces());
+    cube.chamfer(None, Some(2), None, false);
+    assert_eq!(cube.faces(), open.faces());
+}
+
+#[test]
+fn chamfer_icosahedron_order_two() {
+    let mut icosahedron = Polyhedron::icosahedron();
+    let radius = icosahedron
+        .points()
+        .iter()
+        .map(|p| p.mag())
+        .fold(0.0, f32::max);
+
+    icosahedron.chamfer(None, Some(2), None, false);
+
+    let mut goldberg_coxeter = Polyhedron::icosahedron();
+    goldberg_coxeter.goldberg_coxeter(3, 0, false);
+    assert_eq!(icosahedron.faces(), goldberg_coxeter.faces());
+
+    // Every edge is shared by exactly two faces.
+    let edges = icosahedron
+        .faces()
+        .iter()
+        .flat_map(|face| {
+            face.iter()
+                .zip(face.iter().cycle().skip(1))
+                .map(|(a, b)| (*a, *b))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let directed = edges.iter().collect::<std::collections::HashSet<_>>();
+    assert_eq!(directed.len(), edges.len());
+    assert!(edges.iter().all(|(a, b)| directed.contains(&(*b, *a))));
+
+    // Euler characteristic of a closed genus-0 mesh.
+    assert_eq!(
+        icosahedron.points_len() + icosahedron.faces().len(),
+        edges.len() / 2 + 2
+    );
+
+    let chamfered_radius = icosahedron
+        .points()
+        .iter()
+        .map(|p| p.mag())
+        .fold(0.0, f32::max);
+    assert!((radius - chamfered_radius).abs() < 1e-5);
+}
+
+#[test]
+fn gyro_cube_order_three() {
+    let mut cube = Polyhedron::hexahedron();
+
+    cube.gyro(None, None, Some(3), true);
+    // Per original face: four hexagons, four quads and four triangles.
+    assert_eq!(cube.faces().len(), 6 * 4 * 3);
+    assert_eq!(
+        cube.points_len() + cube.faces().len(),
+        cube.to_edges().len() + 2
+    );
+    assert_eq!(cube.name(), "g,,3C");
+}
+
+#[test]
+fn propeller_cube_order_two() {
+    let mut cube = Polyhedron::hexahedron();
+
+    cube.propeller(None, Some(2), true);
+    // Per original face: one central face, four blades and four ring quads.
+    assert_eq!(cube.faces().len(), 6 * 9);
+    assert_eq!(cube.name(), "p,2C");
+}
+
+#[test]
+fn goldberg_coxeter_dodecahedron() {
+    let mut dodecahedron = Polyhedron::dodecahedron();
+
+    // GC(2, 1): T = 7, 12 pentagons and 10 * (T - 1) hexagons.
+    dodecahedron.goldberg_coxeter(2, 1, true);
+    assert_eq!(dodecahedron.faces().len(), 72);
+    assert_eq!(
+        dodecahedron
+            .faces()
+            .iter()
+            .filter(|face| face.len() == 6)
+            .count(),
+        60
+    );
+    assert_eq!(dodecahedron.name(), "GC2,1D");
+
+    // With m - n a multiple of three, vertices of valence five become
+    // decagons. GC(1, 1) of the icosahedron is a truncated dodecahedron.
+    let mut icosahedron = Polyhedron::icosahedron();
+    icosahedron.goldberg_coxeter(1, 1, false);
+    assert_eq!(
+        icosahedron.face_arity_histogram(),
+        [(3, 20), (10, 12)].iter().cloned().collect()
+    );
+}
+
+#[test]
+fn whirl_orders() {
+    // The whirl keeps the valence of the original vertices, as does
+    // GC(n + 1, n), so the orders grow consistently.
+    let mut whirl = Polyhedron::icosahedron();
+    whirl.whirl(None, None, None, false);
+    let mut goldberg_coxeter = Polyhedron::icosahedron();
+    goldberg_coxeter.goldberg_coxeter(2, 1, false);
+    assert_eq!(whirl.points_len(), 12 + 4 * 30);
+    assert_eq!(goldberg_coxeter.points_len(), whirl.points_len());
+    assert_eq!(
+        goldberg_coxeter.face_arity_histogram(),
+        whirl.face_arity_histogram()
+    );
+
+    // T = 19: V = v + 2e(T - 1) / 3, F = f + e(T - 1) / 3.
+    let mut whirl = Polyhedron::icosahedron();
+    whirl.whirl(None, None, Some(2), true);
+    assert_eq!(whirl.points_len(), 12 + 12 * 30);
+    assert_eq!(
+        whirl.face_arity_histogram(),
+        [(3, 20), (6, 6 * 30)].iter().cloned().collect()
+    );
+    assert_eq!(whirl.name(), "w2I");
+}
+
+#[test]
+fn geodesic_icosahedron() {
+    let mut icosahedron = Polyhedron::icosahedron();
+    let radius = icosahedron.points()[0].mag();
+
+    icosahedron.geodesic(3, GeodesicClass::I, true);
+    assert_eq!(icosahedron.faces().len(), 20 * 9);
+    assert!(icosahedron
+        .points()
+        .iter()
+        .all(|point| (point.mag() - radius).abs() < 1e-5));
+    assert_eq!(icosahedron.name(), "geo3,0I");
+}
+
+#[test]
+fn icosphere() {
+    let icosphere = Polyhedron::icosphere(4);
+    assert_eq!(icosphere.faces().len(), 20 * 16);
+    assert_eq!(icosphere.points_len(), 10 * 16 + 2);
+    assert!(icosphere
+        .points()
+        .iter()
+        .all(|point| (point.mag() - 1.0).abs() < 1e-5));
+    assert_eq!(icosphere.name(), "geo4,0I");
+
+    assert_eq!(Polyhedron::icosphere(0).faces().len(), 20);
+}
+
+#[test]
+fn goldberg() {
+    // T = m² + mn + n² = 7: 12 pentagons and 10 * (T - 1) hexagons.
+    for (m, n) in [(2, 1), (1, 2)].iter() {
+        let goldberg = Polyhedron::goldberg(*m, *n);
+        assert_eq!(goldberg.faces().len(), 72);
+        assert_eq!(goldberg.face_set_index.len(), 2);
+        assert!(goldberg.face_set_index[0]
+            .iter()
+            .all(|face| goldberg.faces()[*face as usize].len() == 5));
+        assert_eq!(goldberg.face_set_index[0].len(), 12);
+        assert_eq!(goldberg.face_set_index[1].len(), 60);
+        assert_eq!(goldberg.name(), &format!("Rgeo{},{}I", m, n));
+        assert!(goldberg
+            .face_planarity()
+            .iter()
+            .all(|planarity| *planarity < 1e-5));
+    }
+
+    assert_eq!(Polyhedron::goldberg(0, 3).faces().len(), 92);
+    assert_eq!(Polyhedron::goldberg(0, 0).faces().len(), 12);
+}
+
+#[test]
+fn torus() {
+    let mut torus = Polyhedron::torus(12, 8, 1.0, 0.25);
+    assert_eq!(torus.points_len(), 96);
+    assert_eq!(torus.faces().len(), 96);
+    assert_eq!(torus.to_edges().len(), 192);
+    assert_eq!(torus.name(), "Tor12,8");
+
+    // Operators keep the Euler characteristic of zero.
+    torus.kis(None, None, false).dual(false).ambo(None, false);
+    assert_eq!(
+        torus.points_len() + torus.faces().len(),
+        torus.to_edges().len()
+    );
+}
+
+#[test]
+fn engine_primitives() {
+    let quad_sphere = Polyhedron::quad_sphere(3);
+    assert_eq!(quad_sphere.faces().len(), 6 * 9);
+    assert!(quad_sphere.faces().iter().all(|face| face.len() == 4));
+    assert!(quad_sphere
+        .points()
+        .iter()
+        .all(|point| (point.mag() - 1.0).abs() < 1e-5));
+
+    let uv_sphere = Polyhedron::uv_sphere(16, 8);
+    assert_eq!(uv_sphere.points_len(), 7 * 16 + 2);
+    assert_eq!(uv_sphere.faces().len(), 8 * 16);
+    assert_eq!(uv_sphere.face_set_index[0].len(), 2 * 16);
+    assert!(uv_sphere
+        .points()
+        .iter()
+        .all(|point| (point.mag() - 1.0).abs() < 1e-5));
+
+    let cylinder = Polyhedron::cylinder(16, 3, None);
+    assert_eq!(cylinder.points_len(), 4 * 16);
+    assert_eq!(cylinder.faces().len(), 3 * 16 + 2);
+
+    for polyhedron in [quad_sphere, uv_sphere, cylinder].iter() {
+        assert_eq!(
+            polyhedron.points_len() + polyhedron.faces().len(),
+            polyhedron.to_edges().len() + 2
+        );
+    }
+}
+
+#[test]
+fn waterman() {
+    let cuboctahedron = Polyhedron::waterman(1.0, 1);
+    assert_eq!(cuboctahedron.points_len(), 12);
+    assert_eq!(cuboctahedron.faces().len(), 14);
+    assert_eq!(cuboctahedron.name(), "Wat1");
+
+    for root in 2..12 {
+        let waterman = Polyhedron::waterman(2.0, root);
+        assert_eq!(
+            waterman.points_len() + waterman.faces().len(),
+            waterman.to_edges().len() + 2
+        );
+        assert!((max_magnitude(waterman.points()) - 2.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn zonohedron() {
+    let rhombic_dodecahedron = Polyhedron::zonohedron(&[
+        Vector::new(1.0, 1.0, 1.0),
+        Vector::new(1.0, 1.0, -1.0),
+        Vector::new(1.0, -1.0, 1.0),
+        Vector::new(-1.0, 1.0, 1.0),
+    ]);
+    assert_eq!(rhombic_dodecahedron.points_len(), 14);
+    assert_eq!(rhombic_dodecahedron.faces().len(), 12);
+    assert_eq!(rhombic_dodecahedron.name(), "Z4");
+
+    // The six axes of the icosahedron.
+    let icosahedron = Polyhedron::icosahedron();
+    let axes: Vec<Vector> = icosahedron
+        .points()
+        .iter()
+        .filter(|point| 0.0 < point.dot(Vector::new(0.1, 0.2, 0.3)))
+        .cloned()
+        .collect();
+    let rhombic_triacontahedron = Polyhedron::zonohedron(&axes);
+    assert_eq!(rhombic_triacontahedron.points_len(), 32);
+    assert_eq!(rhombic_triacontahedron.faces().len(), 30);
+    assert!(rhombic_triacontahedron
+        .faces()
+        .iter()
+        .all(|face| face.len() == 4));
+
+    assert!(
+        Polyhedron::zonohedron(&[Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0)])
+            .faces()
+            .is_empty()
+    );
+}
+
+#[test]
+fn spherical_voronoi() {
+    // The cells of the vertices of a cube are the faces of an octahedron.
+    let cube = Polyhedron::hexahedron();
+    let octahedron = Polyhedron::spherical_voronoi(cube.points(), 0);
+    assert_eq!(octahedron.points_len(), 6);
+    assert_eq!(octahedron.faces().len(), 8);
+    assert_eq!(octahedron.name(), "V8");
+
+    // Sites on the upper hemisphere only.
+    let sites: Vec<Point> = once(Point::new(0.0, 1.0, 0.0))
+        .chain(Polyhedron::prism(4).points()[..4].iter().cloned())
+        .collect();
+    let voronoi = Polyhedron::spherical_voronoi(&sites, 0);
+    assert_eq!(voronoi.points_len(), 5);
+    assert!(voronoi
+        .points()
+        .iter()
+        .any(|point| (*point - Point::new(0.0, -1.0, 0.0)).mag() < 1e-5));
+
+    let sites: Vec<Point> = (0..64)
+        .map(|i| {
+            let i = i as Float;
+            Point::new((i * 1.1).sin(), (i * 2.3).cos(), (i * 3.7).sin())
+        })
+        .collect();
+    let voronoi = Polyhedron::spherical_voronoi(&sites, 5);
+    assert_eq!(voronoi.faces().len(), 64);
+    assert_eq!(
+        voronoi.points_len() + voronoi.faces().len(),
+        voronoi.to_edges().len() + 2
+    );
+    assert!(voronoi
+        .points()
+        .iter()
+        .all(|point| (point.mag() - 1.0).abs() < 1e-5));
+
+    assert!(Polyhedron::spherical_voronoi(&sites[..3], 0)
+        .faces()
+        .is_empty());
+}
+
+#[test]
+fn from_points_hull() {
+    // A 3 × 3 × 3 grid. Only its corners are corners of the hull.
+    let points: Vec<Point> = (0..27)
+        .map(|i| Point::new((i % 3) as Float, (i / 3 % 3) as Float, (i / 9) as Float))
+        .collect();
+    let cube = Polyhedron::from_points_hull(&points);
+    assert_eq!(cube.points_len(), 8);
+    assert_eq!(cube.faces().len(), 6);
+    assert!(cube.faces().iter().all(|face| face.len() == 4));
+    // Points are not moved.
+    assert!(cube.points().iter().all(|point| points.contains(point)));
+
+    assert!(Polyhedron::from_points_hull(&points[..9])
+        .faces()
+        .is_empty());
+}
+
+#[test]
+fn from_schlafli() {
+    for (p, q, name) in [
+        (3, 3, "T"),
+        (4, 3, "C"),
+        (3, 4, "O"),
+        (5, 3, "D"),
+        (3, 5, "I"),
+    ]
+    .iter()
+    {
+        assert_eq!(Polyhedron::from_schlafli(*p, *q).unwrap().name(), name);
+    }
+    assert!(Polyhedron::from_schlafli(6, 3).is_err());
+
+    for (symbol, name) in [
+        ("{5, 3}", "D"),
+        ("t{2,5}", "P5"),
+        ("{7}×{}", "P7"),
+        ("{4}x{}", "P4"),
+        ("sr{2,5}", "A5"),
+        ("s{2,8}", "A4"),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            Polyhedron::from_schlafli_symbol(symbol).unwrap().name(),
+            name
+        );
+    }
+    for symbol in ["{4,4}", "s{2,5}", "t{3,3}", "{2}x{}", "{3,", "3,3"].iter() {
+        assert!(Polyhedron::from_schlafli_symbol(symbol).is_err());
+    }
+}
+
+#[test]
+fn wythoff() {
+    for (symbol, points, faces) in [
+        ("3 | 2 3", 4, 4),
+        ("3 | 2 4", 8, 6),
+        ("4 | 2 3", 6, 8),
+        ("3 | 2 5", 20, 12),
+        ("5 | 2 3", 12, 20),
+        ("2 3 | 3", 12, 8),
+        ("2 | 3 4", 12, 14),
+        ("2 3 | 4", 24, 14),
+        ("2 4 | 3", 24, 14),
+        ("3 4 | 2", 24, 26),
+        ("2 3 4 |", 48, 26),
+        ("| 2 3 4", 24, 38),
+        ("2 | 3 5", 30, 32),
+        ("2 3 | 5", 60, 32),
+        ("2 5 | 3", 60, 32),
+        ("3 5 | 2", 60, 62),
+        ("2 3 5 |", 120, 62),
+        ("| 2 3 5", 60, 92),
+        ("2 7 | 2", 14, 9),
+        ("| 2 2 7", 14, 16),
+        ("| 2 3 3", 12, 20),
+    ]
+    .iter()
+    {
+        let polyhedron = Polyhedron::wythoff(symbol).unwrap();
+        assert_eq!(polyhedron.points_len(), *points, "{}", symbol);
+        assert_eq!(polyhedron.faces().len(), *faces, "{}", symbol);
+
+        // All edges have the same length.
+        let lengths: Vec<Float> = polyhedron
+            .to_edges()
+            .iter()
+            .map(|edge| {
+                (polyhedron.points()[edge[0] as usize] - polyhedron.points()[edge[1] as usize])
+                    .mag()
+            })
+            .collect();
+        assert!(lengths
+            .iter()
+            .all(|length| (length - lengths[0]).abs() < 1e-4));
+    }
+    assert_eq!(Polyhedron::wythoff("2 3|4").unwrap().name(), "Wy2,3|4");
+
+    for symbol in [
+        "3 | 3 3",
+        "2 3 4",
+        "2 | 3 | 4",
+        "1 | 2 3",
+        "| 2 2 x",
+        "2 | 2 2",
+    ]
+    .iter()
+    {
+        assert!(Polyhedron::wythoff(symbol).is_err(), "{}", symbol);
+    }
+}
+
+#[test]
+fn exact_seed_coordinates() {
+    for polyhedron in [
+        Polyhedron::octahedron(),
+        Polyhedron::dodecahedron(),
+        Polyhedron::icosahedron(),
+        Polyhedron::prism(7),
+        Polyhedron::antiprism(9),
+        Polyhedron::snub_dodecahedron(),
+    ]
+    .iter()
+    {
+        let radius = polyhedron.points()[0].mag();
+        assert!(polyhedron
+            .points()
+            .iter()
+            .all(|point| (point.mag() - radius).abs() < 1e-6));
+    }
+}
+
+#[test]
+fn normalize_edge_length() {
+    for polyhedron in [
+        Polyhedron::tetrahedron(),
+        Polyhedron::hexahedron(),
+        Polyhedron::octahedron(),
+        Polyhedron::dodecahedron(),
+        Polyhedron::icosahedron(),
+        Polyhedron::prism(5),
+        Polyhedron::antiprism(7),
+    ]
+    .iter_mut()
+    {
+        polyhedron.normalize_edge_length();
+        assert!(polyhedron.to_edges().iter().all(|edge| {
+            ((polyhedron.points()[edge[0] as usize] - polyhedron.points()[edge[1] as usize]).mag()
+                - 1.0)
+                .abs()
+                < 1e-5
+        }));
+    }
+
+    assert!(Polyhedron::new()
+        .normalize_edge_length()
+        .points()
+        .is_empty());
+}
+
+#[test]
+fn triangle_mesh_buffers_by_face_set() {
+    let prism = Polyhedron::prism(5);
+
+    let (index, points, normals) = prism.to_triangle_mesh_buffers_by_face_set(NormalType::Flat);
+    assert_eq!(index.len(), 2);
+    assert_eq!(index[0].len(), 2 * 3 * 3);
+    assert_eq!(index[1].len(), 5 * 2 * 3);
+    assert_eq!(points.len(), 2 * 5 + 5 * 4);
+    assert_eq!(normals.len(), points.len());
+    // Flat normals point away from the center of the face.
+    points
+        .iter()
+        .zip(normals.iter())
+        .for_each(|(point, normal)| {
+            assert!(0.0 < point.dot(*normal));
+        });
+
+    let (index, points, normals) = prism.to_triangle_mesh_buffers_by_face_set(NormalType::Smooth);
+    assert_eq!(index.iter().map(|index| index.len()).sum::<usize>(), 48);
+    assert_eq!(&points, prism.points());
+    points
+        .iter()
+        .zip(normals.iter())
+        .for_each(|(point, normal)| {
+            assert!((normal.mag() - 1.0).abs() < 1e-6);
+            assert!(0.0 < point.dot(*normal));
+        });
+
+    // Faces in no face set get an index buffer of their own.
+    let cube = Polyhedron::hexahedron();
+    let cube = Polyhedron::from(
+        "C",
+        cube.points().clone(),
+        cube.faces().clone(),
+        Some(vec![vec![0, 1]]),
+    );
+    let (index, _, _) = cube.to_triangle_mesh_buffers_by_face_set(NormalType::Flat);
+    assert_eq!(index.len(), 2);
+    assert_eq!(index[1].len(), 4 * 2 * 3);
+}
+
+#[cfg(feature = "gltf")]
+#[test]
+fn write_to_gltf() {
+    let prism = Polyhedron::prism(5);
+    let path = prism
+        .write_to_gltf(&std::env::temp_dir(), NormalType::Flat)
+        .unwrap();
+    let glb = std::fs::read(path).unwrap();
+
+    let word = |offset: usize| {
+        u32::from_le_bytes([
+            glb[offset],
+            glb[offset + 1],
+            glb[offset + 2],
+            glb[offset + 3],
+        ])
+    };
+    assert_eq!(&glb[0..4], b"glTF");
+    assert_eq!(word(4), 2);
+    assert_eq!(word(8) as usize, glb.len());
+
+    let json_len = word(12) as usize;
+    assert_eq!(&glb[16..20], b"JSON");
+    let json = std::str::from_utf8(&glb[20..20 + json_len]).unwrap();
+    // One primitive per face set.
+    assert_eq!(json.matches("\"indices\"").count(), 2);
+    assert_eq!(&glb[24 + json_len..28 + json_len], b"BIN\0");
+    assert_eq!(word(20 + json_len) as usize, glb.len() - 28 - json_len);
+}
+
+#[cfg(feature = "3mf")]
+#[test]
+fn write_to_3mf() {
+    let prism = Polyhedron::prism(5);
+    let path = prism
+        .write_to_3mf(
+            &std::env::temp_dir(),
+            LengthUnit::Millimeter,
+            &[[255, 0, 0]],
+        )
+        .unwrap();
+    let archive = std::fs::read(path).unwrap();
+
+    assert_eq!(&archive[0..4], b"PK\x03\x04");
+    // The archive is uncompressed, so the model can be read directly.
+    let archive = String::from_utf8_lossy(&archive);
+    assert!(archive.contains(r#"<model unit="millimeter""#));
+    assert_eq!(archive.matches("<vertex ").count(), 10);
+    assert_eq!(archive.matches("<triangle ").count(), 2 * 3 + 5 * 2);
+    assert_eq!(archive.matches("<base ").count(), 2);
+}
+
+#[cfg(feature = "ply")]
+#[test]
+fn write_to_ply() {
+    let prism = Polyhedron::prism(3);
+    let path = prism
+        .write_to_ply(&std::env::temp_dir(), &[[255, 0, 0], [0, 0, 255]], false)
+        .unwrap();
+    let ply = std::fs::read_to_string(path).unwrap();
+    let lines: Vec<_> = ply.lines().collect();
+
+    assert_eq!(lines[0], "ply");
+    assert!(lines.contains(&"element vertex 6"));
+    assert!(lines.contains(&"element face 5"));
+    assert!(lines.contains(&"property list uint uint vertex_indices"));
+    assert!(lines.contains(&"property uchar red"));
+
+    let faces = &lines[lines.len() - 5..];
+    // Caps.
+    assert_eq!(faces[0], "3 0 1 2 255 0 0");
+    assert_eq!(faces[1], "3 5 4 3 255 0 0");
+    // Sides.
+    assert_eq!(faces[2], "4 0 3 4 1 0 0 255");
+}
+
+#[cfg(feature = "x3d")]
+#[test]
+fn write_to_x3d_and_vrml() {
+    let pyramid = Polyhedron::pyramid(4, None);
+    let palette = [[255, 0, 0], [0, 0, 255]];
+
+    let path = pyramid
+        .write_to_x3d(&std::env::temp_dir(), &palette)
+        .unwrap();
+    let x3d = std::fs::read_to_string(path).unwrap();
+    assert!(x3d.contains(r#"coordIndex="3 2 1 0 -1, 4 0 1 -1, "#));
+    assert!(x3d.contains(r#"<Color color="1 0 0, 0 0 1, 0 0 1, 0 0 1, 0 0 1"/>"#));
+
+    let path = pyramid
+        .write_to_vrml(&std::env::temp_dir(), &palette)
+        .unwrap();
+    let vrml = std::fs::read_to_string(path).unwrap();
+    assert!(vrml.starts_with("#VRML V2.0 utf8"));
+    assert!(vrml.contains("coordIndex [ 3 2 1 0 -1, 4 0 1 -1, "));
+    assert!(vrml.contains("color Color { color [ 1 0 0, 0 0 1, 0 0 1, 0 0 1, 0 0 1 ] }"));
+}
+
+#[cfg(feature = "scad")]
+#[test]
+fn write_to_scad() {
+    let tetrahedron = Polyhedron::tetrahedron();
+    let path = tetrahedron.write_to_scad(&std::env::temp_dir()).unwrap();
+    let scad = std::fs::read_to_string(path).unwrap();
+
+    assert!(scad.starts_with("// T\npolyhedron(\n"));
+    assert_eq!(scad.matches("        [").count(), 8);
+    assert!(scad.contains(&format!(
+        "        [{}],",
+        tetrahedron.faces()[0].iter().join(", ")
+    )));
+    assert!(scad.ends_with(");\n"));
+}
+
+#[test]
+fn to_dot() {
+    let tetrahedron = Polyhedron::tetrahedron();
+    assert_eq!(tetrahedron.to_edge_list().len(), 6);
+
+    let dot = tetrahedron.to_dot();
+    assert!(dot.starts_with("graph \"T\" {"));
+    assert_eq!(dot.matches(" -- ").count(), 6);
+    assert!(dot.contains("  3;"));
+}
+
+#[cfg(feature = "petgraph")]
+#[test]
+fn to_graph() {
+    let cube = Polyhedron::hexahedron();
+
+    let graph = cube.to_graph();
+    assert_eq!(graph.node_count(), 8);
+    assert_eq!(graph.edge_count(), 12);
+
+    // The dual of a cube is an octahedron.
+    let face_graph = cube.to_face_graph();
+    assert_eq!(face_graph.node_count(), 6);
+    assert_eq!(face_graph.edge_count(), 12);
+    assert!(face_graph
+        .node_indices()
+        .all(|face| 4 == face_graph.neighbors(face).count()));
+}
+
+#[cfg(feature = "bevy")]
+#[test]
+fn to_bevy_mesh() {
+    use bevy::render::mesh::{Indices, Mesh};
+
+    let cube = Polyhedron::cube();
+    let mesh = Mesh::from(&cube);
+
+    // Converting a reference leaves the polyhedron as it was.
+    assert_eq!(cube.faces(), Polyhedron::cube().faces());
+
+    // Four points and two triangles per face.
+    assert_eq!(mesh.count_vertices(), 6 * 4);
+    for attribute in [
+        Mesh::ATTRIBUTE_POSITION,
+        Mesh::ATTRIBUTE_NORMAL,
+        Mesh::ATTRIBUTE_UV_0,
+    ]
+    .iter()
+    {
+        assert_eq!(mesh.attribute(*attribute).unwrap().len(), 6 * 4);
+    }
+    match (mesh.indices(), Mesh::from(cube).indices()) {
+        (Some(Indices::U32(index)), Some(Indices::U32(owned_index))) => {
+            assert_eq!(index.len(), 6 * 2 * 3);
+            assert_eq!(index, owned_index);
+        }
+        _ => panic!("expected u32 indices"),
+    }
+}
+
+#[cfg(feature = "kiss3d")]
+#[test]
+fn to_kiss3d_mesh() {
+    use kiss3d::resource::Mesh;
+    use std::convert::TryFrom;
+
+    let cube = Polyhedron::cube();
+    let mesh = Mesh::try_from(&cube).unwrap();
+
+    let coords = mesh.coords().read().unwrap();
+    let coords = coords.data().as_ref().unwrap();
+    let faces = mesh.faces().read().unwrap();
+    let faces = faces.data().as_ref().unwrap();
+    let normals = mesh.normals().read().unwrap();
+    let normals = normals.data().as_ref().unwrap();
+
+    // Four points and two triangles per face.
+    assert_eq!(coords.len(), 6 * 4);
+    assert_eq!(normals.len(), 6 * 4);
+    assert_eq!(faces.len(), 6 * 2);
+
+    // Triangles are counter-clockwise around their normals.
+    for face in faces {
+        let [a, b, c] = [
+            coords[face.x as usize],
+            coords[face.y as usize],
+            coords[face.z as usize],
+        ];
+        assert!(0.0 < (b - a).cross(&(c - a)).dot(&normals[face.x as usize]));
+    }
+
+    // Each face gets its own points, which overflow the u16 indices.
+    assert!(Mesh::try_from(Polyhedron::prism(20_000)).is_err());
+}
+
+#[cfg(feature = "three-d")]
+#[test]
+fn to_three_d_mesh() {
+    let cube = Polyhedron::cube();
+    let mesh = three_d::CPUMesh::from(&cube);
+
+    assert_eq!(&mesh.name, cube.name());
+
+    // Four points and two triangles per face.
+    assert_eq!(mesh.positions.len(), 6 * 4 * 3);
+    let normals = mesh.normals.as_ref().unwrap();
+    assert_eq!(normals.len(), 6 * 4 * 3);
+    let index = match &mesh.indices {
+        Some(three_d::Indices::U32(index)) => index,
+        _ => panic!("expected u32 indices"),
+    };
+    assert_eq!(index.len(), 6 * 2 * 3);
+
+    // Triangles are counter-clockwise around their normals.
+    let point = |i: u32| {
+        let i = 3 * i as usize;
+        Point::new(
+            mesh.positions[i],
+            mesh.positions[i + 1],
+            mesh.positions[i + 2],
+        )
+    };
+    for triangle in index.chunks(3) {
+        let [a, b, c] = [point(triangle[0]), point(triangle[1]), point(triangle[2])];
+        let i = 3 * triangle[0] as usize;
+        let normal = Normal::new(normals[i], normals[i + 1], normals[i + 2]);
+        assert!(0.0 < (b - a).cross(c - a).dot(normal));
+    }
+}
+
+#[cfg(feature = "nsi")]
+#[test]
+fn to_nsi() {
+    let path = std::env::temp_dir().join("polyhedron-T.nsi");
+    // Creating a context needs the 3Delight library.
+    let ctx = match nsi::Context::new(&[
+        nsi::string!("type", "apistream"),
+        nsi::string!("streamfilename", path.to_str().unwrap()),
+    ]) {
+        Some(ctx) => ctx,
+        None => return,
+    };
+
+    // As many faces as points, so the per-face attributes can't be told
+    // apart from per-vertex ones by their count.
+    let tetrahedron = Polyhedron::tetrahedron();
+    assert_eq!(tetrahedron.faces().len(), tetrahedron.points_len());
+
+    let handle = tetrahedron.to_nsi(
+        &ctx,
+        "T",
+        &NsiOptions {
+            palette: vec![[255, 0, 0]],
+            transform: Some([
+                1., 0., 0., 0., 0., 1., 0., 0., 0., 0., 1., 0., 0., 0., 0., 1.,
+            ]),
+            ..Default::default()
+        },
+    );
+    assert_eq!(handle, "T-transform");
+    drop(ctx);
+
+    let stream = std::fs::read_to_string(path).unwrap();
+    assert!(stream.contains("\"faceset\""));
+    assert!(stream.contains("\"Cs\""));
+    assert!(stream.contains("\"subdivision.scheme\""));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let mut polyhedron = Polyhedron::dodecahedron();
+    polyhedron.kis(None, None, true);
+
+    let json = serde_json::to_string(&polyhedron).unwrap();
+    let deserialized: Polyhedron = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.name(), polyhedron.name());
+    assert_eq!(deserialized.faces(), polyhedron.faces());
+    assert_eq!(deserialized.points(), polyhedron.points());
+    assert_eq!(deserialized.face_set_index, polyhedron.face_set_index);
+}
+
+#[cfg(feature = "svg")]
+#[test]
+fn write_schlegel_svg() {
+    let prism = Polyhedron::prism(5);
+    let path = std::env::temp_dir().join("schlegel-P5.svg");
+    prism
+        .write_schlegel_svg(0, &path, &[[255, 0, 0], [0, 0, 255]])
+        .unwrap();
+    let svg = std::fs::read_to_string(&path).unwrap();
+
+    assert!(svg.starts_with("<svg "));
+    assert_eq!(svg.matches("<polygon ").count(), 7);
+    // The outer face is drawn first.
+    assert!(svg.contains(r##"fill="#ff0000"/>"##));
+    assert_eq!(svg.matches('M').count(), 15);
+
+    assert!(prism.write_schlegel_svg(7, &path, &[]).is_err());
+}
+
+#[cfg(feature = "svg")]
+#[test]
+fn write_net_svg() {
+    let path = std::env::temp_dir().join("net-C.svg");
+    Polyhedron::hexahedron()
+        .write_net_svg(&path, &[[255, 0, 0]], true)
+        .unwrap();
+    let svg = std::fs::read_to_string(&path).unwrap();
+
+    // Six faces and a tab on each of the seven cut edges.
+    assert_eq!(svg.matches("<polygon ").count(), 13);
+    assert_eq!(svg.matches(r##"fill="#e0e0e0""##).count(), 7);
+    assert!(svg.contains("stroke-dasharray"));
+
+    // Without a palette only the tabs are filled.
+    Polyhedron::hexahedron()
+        .write_net_svg(&path, &[], true)
+        .unwrap();
+    let svg = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(svg.matches("<polygon ").count(), 7);
+}
+
+#[cfg(feature = "threejs")]
+#[test]
+fn write_to_threejs() {
+    let prism = Polyhedron::prism(5);
+    let path = prism
+        .write_to_threejs(&std::env::temp_dir(), NormalType::Smooth)
+        .unwrap();
+    let json = std::fs::read_to_string(path).unwrap();
+
+    assert!(json.contains(r#""type":"BufferGeometry""#));
+    assert!(json.contains(r#""name":"P5""#));
+    assert!(json.contains(
+        r#""groups":[{"start":0,"count":18,"materialIndex":0},{"start":18,"count":30,"materialIndex":1}]"#
+    ));
+}
+
+#[cfg(feature = "usd")]
+#[test]
+fn write_to_usd() {
+    let mut cube = Polyhedron::hexahedron();
+    cube.ambo(None, true);
+    let path = cube
+        .write_to_usd(&std::env::temp_dir(), None, Some(5.0))
+        .unwrap();
+    let usd = std::fs::read_to_string(path).unwrap();
+
+    assert!(usd.starts_with("#usda 1.0"));
+    assert!(usd.contains("def Mesh \"aC\""));
+    assert!(usd.contains(&format!(
+        "int[] faceVertexCounts = [{}]",
+        cube.faces().iter().map(|face| face.len()).join(", ")
+    )));
+    assert!(usd.contains(&format!("int[] creaseLengths = [{}]", ["2"; 24].join(", "))));
+    assert!(usd.contains(&format!(
+        "float[] cornerSharpnesses = [{}]",
+        ["5"; 12].join(", ")
+    )));
+}
+
+#[cfg(feature = "off")]
+#[test]
+fn write_to_off() {
+    let mut prism = Polyhedron::prism(5);
+    prism.kis(None, None, false);
+    let path = prism
+        .write_to_off(&std::env::temp_dir(), &[[255, 0, 0], [0, 255, 0]], false)
+        .unwrap();
+
+    let off = Polyhedron::read_from_off(std::fs::File::open(path).unwrap(), false).unwrap();
+    assert_eq!(off.points(), prism.points());
+    assert_eq!(off.faces(), prism.faces());
+}
+
+#[cfg(feature = "off")]
+#[test]
+fn read_from_off() {
+    let off = b"OFF
+# A cube.
+8 6 12
+ 1.0  1.0  1.0
+ 1.0  1.0 -1.0
+ 1.0 -1.0  1.0
+ 1.0 -1.0 -1.0
+-1.0  1.0  1.0
+-1.0  1.0 -1.0
+-1.0 -1.0  1.0
+-1.0 -1.0 -1.0
+4 0 1 5 4 1.0 0.0 0.0
+4 0 4 6 2
+4 0 2 3 1
+4 7 3 2 6
+4 7 6 4 5
+4 7 5 1 3
+";
+    let cube = Polyhedron::read_from_off(&off[..], true).unwrap();
+    assert_eq!(cube.points_len(), 8);
+    assert_eq!(cube.faces(), Polyhedron::hexahedron().faces());
+
+    assert!(Polyhedron::read_from_off(
+        &b"OFF 4 1 0\n0 0 0\n1 0 0\n0 1 0\n0 0 1\n3 0 1 4\n"[..],
+        false
+    )
+    .is_err());
+    assert!(Polyhedron::read_from_off(&b"OFF\n4 1 0\n0 0 0\n"[..], false).is_err());
+}
+
+#[cfg(feature = "off")]
+#[test]
+fn write_chamfered_to_off() {
+    let mut cube = Polyhedron::cube();
+    cube.chamfer(None, None, None, false);
+    let path = cube
+        .write_to_off(&std::env::temp_dir(), &[[255, 0, 0], [0, 255, 0]], false)
+        .unwrap();
+
+    let off = Polyhedron::read_from_off(std::fs::File::open(path).unwrap(), false).unwrap();
+    assert_eq!(off.faces(), cube.faces());
+}
+
+#[test]
+fn chamfered_face_set_buffers() {
+    let mut cube = Polyhedron::cube();
+    cube.chamfer(None, None, None, false);
+
+    // The shrunk squares and the hexagons.
+    let (index, _, _) = cube.to_triangle_mesh_buffers_by_face_set(NormalType::Flat);
+    assert_eq!(index.len(), 2);
+    assert_eq!(index[0].len(), 6 * 2 * 3);
+    assert_eq!(index[1].len(), 12 * 4 * 3);
+
+    let (vertices, _) = cube.to_gpu_buffers(&GpuBufferOptions {
+        face_set_ids: true,
+        layout: VertexLayout::Planar,
+        ..Default::default()
+    });
+    let face_set_ids = &vertices[vertices.len() - (6 * 4 + 12 * 6)..];
+    assert_eq!(face_set_ids.iter().filter(|id| **id == 0.0).count(), 6 * 4);
+    assert_eq!(face_set_ids.iter().filter(|id| **id == 1.0).count(), 12 * 6);
+
+    // Face set entries that are out of range are ignored.
+    let mut cube = Polyhedron::cube();
+    cube.face_set_index.push(vec![6]);
+    let (index, _, _) = cube.to_triangle_mesh_buffers_by_face_set(NormalType::Smooth);
+    assert_eq!(index.len(), 2);
+    assert!(index[1].is_empty());
+}
+
+#[test]
+fn points_as_arrays() {
+    let cube = Polyhedron::hexahedron();
+    let arrays = cube.points_as_arrays();
+    assert_eq!(arrays.len(), 8);
+    assert_eq!(Point::from(arrays[3]), cube.points()[3]);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn points_as_glam() {
+    let dodecahedron = Polyhedron::dodecahedron();
+    let points = dodecahedron.points_as_glam();
+    assert_eq!(points.len(), dodecahedron.points().len());
+    for (point, expected) in points.iter().zip(dodecahedron.points()) {
+        assert_eq!(
+            [point.x, point.y, point.z],
+            [expected.x, expected.y, expected.z]
+        );
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn points_as_nalgebra() {
+    let dodecahedron = Polyhedron::dodecahedron();
+    let points = dodecahedron.points_as_nalgebra();
+    assert_eq!(points.len(), dodecahedron.points().len());
+    for (point, expected) in points.iter().zip(dodecahedron.points()) {
+        assert_eq!(
+            [point.x, point.y, point.z],
+            [expected.x, expected.y, expected.z]
+        );
+    }
+}
+
+#[test]
+fn to_gpu_buffers() {
+    let cube = Polyhedron::hexahedron();
+
+    let (vertices, index) = cube.to_gpu_buffers(&GpuBufferOptions::default());
+    assert_eq!(vertices.len(), 24 * 6);
+    assert_eq!(index.len(), 36);
+
+    let options = GpuBufferOptions {
+        uvs: true,
+        face_set_ids: true,
+        layout: VertexLayout::Planar,
+        ..Default::default()
+    };
+    let (planar, _) = cube.to_gpu_buffers(&options);
+    assert_eq!(planar.len(), 24 * 9);
+    assert_eq!(planar[..3], vertices[..3]);
+
+    let (_, clockwise) = cube.to_gpu_buffers(&GpuBufferOptions {
+        reverse_winding: false,
+        ..Default::default()
+    });
+    assert_eq!([clockwise[0], clockwise[2], clockwise[1]], index[..3]);
+
+    // Smooth normals share points.
+    let (smooth, _) = cube.to_gpu_buffers(&GpuBufferOptions {
+        normal_type: NormalType::Smooth,
+        ..Default::default()
+    });
+    assert_eq!(smooth.len(), 8 * 6);
+}
+
+#[cfg(feature = "meshopt")]
+#[test]
+fn to_optimized_gpu_buffers() {
+    let polyhedron = Polyhedron::dodecahedron().kis(None, None, true).finalize();
+
+    for layout in [VertexLayout::Interleaved, VertexLayout::Planar] {
+        let options = GpuBufferOptions {
+            normal_type: NormalType::Smooth,
+            uvs: true,
+            layout,
+            ..Default::default()
+        };
+        let (vertices, index) = polyhedron.to_gpu_buffers(&options);
+        let (optimized_vertices, optimized_index) =
+            polyhedron.to_optimized_gpu_buffers(&options, None);
+
+        assert_eq!(optimized_vertices.len(), vertices.len());
+        assert_eq!(optimized_index.len(), index.len());
+        // The vertices are numbered in the order of their first use.
+        assert_eq!(optimized_index[0], 0);
+        assert!(optimized_index
+            .iter()
+            .all(|v| (*v as usize) < vertices.len() / 8));
+
+        // The vertices are only reordered.
+        let sorted = |vertices: &[f32]| {
+            let mut vertices = vertices.to_vec();
+            vertices.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            vertices
+        };
+        assert_eq!(sorted(&optimized_vertices), sorted(&vertices));
+
+        let (quantized, _) = polyhedron.to_quantized_gpu_buffers(&options, None);
+        assert_eq!(quantized.len(), vertices.len());
+    }
+}
+
+#[cfg(feature = "tri-mesh")]
+#[test]
+fn tri_mesh() {
+    use std::convert::TryFrom;
+
+    let cube = Polyhedron::hexahedron();
+    let mesh = tri_mesh::prelude::Mesh::try_from(&cube).unwrap();
+    assert_eq!(mesh.no_vertices(), 8);
+    assert_eq!(mesh.no_faces(), 12);
+
+    let triangulated: Polyhedron = (&mesh).into();
+    assert_eq!(triangulated.points_len(), 8);
+    assert_eq!(triangulated.faces().len(), 12);
+}
+
+#[cfg(feature = "obj")]
+#[test]
+fn write_obj() {
+    let tetrahedron = Polyhedron::tetrahedron();
+
+    let mut obj = Vec::new();
+    tetrahedron.write_obj(&mut obj, false).unwrap();
+    assert!(obj.starts_with(b"o T\n"));
+
+    let read = Polyhedron::read_from_obj(&obj[..], None, false).unwrap();
+    assert_eq!(read.faces(), tetrahedron.faces());
+
+    let mut reversed = Vec::new();
+    tetrahedron.write_obj(&mut reversed, true).unwrap();
+    let read = Polyhedron::read_from_obj(&reversed[..], None, true).unwrap();
+    assert_eq!(read.faces(), tetrahedron.faces());
+}
+
+#[cfg(feature = "obj")]
+#[test]
+fn write_obj_with_face_sets() {
+    let prism = Polyhedron::prism(5);
+
+    let mut obj = Vec::new();
+    prism
+        .write_obj_with_face_sets(&mut obj, false, Some(NormalType::Flat), Some("P5.mtl"))
+        .unwrap();
+    let obj = String::from_utf8(obj).unwrap();
+    assert!(obj.starts_with("mtllib P5.mtl\n"));
+    assert_eq!(obj.matches("\nvn ").count(), 7);
+    assert_eq!(obj.matches("\ng ").count(), 2);
+    assert!(obj.contains("usemtl face_set_1\n"));
+    assert!(obj.contains(" 1//1 "));
+
+    let read = Polyhedron::read_from_obj(obj.as_bytes(), None, false).unwrap();
+    assert_eq!(read.faces(), prism.faces());
+
+    let mut mtl = Vec::new();
+    prism.write_mtl(&mut mtl, &[[255, 0, 0]]).unwrap();
+    let mtl = String::from_utf8(mtl).unwrap();
+    assert_eq!(mtl.matches("newmtl ").count(), 3);
+    assert!(mtl.contains("newmtl face_set_1\nKd 1 0 0\n"));
+}
+
+#[cfg(feature = "obj")]
+#[test]
+fn read_from_obj() {
+    // A cube with each face using its own points.
+    let obj = b"o cube
+v  1.0  1.0  1.0
+v  1.0  1.0 -1.0
+v -1.0  1.0 -1.0
+v -1.0  1.0  1.0
+vn 0.0 1.0 0.0
+f 1//1 2//1 3//1 4//1
+v  1.0 -1.0  1.0
+v -1.0 -1.0  1.0
+v -1.0 -1.0 -1.0
+v  1.0 -1.0 -1.0
+f -4 -3 -2 -1
+v  1.0  1.0  1.0
+v -1.0  1.0  1.0
+v -1.0 -1.0  1.0
+v  1.0 -1.0  1.0
+f 9/1 10/2 11/3 12/4
+v  1.0  1.0 -1.0
+v  1.0 -1.0 -1.0
+v -1.0 -1.0 -1.0
+v -1.0  1.0 -1.0
+f 13 14 15 16
+v  1.0  1.0  1.0
+v  1.0 -1.0  1.0
+v  1.0 -1.0 -1.0
+v  1.0  1.0 -1.0
+f 17 18 19 20
+v -1.0  1.0  1.0
+v -1.0  1.0 -1.0
+v -1.0 -1.0 -1.0
+v -1.0 -1.0  1.0
+f 21 22 23 24
+";
+    let cube = Polyhedron::read_from_obj(&obj[..], None, false).unwrap();
+    assert_eq!(cube.name(), "cube");
+    assert_eq!(cube.points_len(), 24);
+    assert_eq!(cube.faces().len(), 6);
+
+    let cube = Polyhedron::read_from_obj(&obj[..], Some(1e-4), false).unwrap();
+    assert_eq!(cube.points_len(), 8);
+    assert_eq!(cube.to_edges().len(), 12);
+
+    assert!(Polyhedron::read_from_obj(&b"v 0 0 0\nf 1 2 3\n"[..], None, false).is_err());
+}
+
+#[test]
+fn compound_with_dual_tetrahedron() {
+    let tetrahedron = Polyhedron::tetrahedron();
+
+    // Stella octangula.
+    let compound = tetrahedron.compound_with_dual();
+    assert_eq!(compound.points().len(), 8);
+    assert_eq!(compound.faces().len(), 8);
+    assert_eq!(
+        compound.face_set_index,
+        vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7]]
+    );
+    assert!(compound.faces()[4..]
+        .iter()
+        .all(|face| face.iter().all(|v| 4 <= *v)));
+}
+
+#[test]
+fn canonicalize_truncated_octahedron() {
+    let mut polyhedron = Polyhedron::octahedron();
+    polyhedron.truncate(None, None, false).canonicalize(300);
+
+    // All edges are tangent to the unit sphere.
+    assert!(polyhedron.to_edges().iter().all(|edge| {
+        let tangent_point = edge_tangent_point(
+            &polyhedron.points()[edge[0] as usize],
+            &polyhedron.points()[edge[1] as usize],
+        );
+        (tangent_point.mag() - 1.).abs() < 1e-3
+    }));
+}
+
+#[test]
+fn planarize_gyro_cube() {
+    let mut cube = Polyhedron::cube();
+    cube.gyro(None, Some(0.3), None, false)
+        .planarize(Some(1e-5), Some(1000));
+
+    assert!(cube.faces().iter().all(|face| {
+        let face_points = index_as_points(face, cube.points());
+        let normal = face_normal(&face_points).unwrap().normalized();
+        let centroid = centroid_ref(&face_points);
+        face_points
+            .iter()
+            .all(|point| normal.dot(centroid - **point).abs() < 1e-4)
+    }));
+}
+
+#[test]
+fn spherize_kis_cube() {
+    let mut cube = Polyhedron::cube();
+    cube.kis(Some(-0.2), None, false);
+    let radius = cube
+        .points()
+        .iter()
+        .map(|point| point.mag())
+        .fold(0., Float::max);
+
+    cube.spherize(Some(0.5), true);
+    assert!(cube
+        .points()
+        .iter()
+        .all(|point| point.mag() <= radius + 1e-5));
+
+    cube.spherize(None, true);
+    assert!(cube
+        .points()
+        .iter()
+        .all(|point| (point.mag() - radius).abs() < 1e-5));
+    assert_eq!(cube.name(), "SS0.50C");
+}
+
+#[test]
+fn relax_whirl_icosahedron() {
+    let mut icosahedron = Polyhedron::icosahedron();
+    icosahedron
+        .whirl(None, None, None, false)
+        .relax(10, None, true);
+
+    assert!(icosahedron
+        .points()
+        .iter()
+        .all(|point| (point.mag() - 1.).abs() < 1e-5));
+}
+
+#[test]
+fn catmull_clark_cube() {
+    let mut cube = Polyhedron::cube();
+    let bounds = cube.bounding_box();
+
+    // Fully creased: the cube keeps its shape.
+    cube.catmull_clark(2, Some(10.), true);
+    assert_eq!(cube.faces().len(), 6 * 16);
+    assert!(cube
+        .bounding_box()
+        .iter()
+        .zip(bounds.iter())
+        .all(|(a, b)| (a - b).abs() < 1e-6));
+    assert_eq!(cube.name(), "v2,10.00C");
+
+    // Smooth: the cube shrinks towards its limit surface.
+    let mut cube = Polyhedron::cube();
+    cube.catmull_clark(2, None, true);
+    assert!(cube.bounding_box()[3] < bounds[3]);
+    assert_eq!(cube.name(), "v2C");
+}
+
+#[test]
+fn truncate_cube() {
+    let mut cube = Polyhedron::cube();
+    cube.truncate(Some(1. / (2. + 2f32.sqrt())), None, true);
+
+    // Truncated cube: 6 octagons, 8 triangles, all edges of equal length.
+    assert_eq!(cube.points().len(), 24);
+    assert_eq!(cube.faces().len(), 14);
+    let lengths = cube
+        .to_edges()
+        .iter()
+        .map(|edge| edge_length(edge, cube.points()))
+        .collect::<Vec<_>>();
+    assert!(lengths
+        .iter()
+        .all(|length| (length - lengths[0]).abs() < 1e-5));
+    assert_eq!(cube.name(), "t0.29C");
+}
+
+#[test]
+fn join_cube() {
+    let mut cube = Polyhedron::cube();
+
+    // Rhombic dodecahedron.
+    let mut lifted = cube.clone();
+    cube.join(Some(1.), None, true);
+    assert_eq!(cube.points().len(), 14);
+    assert_eq!(cube.faces().len(), 12);
+    assert!(cube.faces().iter().all(|face| face.len() == 4));
+    assert!(cube
+        .face_planarity()
+        .iter()
+        .all(|planarity| *planarity < 1e-5));
+    assert_eq!(cube.face_set_index, vec![(0..12).collect::<FaceSet>()]);
+    assert_eq!(cube.name(), "j1.00C");
+
+    // The poles of the cube's faces are one unit above their centroids.
+    lifted.join(None, Some(1.), true);
+    assert!(lifted
+        .points()
+        .iter()
+        .zip(cube.points())
+        .all(|(a, b)| (*a - *b).mag() < 1e-5));
+    assert_eq!(lifted.name(), "j,1.00C");
+}
+
+#[test]
+fn join_prism_face_sets() {
+    let mut prism = Polyhedron::prism(4);
+    prism.join(None, None, false);
+
+    // Faces between a cap and a side, and between two sides.
+    assert_eq!(prism.face_set_index.len(), 2);
+    let mut lengths = prism
+        .face_set_index
+        .iter()
+        .map(|face_set| face_set.len())
+        .collect::<Vec<_>>();
+    lengths.sort_unstable();
+    assert_eq!(lengths, vec![4, 8]);
+}
+
+#[test]
+fn reciprocal_truncated_cube() {
+    let mut polyhedron = Polyhedron::cube();
+    polyhedron.truncate(None, None, false);
+    let points = polyhedron.points().clone();
+
+    // Reciprocating twice about the same sphere is the identity.
+    polyhedron
+        .reciprocal(Some(1.), false)
+        .reciprocal(Some(1.), true);
+    assert!(polyhedron
+        .points()
+        .iter()
+        .zip(points.iter())
+        .all(|(a, b)| (*a - *b).mag() < 1e-4));
+    assert_eq!(polyhedron.name(), "R1.00C");
+}
+
+#[test]
+fn reciprocal_face_through_center() {
+    // Both faces have their centroid at the origin.
+    let mut dihedron = Polyhedron::from(
+        "dihedron",
+        vec![
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(-0.5, 0.866_025_4, 0.0),
+            Point::new(-0.5, -0.866_025_4, 0.0),
+        ],
+        vec![vec![0, 1, 2], vec![2, 1, 0]],
+        None,
+    );
+    dihedron.reciprocal(None, false);
+    assert!(dihedron
+        .points()
+        .iter()
+        .all(|point| point.x.is_finite() && point.y.is_finite() && point.z.is_finite()));
+}
+
+#[test]
+fn kis_with_apex_cube() {
+    let mut cube = Polyhedron::cube();
+    cube.kis_with_apex(KisApex::Radial(1.), None, true);
+    assert_eq!(cube.points().len(), 14);
+    assert!(cube.points()[8..]
+        .iter()
+        .all(|point| (point.mag() - 2.).abs() < 1e-6));
+    assert_eq!(cube.name(), "k{r}1.00C");
+
+    let mut cube = Polyhedron::cube();
+    cube.kis_with_apex(KisApex::Normal(1.), None, true);
+    assert_eq!(cube.name(), "k1.00C");
+
+    let mut cube = Polyhedron::cube();
+    cube.kis_with_apex(
+        KisApex::Custom(Arc::new(|face, points| points[face[0] as usize] * 2.)),
+        None,
+        true,
+    );
+    assert!(cube.points()[8..]
+        .iter()
+        .all(|point| (point.mag() - 2. * 3f32.sqrt()).abs() < 1e-5));
+    assert_eq!(cube.name(), "k{..}C");
+}
+
+#[test]
+fn per_face_heights_cube() {
+    let mut cube = Polyhedron::cube();
+    cube.kis_with_apex(
+        KisApex::NormalPerFace(Arc::new(|index, _, _| index as Float)),
+        None,
+        false,
+    );
+    // The apex of the first face was not moved, the others were.
+    assert!((cube.points()[8].mag() - 1.).abs() < 1e-6);
+    assert!(cube.points()[9..].iter().all(|point| 1.5 < point.mag()));
+
+    let mut cube = Polyhedron::cube();
+    let mut reference = cube.clone();
+    cube.gyro_with_heights(&|_, _, _| 0.25, &|_, _, _| 0.5, None, true);
+    reference.gyro(Some(0.25), Some(0.5), None, false);
+    assert_eq!(cube.points(), reference.points());
+    assert_eq!(cube.name(), "g{n..},{n..}C");
+
+    // Only the edge points of the first face move along their edges.
+    let mut graded = Polyhedron::cube();
+    graded.gyro_with_heights(
+        &|index, _, _| if 0 == index { 0.5 } else { 0.25 },
+        &|_, _, _| 0.5,
+        None,
+        false,
+    );
+    let moved = graded
+        .points()
+        .iter()
+        .zip(reference.points())
+        .filter(|(a, b)| 1e-6 < (**a - **b).mag())
+        .count();
+    assert_eq!(moved, 4);
+
+    let mut cube = Polyhedron::cube();
+    let mut reference = cube.clone();
+    cube.whirl_with_heights(None, &|_, _, _| 0.5, true);
+    reference.whirl(None, Some(0.5), None, false);
+    assert_eq!(cube.points(), reference.points());
+    assert_eq!(cube.name(), "w,{n..}C");
+
+    let mut cube = Polyhedron::cube();
+    cube.gyro_with_heights(&|_, _, _| 0.25, &|_, _, _| 0.5, Some(2), true)
+        .whirl_with_heights(Some(0.2), &|_, _, _| 0.5, true);
+    assert_eq!(cube.name(), "w0.20,{n..}g{n..},{n..},2C");
+}
+
+#[test]
+fn truncate_and_needle_vertex_indices() {
+    let mut cube = Polyhedron::cube();
+    cube.truncate(None, Some(FaceSelector::Indices(vec![0])), false);
+    // One corner cut off.
+    assert_eq!(cube.points().len(), 10);
+    assert_eq!(cube.faces().len(), 7);
+
+    let mut cube = Polyhedron::cube();
+    cube.needle(None, Some(FaceSelector::Indices(vec![0, 7])), true);
+    // Dual octahedron with two faces split into three triangles each.
+    assert_eq!(cube.faces().len(), 12);
+    assert_eq!(cube.name(), "n,{i[0,7]}C");
+}
+
+#[test]
+fn vertex_selectors_match_dual() {
+    for notation in ["cC", "kC", "gT", "P5"] {
+        let polyhedron = Polyhedron::from_notation(notation).unwrap();
+        let mut dual = polyhedron.clone();
+        dual.dual(false);
+
+        for selector in [
+            FaceSelector::Arity(vec![3]),
+            FaceSelector::Regular,
+            FaceSelector::Indices(vec![0, 2, 100]),
+            FaceSelector::FaceSet(1),
+            FaceSelector::And(vec![
+                FaceSelector::FaceSet(0),
+                FaceSelector::Predicate(Arc::new(|_, _, points| 0. < points[0].x)),
+            ]),
+        ] {
+            assert_eq!(
+                polyhedron.selected_vertices(Some(&selector)),
+                dual.selected_faces(Some(&selector))
+            );
+        }
+    }
+
+    let mut cube = Polyhedron::cube();
+    cube.chamfer(None, None, None, false)
+        .truncate(None, Some(FaceSelector::Arity(vec![3])), false);
+    assert_eq!(cube.faces().len(), 18 + 32);
+
+    // The original corners only touch hexagons.
+    let mut cube = Polyhedron::cube();
+    cube.chamfer(None, None, None, false)
+        .truncate(None, Some(FaceSelector::FaceSet(0)), false);
+    assert_eq!(cube.faces().len(), 18 + 8);
+}
+
+#[test]
+fn face_selector_cube() {
+    let mut cube = Polyhedron::cube();
+    cube.kis(None, Some(FaceSelector::Indices(vec![0, 1])), true);
+    assert_eq!(cube.faces().len(), 4 + 2 * 4);
+    assert_eq!(cube.name(), "k,{i[0,1]}C");
+
+    let mut cube = Polyhedron::cube();
+    cube.extrude(None, None, Some(FaceSelector::FaceSet(0)), true);
+    assert_eq!(cube.name(), "x,,{f0}C");
+    // The cube's only face set covers all six faces.
+    assert_eq!(cube.faces().len(), 6 + 6 * 4);
+
+    let mut cube = Polyhedron::cube();
+    cube.perforate(
+        None,
+        Some(FaceSelector::And(vec![
+            FaceSelector::Arity(vec![4]),
+            FaceSelector::Predicate(Arc::new(|_, face, points| {
+                face.iter().all(|v| 0. < points[*v as usize].y)
+            })),
+        ])),
+        true,
+    );
+    // Only the top face was perforated.
+    assert_eq!(cube.faces().len(), 5 + 4);
+    assert_eq!(cube.name(), "f,4,{..}C");
+}
+
+#[test]
+fn lace_cube() {
+    // V = v + 2e, F = f + 4e.
+    let mut cube = Polyhedron::cube();
+    cube.lace(None, true);
+    assert_eq!(cube.points().len(), 8 + 24);
+    assert_eq!(cube.faces().len(), 6 + 48);
+    assert_eq!(cube.name(), "LC");
+
+    // F = f + 3e.
+    let mut cube = Polyhedron::cube();
+    cube.joined_lace(None, true);
+    assert_eq!(cube.faces().len(), 6 + 36);
+    assert_eq!(cube.name(), "L0C");
+
+    // F = f + 4e.
+    let mut cube = Polyhedron::cube();
+    cube.opposite_lace(Some(0.6), true);
+    assert_eq!(cube.faces().len(), 6 + 48);
+    assert_eq!(cube.name(), "L'0.60C");
+}
+
+#[test]
+fn bowtie_cube() {
+    // V = v + 3e, F = f + 4e.
+    let mut cube = Polyhedron::cube();
+    cube.bowtie(Some(0.4), true);
+    assert_eq!(cube.points().len(), 8 + 36);
+    assert_eq!(cube.faces().len(), 6 + 48);
+    assert_eq!(cube.name(), "B0.40C");
+}
+
+#[test]
+fn waffle_prism() {
+    // Only the two hexagons are divided into 1 + 2 * 6 faces each. The six
+    // side quads get two extra points on each of their two hexagon edges.
+    let mut prism = Polyhedron::prism(6);
+    prism.waffle(None, Some(FaceSelector::Arity(vec![6])), true);
+    assert_eq!(prism.faces().len(), 6 + 2 * 13);
+    assert_eq!(prism.points().len(), 12 + 2 * (12 + 6));
+    assert!(prism.faces().iter().filter(|face| face.len() == 8).count() == 6);
+    assert_eq!(prism.name(), "W,6P6");
+}
+
+#[test]
+fn contract_truncated_cube() {
+    let mut cube = Polyhedron::cube();
+    cube.truncate(None, None, false);
+    cube.contract(None, true);
+    assert_eq!(cube.points().len(), 8);
+    assert_eq!(cube.faces().len(), 6);
+    assert!(cube.faces().iter().all(|face| face.len() == 4));
+    assert_eq!(cube.name(), "ukC");
+
+    // The face sets follow the faces that are left.
+    let mut cube = Polyhedron::cube();
+    cube.face_set_index = vec![vec![0, 5], vec![1, 2, 3, 4]];
+    cube.contract(Some(FaceSelector::Indices(vec![0])), false);
+    assert_eq!(cube.faces().len(), 5);
+    assert_eq!(cube.face_set_index, vec![vec![4], vec![0, 1, 2, 3]]);
+}
+
+#[test]
+fn contract_undoes_kis() {
+    for seed in ["T", "C", "D", "P6"] {
+        let original = Polyhedron::from_notation(seed).unwrap();
+        let mut contracted = original.clone();
+        contracted.kis(None, None, false).contract(None, false);
+        assert_eq!(contracted.points(), original.points());
+        assert_eq!(
+            contracted.face_arity_histogram(),
+            original.face_arity_histogram()
+        );
+
+        // The result is a valid mesh.
+        contracted.dual(false);
+        assert_eq!(contracted.faces().len(), original.points().len());
+    }
+
+    // The faces around one vertex of the tetrahedron merge into the face
+    // opposite to it, leaving two faces.
+    let mut tetrahedron = Polyhedron::tetrahedron();
+    tetrahedron.contract(None, false);
+    assert_eq!(tetrahedron.points().len(), 3);
+    assert_eq!(tetrahedron.faces().len(), 2);
+    tetrahedron.dual(false);
+    assert_eq!(tetrahedron.faces().len(), 3);
+}
+
+#[test]
+fn ortho_two_heights() {
+    let mut cube = Polyhedron::cube();
+    cube.ortho(None, None, Some(0.1), Some(0.2), true);
+    assert_eq!(cube.faces().len(), 24);
+    assert_eq!(cube.name(), "o,,0.10,0.20C");
+
+    let mut other = Polyhedron::cube();
+    other.ortho(None, None, Some(0.1), None, false);
+    assert_ne!(cube.points(), other.points());
+
+    // The ratios of the two passes are independent.
+    let mut cube = Polyhedron::cube();
+    cube.ortho(Some(0.5), Some(1.0), None, None, true);
+    assert_eq!(cube.name(), "o0.50,1.00C");
+
+    let mut other = Polyhedron::cube();
+    other.ortho(Some(0.5), None, None, None, false);
+    assert_ne!(cube.points(), other.points());
+
+    let mut second = Polyhedron::cube();
+    second.ortho(None, Some(0.3), None, None, true);
+    assert_eq!(second.name(), "o,0.30C");
+}
+
+#[test]
+fn expand_two_ratios() {
+    let mut cube = Polyhedron::cube();
+    cube.expand(Some(0.3), Some(0.6), true);
+    assert_eq!(cube.faces().len(), 26);
+    assert_eq!(cube.name(), "e0.30,0.60C");
+
+    let mut other = Polyhedron::cube();
+    other.expand(Some(0.3), None, false);
+    assert_ne!(cube.points(), other.points());
+}
+
+#[test]
+fn quinto_ratio() {
+    let mut cube = Polyhedron::cube();
+    cube.quinto(Some(0.25), None, true);
+    assert_eq!(cube.faces().len(), 6 + 24);
+    assert_eq!(cube.name(), "q0.25C");
+
+    // The new edge vertices stay on the original edges, a quarter along
+    // the first face's winding.
+    let cube = Polyhedron::cube();
+    let mut quinto = cube.clone();
+    quinto.quinto(Some(0.25), None, false);
+    let a = cube.points()[cube.faces()[0][0] as usize];
+    let b = cube.points()[cube.faces()[0][1] as usize];
+    assert!(quinto.points()[8..]
+        .iter()
+        .any(|p| (*p - (0.75 * a + 0.25 * b)).mag() < 1e-6));
+
+    // Independent of how the points are numbered.
+    let points_len = cube.points_len() as VertexKey;
+    let mut renumbered = Polyhedron::from(
+        "renumbered",
+        cube.points().iter().rev().cloned().collect(),
+        cube.faces()
+            .iter()
+            .map(|face| face.iter().map(|v| points_len - 1 - v).collect())
+            .collect(),
+        None,
+    );
+    renumbered.quinto(Some(0.25), None, false);
+    assert_eq!(renumbered.points()[8..], quinto.points()[8..]);
+}
+
+#[test]
+fn chamfer_keeps_circumradius() {
+    let mut cube = Polyhedron::cube();
+    let radius = cube.points().iter().map(|p| p.mag()).fold(0.0, f32::max);
+    cube.chamfer(Some(0.3), None, None, false);
+    let chamfered_radius = cube.points().iter().map(|p| p.mag()).fold(0.0, f32::max);
+    assert!((radius - chamfered_radius).abs() < 1e-5);
+}
+
+#[test]
+fn chamfer_is_planar_and_convex() {
+    for seed in ["T", "C", "O", "D", "I"] {
+        for ratio in [0.1, 0.3, 0.5, 0.8] {
+            let mut chamfered = Polyhedron::from_notation(seed).unwrap();
+            chamfered.chamfer(Some(ratio), None, None, false);
+
+            assert!(chamfered
+                .face_planarity()
+                .iter()
+                .all(|planarity| *planarity < 1e-4));
+
+            // All points are on the same side of the plane of each face.
+            assert!(chamfered.faces().iter().all(|face| {
+                let a = chamfered.points()[face[0] as usize];
+                let b = chamfered.points()[face[1] as usize];
+                let c = chamfered.points()[face[2] as usize];
+                let normal = (b - a).cross(c - a).normalized();
+                let distances = chamfered
+                    .points()
+                    .iter()
+                    .map(|p| normal.dot(*p - a))
+                    .collect::<Vec<_>>();
+                distances.iter().all(|d| *d < 1e-4) || distances.iter().all(|d| -1e-4 < *d)
+            }));
+        }
+    }
+}
+
+#[test]
+fn chamfer_selected_faces() {
+    let mut cube = Polyhedron::cube();
+    let selector = FaceSelector::Indices(vec![0]);
+    cube.chamfer(None, None, Some(selector.clone()), true);
+    // One shrunk face and a quadrilateral for each of its edges.
+    assert_eq!(cube.points().len(), 8 + 4);
+    assert_eq!(cube.faces().len(), 6 + 4);
+    assert!(cube.faces()[6..].iter().all(|face| face.len() == 4));
+    assert_eq!(format!("{:?}", selector), "Indices([0])");
+}
+
+#[test]
+fn jitter_is_reproducible() {
+    let cube = Polyhedron::cube();
+
+    let mut a = cube.clone();
+    a.jitter(Some(0.2), Some(7), None, true);
+    let mut b = cube.clone();
+    b.jitter(Some(0.2), Some(7), None, false);
+    assert_eq!(a.points(), b.points());
+    assert_eq!(a.name(), "N0.20,7C");
+    assert_ne!(a.points(), cube.points());
+    assert!(a
+        .points()
+        .iter()
+        .zip(cube.points())
+        .all(|(a, b)| (*a - *b).mag() <= 0.2 + 1e-6));
+
+    // Radial jitter keeps points on rays through the origin.
+    let mut radial = cube.clone();
+    radial.jitter(Some(0.2), Some(7), Some(JitterDirection::Radial), true);
+    assert!(radial
+        .points()
+        .iter()
+        .zip(cube.points())
+        .all(|(a, b)| a.normalized().dot(b.normalized()) > 1.0 - 1e-5));
+    assert_eq!(radial.name(), "N0.20,7,rC");
+
+    // A point at the origin has no radial direction but is still moved.
+    let mut origin = Polyhedron::from("origin", vec![Point::zero()], Vec::new(), None);
+    origin.jitter(Some(0.2), Some(7), Some(JitterDirection::Tangential), false);
+    assert!(origin.points()[0].x.is_finite());
+    assert!(origin.points()[0].mag() <= 0.2 + 1e-6);
+}
+
+#[test]
+fn medial_cube() {
+    let mut medial = Polyhedron::cube();
+    medial.medial(None, None, None, true);
+    assert_eq!(medial.name(), "MC");
+
+    let mut composed = Polyhedron::cube();
+    composed
+        .kis(None, None, false)
+        .dual(false)
+        .ambo(None, false);
+    assert_eq!(medial.points(), composed.points());
+    assert_eq!(medial.faces(), composed.faces());
+}
+
+#[test]
+fn twist_cube() {
+    let mut cube = Polyhedron::cube();
+    cube.twist(None, None, Some(FaceSelector::Indices(vec![0])), true);
+    assert_eq!(cube.points().len(), 8 + 4);
+    assert_eq!(cube.faces().len(), 6 + 8);
+    assert_eq!(cube.name(), "tw,,{i0}C");
+}
+
+#[test]
+fn slice_cube() {
+    let mut cube = Polyhedron::cube();
+    cube.slice(Point::zero(), Vector::new(1.0, 1.0, 1.0), false);
+    // The four corners on one side and six cut points. The three faces
+    // around the corner furthest from the plane become pentagons, the
+    // other three triangles.
+    assert_eq!(cube.faces().len(), 3 + 3);
+    assert_eq!(cube.points().len(), 4 + 6);
+    assert_eq!(
+        cube.face_arity_histogram(),
+        [(3, 3), (5, 3)].iter().cloned().collect()
+    );
+
+    // Plus the hexagonal cap.
+    let mut cube = Polyhedron::cube();
+    cube.slice(Point::zero(), Vector::new(1.0, 1.0, 1.0), true);
+    assert_eq!(cube.faces().len(), 3 + 3 + 1);
+    assert!(cube.faces().iter().any(|face| face.len() == 6));
+}
+
+#[test]
+fn open_mesh_operators() {
+    let mut cube = Polyhedron::cube();
+    cube.remove_faces(FaceSelector::Indices(vec![0]));
+    assert_eq!(cube.faces().len(), 5);
+    assert_eq!(cube.points().len(), 8);
+    // 4 boundary edges are kept.
+    assert_eq!(cube.to_edges().len(), 12);
+
+    // Operators must not panic on boundary edges.
+    let mut ambo = cube.clone();
+    ambo.ambo(None, false);
+    assert_eq!(ambo.faces().len(), 5 + 8);
+
+    let mut chamfer = cube.clone();
+    chamfer.chamfer(None, None, None, false);
+    assert_eq!(chamfer.faces().len(), 5 + 12);
+
+    let mut truncate = cube.clone();
+    truncate.truncate(None, None, false);
+    assert_eq!(truncate.faces().len(), 5 + 8);
+}
+
+#[test]
+fn reflect_plane() {
+    let mut a = Polyhedron::dodecahedron();
+    a.gyro(None, None, None, false).reflect(true);
+    let mut b = Polyhedron::dodecahedron();
+    b.gyro(None, None, None, false)
+        .reflect_plane(Point::zero(), Vector::new(0.0, 2.0, 0.0), true);
+    assert_eq!(a.faces(), b.faces());
+    assert!(a
+        .points()
+        .iter()
+        .zip(b.points())
+        .all(|(a, b)| (*a - *b).mag() < 1e-6));
+    assert_eq!(b.name(), "rD");
+
+    // Mirroring across x = 1 moves the cube to x > 1.
+    let mut cube = Polyhedron::cube();
+    cube.reflect_plane(Point::new(1.0, 0.0, 0.0), Vector::unit_x(), false);
+    assert!(cube.points().iter().all(|p| 1.0 - 1e-6 < p.x));
+}
+
+#[test]
+fn minkowski_cube_octahedron() {
+    let cube = Polyhedron::cube();
+    let octahedron = Polyhedron::octahedron();
+    let minkowski = cube.minkowski(&octahedron);
+
+    // 8 cube corners times 3 octahedron directions pointing outwards.
+    assert_eq!(minkowski.points().len(), 24);
+    // A square for each cube face, a rectangle for each cube edge and a
+    // triangle for each cube corner.
+    assert_eq!(
+        minkowski.face_arity_histogram(),
+        [(3, 8), (4, 6 + 12)].iter().cloned().collect()
+    );
+    assert_eq!(minkowski.name(), "C⊕O");
+}
+
+#[test]
+fn csg_cubes() {
+    let cube = Polyhedron::cube();
+    let mut shifted = Polyhedron::cube();
+    shifted.reflect_plane(Point::new(0.25, 0.0, 0.0), Vector::unit_x(), false);
+
+    let intersection = cube.intersection(&shifted);
+    assert_eq!(intersection.points().len(), 8);
+    assert_eq!(intersection.faces().len(), 6);
+    assert_eq!(intersection.name(), "C∩C");
+
+    let difference = cube.difference(&shifted);
+    assert_eq!(difference.points().len(), 8);
+    assert_eq!(difference.faces().len(), 6);
+
+    // The union spans both cubes, from x = -1 to x = 1.5.
+    let union = cube.union(&shifted);
+    let min_x = union.points().iter().map(|p| p.x).fold(0.0, f32::min);
+    let max_x = union.points().iter().map(|p| p.x).fold(0.0, f32::max);
+    assert!((min_x + 1.0).abs() < 1e-5);
+    assert!((max_x - 1.5).abs() < 1e-5);
+}
+
+#[test]
+fn dual_keeps_face_sets() {
+    let mut cube = Polyhedron::cube();
+    cube.dual(false);
+    assert_eq!(cube.face_set_index, vec![(0..8).collect::<FaceSet>()]);
+
+    // The side quads of the extrusion are in their own face set. The
+    // vertices touching them become a new face set in the dual.
+    let mut cube = Polyhedron::cube();
+    cube.extrude(None, None, Some(FaceSelector::Indices(vec![0])), false);
+    cube.dual(false);
+    assert_eq!(cube.face_set_index.len(), 2);
+    assert_eq!(
+        cube.face_set_index[0].len() + cube.face_set_index[1].len(),
+        12
+    );
+    assert!(cube
+        .face_set_index
+        .iter()
+        .any(|face_set| face_set.len() == 8));
+}
+
+#[test]
+fn dual_after_chamfer() {
+    for seed in ["T", "C", "P5", "A4"] {
+        let notation = format!("dc{}", seed);
+        let dual = Polyhedron::from_notation(&notation).unwrap();
+        assert!(dual
+            .face_set_index
+            .iter()
+            .flatten()
+            .all(|face| (*face as usize) < dual.faces().len()));
+    }
+
+    // The original vertices touch the hexagons only, the new ones also
+    // touch a shrunk face.
+    let dual = Polyhedron::from_notation("dcC").unwrap();
+    assert_eq!(dual.face_set_index.len(), 2);
+
+    // Face set entries that are out of range are ignored.
+    let mut cube = Polyhedron::cube();
+    cube.face_set_index.push(vec![6]);
+    cube.dual(false);
+    assert_eq!(cube.face_set_index, vec![(0..8).collect::<FaceSet>()]);
+}
+
+#[test]
+fn merge_coplanar_faces() {
+    // Kis with zero height splits each face into coplanar triangles.
+    let mut cube = Polyhedron::cube();
+    cube.kis(Some(0.0), None, false);
+    assert_eq!(cube.faces().len(), 24);
+    cube.merge_coplanar_faces(None);
+    assert_eq!(cube.faces().len(), 6);
+    assert_eq!(cube.points().len(), 8);
+    assert!(cube.faces().iter().all(|face| face.len() == 4));
+}
+
+#[test]
+fn whirl_order_two() {
+    // GC(3, 2) has T = 3² + 3·2 + 2² = 19.
+    let mut dodecahedron = Polyhedron::dodecahedron();
+    dodecahedron.whirl(None, None, Some(2), true);
+    assert_eq!(dodecahedron.faces().len(), 10 * 19 + 2);
+    assert_eq!(dodecahedron.name(), "w2D");
+}
+
+#[test]
+fn whirl_face_sets() {
+    let mut cube = Polyhedron::cube();
+    cube.whirl(None, None, None, false);
+    // The center faces stay in place, the hexagons get their own set.
+    assert_eq!(
+        cube.face_set_index,
+        vec![(0..6).collect::<FaceSet>(), (6..30).collect()]
+    );
+    assert!(cube.faces()[..6].iter().all(|face| face.len() == 4));
+
+    let (index, _, _) = cube.to_triangle_mesh_buffers_by_face_set(NormalType::Flat);
+    assert_eq!(index.len(), 2);
+}
+
+#[test]
+fn prism_seed() {
+    let prism = Polyhedron::prism(5);
+    assert_eq!(prism.points().len(), 10);
+    assert_eq!(prism.faces().len(), 7);
+    assert!(prism.points().iter().all(|p| (p.mag() - 1.0).abs() < 1e-6));
+    assert_eq!(
+        prism.face_set_index,
+        vec![vec![0, 1], (2..7).collect::<FaceSet>()]
+    );
+
+    // Square sides.
+    let side = &prism.faces()[2];
+    let a = prism.points()[side[0] as usize];
+    let b = prism.points()[side[1] as usize];
+    let c = prism.points()[side[2] as usize];
+    assert!(((b - a).mag() - (c - b).mag()).abs() < 1e-6);
+}
+
+#[test]
+fn antiprism_seed() {
+    let antiprism = Polyhedron::antiprism(5);
+    assert_eq!(antiprism.points().len(), 10);
+    assert_eq!(antiprism.faces().len(), 2 + 10);
+    assert_eq!(antiprism.name(), "A5");
+    assert!(antiprism
+        .points()
+        .iter()
+        .all(|p| (p.mag() - 1.0).abs() < 1e-6));
+
+    // All edges have the same length.
+    let lengths: Vec<f32> = antiprism
+        .to_edges()
+        .iter()
+        .map(|edge| {
+            (antiprism.points()[edge[0] as usize] - antiprism.points()[edge[1] as usize]).mag()
+        })
+        .collect();
+    assert!(lengths.iter().all(|l| (l - lengths[0]).abs() < 1e-5));
+}
+
+#[test]
+fn pyramid_seed() {
+    let mut pyramid = Polyhedron::pyramid(4, None);
+    assert_eq!(pyramid.points().len(), 5);
+    assert_eq!(pyramid.faces().len(), 5);
+    assert_eq!(pyramid.name(), "Y4");
+
+    // The sides of the square pyramid are equilateral.
+    let lengths: Vec<f32> = pyramid
+        .to_edges()
+        .iter()
+        .map(|edge| (pyramid.points()[edge[0] as usize] - pyramid.points()[edge[1] as usize]).mag())
+        .collect();
+    assert!(lengths.iter().all(|l| (l - lengths[0]).abs() < 1e-5));
+
+    pyramid.kis(None, None, true);
+    assert_eq!(pyramid.name(), "kY4");
+}
+
+#[test]
+fn johnson_solids() {
+    assert!(Polyhedron::johnson(0).is_none());
+    assert!(Polyhedron::johnson(93).is_none());
+
+    for n in 1..=92 {
+        let johnson = Polyhedron::johnson(n).unwrap();
+        assert_eq!(johnson.name(), &format!("J{}", n));
+
+        let edges = johnson.to_edges();
+        assert_eq!(
+            johnson.points().len() + johnson.faces().len(),
+            edges.len() + 2
+        );
+        // All edges have unit length.
+        assert!(edges.iter().all(|edge| {
+            ((johnson.points()[edge[0] as usize] - johnson.points()[edge[1] as usize]).mag() - 1.0)
+                .abs()
+                < 1e-4
+        }));
+    }
+
+    // Triangular hebesphenorotunda.
+    let johnson = Polyhedron::johnson(92).unwrap();
+    assert_eq!(johnson.points().len(), 18);
+    assert_eq!(johnson.faces().len(), 20);
+}
+
+#[test]
+fn frustum() {
+    let frustum = Polyhedron::frustum(6, 0.5, 1.0, 2.0);
+    assert_eq!(frustum.name(), "Fru6");
+    assert_eq!(frustum.points_len(), 12);
+    assert_eq!(frustum.faces().len(), 8);
+    assert_eq!(frustum.to_edges().len(), 18);
+    assert_eq!(frustum.face_set_index[0], vec![0, 1]);
+
+    frustum.points()[..6].iter().for_each(|point| {
+        assert!((point.y - 1.0).abs() < 1e-6);
+        assert!((point.x.hypot(point.z) - 0.5).abs() < 1e-6);
+    });
+    frustum.points()[6..].iter().for_each(|point| {
+        assert!((point.y + 1.0).abs() < 1e-6);
+        assert!((point.x.hypot(point.z) - 1.0).abs() < 1e-6);
+    });
+
+    // A top radius of zero gives a pyramid.
+    let pyramid = Polyhedron::frustum(6, 0.0, 1.0, 2.0);
+    assert_eq!(pyramid.points_len(), 7);
+    assert_eq!(
+        pyramid.face_arity_histogram(),
+        [(3, 6), (6, 1)].iter().cloned().collect()
+    );
+    assert_eq!(pyramid.to_edges().len(), 12);
+    assert_eq!(pyramid.points()[6], Point::new(0.0, 1.0, 0.0));
+    assert!(0.0 < pyramid.volume());
+}
+
+#[test]
+fn bipyramid_and_trapezohedron() {
+    for n in 3..9 {
+        let bipyramid = Polyhedron::bipyramid(n);
+        assert_eq!(bipyramid.points_len(), n + 2);
+        assert_eq!(bipyramid.faces().len(), 2 * n);
+        assert_eq!(bipyramid.to_edges().len(), 3 * n);
+
+        let trapezohedron = Polyhedron::trapezohedron(n);
+        assert_eq!(trapezohedron.points_len(), 2 * n + 2);
+        assert_eq!(trapezohedron.faces().len(), 2 * n);
+        assert_eq!(trapezohedron.to_edges().len(), 4 * n);
+        // The kites are planar.
+        for face in trapezohedron.faces() {
+            let p: Vec<_> = face
+                .iter()
+                .map(|v| trapezohedron.points()[*v as usize])
+                .collect();
+            let normal = (p[1] - p[0]).cross(p[2] - p[0]).normalized();
+            assert!(normal.dot(p[3] - p[0]).abs() < 1e-5);
+        }
+    }
+
+    // The trigonal trapezohedron with these proportions is a cube.
+    let cube = Polyhedron::trapezohedron(3);
+    let edges = cube.to_edges();
+    edges.iter().for_each(|edge| {
+        let length = (cube.points()[edge[0] as usize] - cube.points()[edge[1] as usize]).mag();
+        assert!((length - 2.0 / 3.0f32.sqrt()).abs() < 1e-5);
+    });
+}
+
+#[test]
+fn from_name() {
+    for name in [
+        "T", "C", "O", "D", "I", "tI", "sD", "P5", "A7", "Y4", "dP5", "dA7", "J38",
+    ]
+    .iter()
+    {
+        assert_eq!(Polyhedron::from_name(name).unwrap().name(), name);
+    }
+
+    for name in ["", "X", "ti", "P2", "A", "J0", "J93", "A+7", "T3"].iter() {
+        assert!(Polyhedron::from_name(name).is_none());
+    }
+}
+
+#[test]
+fn from_notation() {
+    let polyhedron = Polyhedron::from_notation("gapcD").unwrap();
+    let mut expected = Polyhedron::dodecahedron();
+    expected
+        .chamfer(None, None, None, true)
+        .propeller(None, None, true)
+        .ambo(None, true)
+        .gyro(None, None, None, true);
+    assert_eq!(polyhedron.name(), "gapcD");
+    assert_eq!(polyhedron.faces(), expected.faces());
+
+    assert_eq!(Polyhedron::from_notation("dtI").unwrap().faces().len(), 60);
+    assert_eq!(Polyhedron::from_notation("kP5").unwrap().name(), "kP5");
+
+    for notation in ["", "c", "yD", "cX"].iter() {
+        assert!(Polyhedron::from_notation(notation).is_none());
+    }
+}
+
+#[test]
+fn archimedean_solids() {
+    [
+        (Polyhedron::truncated_tetrahedron(), 12, 8),
+        (Polyhedron::cuboctahedron(), 12, 14),
+        (Polyhedron::truncated_cube(), 24, 14),
+        (Polyhedron::truncated_octahedron(), 24, 14),
+        (Polyhedron::rhombicuboctahedron(), 24, 26),
+        (Polyhedron::truncated_cuboctahedron(), 48, 26),
+        (Polyhedron::snub_cube(), 24, 38),
+        (Polyhedron::icosidodecahedron(), 30, 32),
+        (Polyhedron::truncated_dodecahedron(), 60, 32),
+        (Polyhedron::truncated_icosahedron(), 60, 32),
+        (Polyhedron::rhombicosidodecahedron(), 60, 62),
+        (Polyhedron::truncated_icosidodecahedron(), 120, 62),
+        (Polyhedron::snub_dodecahedron(), 60, 92),
+    ]
+    .iter()
+    .for_each(|(polyhedron, points, faces)| {
+        assert_eq!(polyhedron.points().len(), *points);
+        assert_eq!(polyhedron.faces().len(), *faces);
+        // All edges have unit length.
+        assert!(polyhedron.to_edges().iter().all(|edge| {
+            ((polyhedron.points()[edge[0] as usize] - polyhedron.points()[edge[1] as usize]).mag()
+                - 1.0)
+                .abs()
+                < 1e-4
+        }));
+    });
+
+    assert_eq!(Polyhedron::truncated_icosahedron().name(), "tI");
+}
+
+#[test]
+fn volume() {
+    let mut cube = Polyhedron::hexahedron();
+    let [x_min, y_min, z_min, x_max, y_max, z_max] = cube.bounding_box();
+    let expected = ((x_max - x_min) * (y_max - y_min) * (z_max - z_min)) as Float;
+    assert!((cube.volume() - expected).abs() < 1e-5);
+
+    // Volume doesn't depend on the triangulation or where the origin is.
+    let mut moved = cube.clone();
+    moved
+        .points
+        .iter_mut()
+        .for_each(|point| *point += Vector::new(1.0, 2.0, 3.0));
+    moved.triangulate(None);
+    assert!((moved.volume() - expected).abs() < 1e-4);
+
+    cube.reverse();
+    assert!((cube.volume() + expected).abs() < 1e-5);
+}
+
+#[test]
+fn radii() {
+    let cube = Polyhedron::hexahedron();
+    let edge = cube.to_edges()[0];
+    let edge_length = (cube.points()[edge[0] as usize] - cube.points()[edge[1] as usize]).mag();
+    assert!((cube.inradius() - edge_length / 2.0).abs() < 1e-5);
+    assert!((cube.midradius() - edge_length / 2.0f32.sqrt()).abs() < 1e-5);
+    assert!((cube.circumradius() - edge_length * 3.0f32.sqrt() / 2.0).abs() < 1e-5);
+}
+
+#[test]
+fn face_planarity() {
+    let mut cube = Polyhedron::hexahedron();
+    assert!(cube
+        .face_planarity()
+        .iter()
+        .all(|planarity| *planarity < 1e-5));
+
+    // Lift one corner of the cube.
+    cube.points[0] *= 1.5;
+    let planarity = cube.face_planarity();
+    assert_eq!(
+        planarity
+            .iter()
+            .filter(|planarity| 1e-3 < **planarity)
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn histograms() {
+    let truncated_icosahedron = Polyhedron::truncated_icosahedron();
+    assert_eq!(
+        truncated_icosahedron.face_arity_histogram(),
+        [(5, 12), (6, 20)].iter().cloned().collect()
+    );
+    assert_eq!(
+        truncated_icosahedron.vertex_valence_histogram(),
+        [(3, 60)].iter().cloned().collect()
+    );
+
+    let goldberg = Polyhedron::from_notation("ctI").unwrap();
+    assert_eq!(goldberg.face_arity_histogram()[&5], 12);
+}
+
+#[test]
+fn edge_length_stats() {
+    let cube = Polyhedron::hexahedron();
+    let stats = cube.edge_length_stats().unwrap();
+    assert!((stats.min - stats.max).abs() < 1e-5);
+    assert!((stats.mean - stats.max).abs() < 1e-5);
+    assert!(stats.std_dev < 1e-5);
+    assert!(cube
+        .face_irregularity()
+        .iter()
+        .all(|irregularity| (irregularity - 1.0).abs() < 1e-5));
+
+    let mut stretched = Polyhedron::prism(4);
+    stretched.points.iter_mut().for_each(|point| point.y *= 2.0);
+    let stats = stretched.edge_length_stats().unwrap();
+    assert!(stats.min < stats.max);
+    assert!(0.0 < stats.std_dev);
+    assert!(stretched
+        .face_irregularity()
+        .iter()
+        .any(|irregularity| 1.5 < *irregularity));
+}
+
+#[test]
+fn identify() {
+    assert_eq!(Polyhedron::hexahedron().identify(), Some("cube"));
+    assert_eq!(
+        Polyhedron::snub_dodecahedron().identify(),
+        Some("snub dodecahedron")
+    );
+
+    let mut cuboctahedron = Polyhedron::hexahedron();
+    cuboctahedron.ambo(None, true);
+    assert_eq!(cuboctahedron.identify(), Some("cuboctahedron"));
+
+    let mut rhombic_dodecahedron = cuboctahedron.clone();
+    rhombic_dodecahedron.reciprocal(None, true);
+    assert_eq!(rhombic_dodecahedron.name(), "RaC");
+    assert_eq!(
+        rhombic_dodecahedron.identify(),
+        Some("rhombic dodecahedron")
+    );
+
+    // Right combinatorics, wrong shape.
+    let mut cuboid = Polyhedron::hexahedron();
+    cuboid.points.iter_mut().for_each(|point| point.y *= 2.0);
+    assert_eq!(cuboid.identify(), None);
+
+    assert_eq!(Polyhedron::prism(5).identify(), None);
+
+    // Right histograms, wrong topology.
+    assert_eq!(Polyhedron::johnson(34).unwrap().identify(), None);
+    assert_eq!(Polyhedron::johnson(37).unwrap().identify(), None);
+}
+
+#[test]
+fn topology_hash() {
+    let cube = Polyhedron::hexahedron();
+    let hash = cube.topology_hash();
+
+    // Renumbered and moved points.
+    let points_len = cube.points_len() as VertexKey;
+    let renumbered = Polyhedron::from(
+        "renumbered",
+        cube.points()
+            .iter()
+            .rev()
+            .map(|point| *point * 2.0 + Vector::unit_x())
+            .collect(),
+        cube.faces()
+            .iter()
+            .map(|face| face.iter().map(|v| points_len - 1 - v).collect())
+            .collect(),
+        None,
+    );
+    assert_eq!(renumbered.topology_hash(), hash);
+    assert_eq!(Polyhedron::prism(4).topology_hash(), hash);
+    assert_ne!(Polyhedron::octahedron().topology_hash(), hash);
+    assert_ne!(Polyhedron::prism(5).topology_hash(), hash);
+
+    // Mirror images.
+    let snub_cube = Polyhedron::snub_cube();
+    let mut mirrored = snub_cube.clone();
+    mirrored.reflect(true);
+    assert_eq!(mirrored.topology_hash(), snub_cube.topology_hash());
+}
+
+#[test]
+fn contains() {
+    let cube = Polyhedron::hexahedron();
+    assert!(cube.contains(&Point::zero()));
+    assert!(!cube.contains(&Point::new(2.0, 0.0, 0.0)));
+
+    // Non-convex: a point just above the middle of an edge of the
+    // icosahedron is between the spikes, one as far out above the middle
+    // of a face is inside a spike.
+    let icosahedron = Polyhedron::icosahedron();
+    let mut spiky = icosahedron.clone();
+    spiky.kis(Some(1.0), None, true);
+    assert!(spiky.contains(&Point::zero()));
+
+    let edge = icosahedron.to_edges()[0];
+    let middle =
+        (icosahedron.points()[edge[0] as usize] + icosahedron.points()[edge[1] as usize]) * 0.5;
+    assert!(!spiky.contains(&(middle * 1.05)));
+    let face = &icosahedron.faces()[0];
+    let center = face.iter().fold(Point::zero(), |sum, v| {
+        sum + icosahedron.points()[*v as usize]
+    });
+    assert!(spiky.contains(&(center.normalized() * middle.mag() * 1.05)));
+}
+
+#[test]
+fn ray_intersect() {
+    let cube = Polyhedron::hexahedron();
+    let [_, _, _, x_max, _, _] = cube.bounding_box();
+    let (distance, face) = cube
+        .ray_intersect(&Point::new(-5.0, 0.1, 0.2), &Vector::unit_x())
+        .unwrap();
+    assert!((distance - (5.0 - x_max as Float)).abs() < 1e-5);
+    assert!(cube.faces()[face]
+        .iter()
+        .all(|v| (cube.points()[*v as usize].x + x_max as Float).abs() < 1e-5));
+
+    assert!(cube
+        .ray_intersect(&Point::new(-5.0, 0.1, 0.2), &-Vector::unit_x())
+        .is_none());
+}
+
+#[test]
+fn sample() {
+    let cube = Polyhedron::hexahedron();
+    let [_, _, _, x_max, _, _] = cube.bounding_box();
+    let half_size = x_max as Float;
+
+    let surface = cube.sample_surface(100, Some(7));
+    assert_eq!(surface.len(), 100);
+    assert!(surface.iter().all(|point| {
+        (point.x.abs().max(point.y.abs()).max(point.z.abs()) - half_size).abs() < 1e-4
+    }));
+    assert_eq!(surface, cube.sample_surface(100, Some(7)));
+    assert_ne!(surface, cube.sample_surface(100, Some(8)));
+
+    let volume = cube.sample_volume(100, None);
+    assert_eq!(volume.len(), 100);
+    assert!(volume.iter().all(|point| cube.contains(point)));
+
+    // An open mesh with nothing inside.
+    let triangle = Polyhedron::from(
+        "triangle",
+        vec![Point::unit_x(), Point::unit_y(), Point::unit_z()],
+        vec![vec![0, 1, 2]],
+        None,
+    );
+    assert!(triangle.sample_volume(100, None).is_empty());
+}
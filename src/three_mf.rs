@@ -0,0 +1,225 @@
+//! Support for the [3MF](https://3mf.io/) file format used by slicers for
+//! 3D printing.
+//!
+//! A 3MF file is a ZIP archive. As the contents are small, the archive is
+//! written uncompressed.
+use crate::*;
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufWriter, Write as IoWrite},
+    path::{Path, PathBuf},
+};
+
+/// The unit of length the coordinates of a 3MF file are in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthUnit {
+    Micron,
+    Millimeter,
+    Centimeter,
+    Inch,
+    Foot,
+    Meter,
+}
+
+impl Display for LengthUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LengthUnit::Micron => "micron",
+            LengthUnit::Millimeter => "millimeter",
+            LengthUnit::Centimeter => "centimeter",
+            LengthUnit::Inch => "inch",
+            LengthUnit::Foot => "foot",
+            LengthUnit::Meter => "meter",
+        })
+    }
+}
+
+const CONTENT_TYPES: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+    r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+    r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+    r#"<Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>"#,
+    r#"</Types>"#
+);
+
+const RELATIONSHIPS: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+    r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+    r#"<Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>"#,
+    r#"</Relationships>"#
+);
+
+/// The CRC-32 checksum of `bytes`, as used by ZIP.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| match crc & 1 {
+            1 => (crc >> 1) ^ 0xedb8_8320,
+            _ => crc >> 1,
+        })
+    })
+}
+
+/// Writes `files`, given by name and contents, to an uncompressed ZIP
+/// archive.
+fn write_zip(writer: &mut impl IoWrite, files: &[(&str, &[u8])]) -> std::io::Result<()> {
+    // 1980-01-01, the earliest date ZIP can store.
+    const DATE: u16 = (1 << 5) | 1;
+
+    let mut offset = 0u32;
+    let mut central_directory = Vec::new();
+    for (name, contents) in files {
+        let crc = crc32(contents);
+        let size = contents.len() as u32;
+
+        // The fields shared by the local and the central header: version
+        // needed, flags, method, time, date, CRC and sizes.
+        let mut common = Vec::new();
+        common.extend_from_slice(&20u16.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+        common.extend_from_slice(&DATE.to_le_bytes());
+        common.extend_from_slice(&crc.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+
+        writer.write_all(&0x0403_4b50u32.to_le_bytes())?;
+        writer.write_all(&common)?;
+        writer.write_all(name.as_bytes())?;
+        writer.write_all(contents)?;
+
+        central_directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        // Version made by.
+        central_directory.extend_from_slice(&20u16.to_le_bytes());
+        central_directory.extend_from_slice(&common);
+        // Comment length, disk number, internal and external attributes.
+        central_directory.extend_from_slice(&[0; 10]);
+        central_directory.extend_from_slice(&offset.to_le_bytes());
+        central_directory.extend_from_slice(name.as_bytes());
+
+        offset += 30 + name.len() as u32 + size;
+    }
+
+    writer.write_all(&central_directory)?;
+    writer.write_all(&0x0605_4b50u32.to_le_bytes())?;
+    // Disk numbers.
+    writer.write_all(&[0; 4])?;
+    writer.write_all(&(files.len() as u16).to_le_bytes())?;
+    writer.write_all(&(files.len() as u16).to_le_bytes())?;
+    writer.write_all(&(central_directory.len() as u32).to_le_bytes())?;
+    writer.write_all(&offset.to_le_bytes())?;
+    // Comment length.
+    writer.write_all(&[0; 2])
+}
+
+impl Polyhedron {
+    /// Write the polyhedron to a [3MF](https://3mf.io/) file for 3D
+    /// printing.
+    ///
+    /// The [`name`](Polyhedron::name()) of the polyhedron is appended
+    /// to the given `destination` and postfixed with the extension
+    /// `.3mf`.
+    ///
+    /// The mesh is triangulated and keeps its shared points, so it stays
+    /// manifold. Coordinates are written as they are, in the given `unit`.
+    ///
+    /// If the `palette` is not empty, each face gets the color of the first
+    /// face set it is in, cycling through the `palette`. Faces that are in
+    /// no face set are white.
+    ///
+    /// The return value, on success, is the final, complete path of
+    /// the 3MF file.
+    pub fn write_to_3mf(
+        &self,
+        destination: &Path,
+        unit: LengthUnit,
+        palette: &[[u8; 3]],
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let (index, points, _) = self.to_triangle_mesh_buffers_by_face_set(NormalType::Smooth);
+
+        let mut model = String::new();
+        writeln!(model, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            model,
+            r#"<model unit="{}" xml:lang="en-US" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">"#,
+            unit
+        )?;
+        writeln!(
+            model,
+            r#"<metadata name="Title">{}</metadata>"#,
+            xml_escape(&self.name)
+        )?;
+        writeln!(model, "<resources>")?;
+
+        let colored = !palette.is_empty();
+        if colored {
+            writeln!(model, r#"<basematerials id="2">"#)?;
+            for set in 0..index.len() {
+                let color = match set < self.face_set_index.len() {
+                    true => palette[set % palette.len()],
+                    false => [255; 3],
+                };
+                writeln!(
+                    model,
+                    r##"<base name="{}" displaycolor="#{:02X}{:02X}{:02X}"/>"##,
+                    set, color[0], color[1], color[2]
+                )?;
+            }
+            writeln!(model, "</basematerials>")?;
+            writeln!(model, r#"<object id="1" type="model" pid="2" pindex="0">"#)?;
+        } else {
+            writeln!(model, r#"<object id="1" type="model">"#)?;
+        }
+
+        writeln!(model, "<mesh>")?;
+        writeln!(model, "<vertices>")?;
+        for point in &points {
+            writeln!(
+                model,
+                r#"<vertex x="{}" y="{}" z="{}"/>"#,
+                point.x, point.y, point.z
+            )?;
+        }
+        writeln!(model, "</vertices>")?;
+        writeln!(model, "<triangles>")?;
+        for (set, face_set_index) in index.iter().enumerate() {
+            // 3MF triangles are counter-clockwise seen from the outside.
+            for triangle in face_set_index.chunks(3) {
+                write!(
+                    model,
+                    r#"<triangle v1="{}" v2="{}" v3="{}""#,
+                    triangle[0], triangle[2], triangle[1]
+                )?;
+                if colored {
+                    write!(model, r#" pid="2" p1="{}""#, set)?;
+                }
+                writeln!(model, "/>")?;
+            }
+        }
+        writeln!(model, "</triangles>")?;
+        writeln!(model, "</mesh>")?;
+        writeln!(model, "</object>")?;
+        writeln!(model, "</resources>")?;
+        writeln!(model, r#"<build><item objectid="1"/></build>"#)?;
+        writeln!(model, "</model>")?;
+
+        let path = destination.join(format!("polyhedron-{}.3mf", self.name));
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        write_zip(
+            &mut file,
+            &[
+                ("[Content_Types].xml", CONTENT_TYPES.as_bytes()),
+                ("_rels/.rels", RELATIONSHIPS.as_bytes()),
+                ("3D/3dmodel.model", model.as_bytes()),
+            ],
+        )?;
+
+        file.flush()?;
+
+        Ok(path)
+    }
+}
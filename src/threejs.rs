@@ -0,0 +1,96 @@
+//! Support for the [Three.js](https://threejs.org/) JSON format of a
+//! `BufferGeometry`.
+use crate::*;
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufWriter, Write as IoWrite},
+    path::{Path, PathBuf},
+};
+
+impl Polyhedron {
+    /// Write the polyhedron to a [Three.js](https://threejs.org/) JSON file
+    /// that can be read with a `BufferGeometryLoader`.
+    ///
+    /// The [`name`](Polyhedron::name()) of the polyhedron is appended
+    /// to the given `destination` and postfixed with the extension
+    /// `.json`.
+    ///
+    /// The geometry is triangulated and has one group per face set, with
+    /// the index of the face set as its material index. See
+    /// [`to_triangle_mesh_buffers_by_face_set()`](Polyhedron::to_triangle_mesh_buffers_by_face_set())
+    /// for how faces are assigned to groups. The winding order is reversed
+    /// to match Three.js’ counter-clockwise front faces.
+    ///
+    /// The return value, on success, is the final, complete path of
+    /// the JSON file.
+    pub fn write_to_threejs(
+        &self,
+        destination: &Path,
+        normal_type: NormalType,
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let (index, points, normals) = self.to_triangle_mesh_buffers_by_face_set(normal_type);
+
+        let vectors = |vectors: &[Vector]| {
+            vectors
+                .iter()
+                .map(|v| format!("{},{},{}", v.x, v.y, v.z))
+                .join(",")
+        };
+
+        let mut start = 0;
+        let mut groups = Vec::new();
+        for (set, face_set_index) in index.iter().enumerate() {
+            if !face_set_index.is_empty() {
+                groups.push(format!(
+                    r#"{{"start":{},"count":{},"materialIndex":{}}}"#,
+                    start,
+                    face_set_index.len(),
+                    set
+                ));
+                start += face_set_index.len();
+            }
+        }
+
+        let path = destination.join(format!("polyhedron-{}.json", self.name));
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        writeln!(file, "{{")?;
+        writeln!(
+            file,
+            r#""metadata":{{"version":4.5,"type":"BufferGeometry","generator":"polyhedron-ops"}},"#
+        )?;
+        writeln!(file, r#""type":"BufferGeometry","#)?;
+        writeln!(file, r#""name":"{}","#, json_escape(&self.name))?;
+        writeln!(file, r#""data":{{"#)?;
+        writeln!(file, r#""attributes":{{"#)?;
+        writeln!(
+            file,
+            r#""position":{{"itemSize":3,"type":"Float32Array","normalized":false,"array":[{}]}},"#,
+            vectors(&points)
+        )?;
+        writeln!(
+            file,
+            r#""normal":{{"itemSize":3,"type":"Float32Array","normalized":false,"array":[{}]}}"#,
+            vectors(&normals)
+        )?;
+        writeln!(file, "}},")?;
+        writeln!(
+            file,
+            r#""index":{{"type":"Uint32Array","array":[{}]}},"#,
+            index
+                .iter()
+                .flatten()
+                .tuples::<(_, _, _)>()
+                .map(|(a, b, c)| format!("{},{},{}", a, c, b))
+                .join(",")
+        )?;
+        writeln!(file, r#""groups":[{}]"#, groups.join(","))?;
+        writeln!(file, "}}")?;
+        writeln!(file, "}}")?;
+
+        file.flush()?;
+
+        Ok(path)
+    }
+}
@@ -0,0 +1,103 @@
+//! A hash of the combinatorial structure of a polyhedron.
+use crate::*;
+use std::collections::VecDeque;
+
+/// Separates the faces in a traversal code.
+const FACE_END: usize = usize::MAX;
+
+/// A face and the position of a point in it.
+type Corner = (usize, usize);
+
+/// Traverses the faces breadth-first, starting with the face of the given
+/// corner and going around each face from the corner it was entered at.
+/// Points are labeled in the order they are first reached.
+///
+/// The resulting code only depends on the structure of the faces and the
+/// `start`, not on how the points are numbered.
+fn traversal_code(
+    faces: &FacesSlice,
+    corners: &HashMap<Edge, Corner>,
+    start: Corner,
+) -> Vec<usize> {
+    let mut labels = HashMap::new();
+    let mut visited = vec![false; faces.len()];
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    let mut code = Vec::new();
+    while let Some((face, position)) = queue.pop_front() {
+        if visited[face] {
+            continue;
+        }
+        visited[face] = true;
+
+        let face = &faces[face];
+        for i in 0..face.len() {
+            let a = face[(position + i) % face.len()];
+            let b = face[(position + i + 1) % face.len()];
+            let label = labels.len();
+            code.push(*labels.entry(a).or_insert(label));
+            // Continue with the neighbor across this edge.
+            if let Some(corner) = corners.get(&[b, a]) {
+                if !visited[corner.0] {
+                    queue.push_back(*corner);
+                }
+            }
+        }
+        code.push(FACE_END);
+    }
+    code
+}
+
+/// Returns the smallest traversal code over all starting corners.
+fn canonical_code(faces: &FacesSlice) -> Vec<usize> {
+    let corners: HashMap<Edge, Corner> = faces
+        .iter()
+        .enumerate()
+        .flat_map(|(index, face)| {
+            (0..face.len()).map(move |position| {
+                (
+                    [face[position], face[(position + 1) % face.len()]],
+                    (index, position),
+                )
+            })
+        })
+        .collect();
+
+    corners
+        .par_iter()
+        .map(|(_, corner)| traversal_code(faces, &corners, *corner))
+        .min()
+        .unwrap_or_default()
+}
+
+impl Polyhedron {
+    /// Returns a hash of the combinatorial structure of the polyhedron.
+    ///
+    /// Two polyhedra have the same hash if their faces are connected in the
+    /// same way, regardless of how their points are numbered, where they
+    /// are or whether one is the mirror image of the other. E.g. a cube and a
+    /// square [`prism()`](Polyhedron::prism()) have the same hash. Different
+    /// structures have different hashes, barring hash collisions.
+    ///
+    /// The hash is the [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)
+    /// hash of the smallest code of a breadth-first traversal of the faces,
+    /// over all starting corners and both winding orders. This takes time
+    /// quadratic in the number of edges. Only the faces connected to the
+    /// starting one are taken into account, so the polyhedron should be
+    /// connected.
+    pub fn topology_hash(&self) -> u64 {
+        let mirrored = self
+            .face_index
+            .iter()
+            .map(|face| face.iter().rev().cloned().collect())
+            .collect::<Faces>();
+        let code = canonical_code(&self.face_index).min(canonical_code(&mirrored));
+
+        code.iter()
+            .flat_map(|value| (*value as u64).to_le_bytes().to_vec())
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+}
@@ -0,0 +1,149 @@
+//! Support for the [USD](https://graphics.pixar.com/usd/) ASCII file format,
+//! USDA.
+use crate::*;
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufWriter, Write as IoWrite},
+    path::{Path, PathBuf},
+};
+
+/// Turns `name` into a valid USD prim name by replacing all characters
+/// other than ASCII letters, digits and underscores with underscores.
+fn prim_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c,
+            false => '_',
+        })
+        .collect();
+    match name.chars().next() {
+        Some(c) if !c.is_ascii_digit() => name,
+        _ => format!("_{}", name),
+    }
+}
+
+/// Formats `value`, repeated `n` times, as a comma separated list.
+fn repeated(value: impl Display, n: usize) -> String {
+    vec![value.to_string(); n].join(", ")
+}
+
+impl Polyhedron {
+    /// Write the polyhedron to a [USD](https://graphics.pixar.com/usd/)
+    /// ASCII file as a `UsdGeomMesh`.
+    ///
+    /// The mesh is set up like in [`to_nsi()`](Polyhedron::to_nsi()): as a
+    /// Catmull-Clark subdivision surface with creased edges and, optionally,
+    /// corners.
+    ///
+    /// The [`name`](Polyhedron::name()) of the polyhedron is appended
+    /// to the given `destination` and postfixed with the extension
+    /// `.usda`. The name of the mesh prim is the polyhedron’s name with
+    /// characters that are not allowed in prim names replaced by
+    /// underscores.
+    ///
+    /// The return value, on success, is the final, complete path of
+    /// the USDA file.
+    /// # Arguments
+    /// * `crease_hardness` - The hardness of edges (default: 10).
+    ///
+    /// * `corner_hardness` - The hardness of vertices (default: 0).
+    pub fn write_to_usd(
+        &self,
+        destination: &Path,
+        crease_hardness: Option<f32>,
+        corner_hardness: Option<f32>,
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let path = destination.join(format!("polyhedron-{}.usda", self.name));
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        let name = prim_name(&self.name);
+        writeln!(file, "#usda 1.0")?;
+        writeln!(file, "(")?;
+        writeln!(file, "    defaultPrim = \"{}\"", name)?;
+        writeln!(file, "    upAxis = \"Y\"")?;
+        writeln!(file, ")")?;
+        writeln!(file)?;
+        writeln!(file, "def Mesh \"{}\"", name)?;
+        writeln!(file, "{{")?;
+
+        let (min, max) = self.points.iter().fold(
+            (Point::broadcast(Float::MAX), Point::broadcast(Float::MIN)),
+            |(min, max), point| (min.min_by_component(*point), max.max_by_component(*point)),
+        );
+        writeln!(
+            file,
+            "    float3[] extent = [({}, {}, {}), ({}, {}, {})]",
+            min.x, min.y, min.z, max.x, max.y, max.z
+        )?;
+        writeln!(
+            file,
+            "    point3f[] points = [{}]",
+            self.points
+                .iter()
+                .map(|point| format!("({}, {}, {})", point.x, point.y, point.z))
+                .join(", ")
+        )?;
+        writeln!(
+            file,
+            "    int[] faceVertexCounts = [{}]",
+            self.face_index.iter().map(|face| face.len()).join(", ")
+        )?;
+        writeln!(
+            file,
+            "    int[] faceVertexIndices = [{}]",
+            self.face_index.iter().flatten().join(", ")
+        )?;
+        // Render this as a C-C subdivison surface.
+        writeln!(
+            file,
+            "    uniform token subdivisionScheme = \"catmullClark\""
+        )?;
+        // This saves us from having to reverse the mesh ourselves.
+        writeln!(file, "    uniform token orientation = \"leftHanded\"")?;
+
+        // Default: semi sharp creases.
+        let crease_hardness = crease_hardness.unwrap_or(10.);
+
+        if 0.0 != crease_hardness {
+            let edges = self.to_edges();
+            writeln!(
+                file,
+                "    int[] creaseIndices = [{}]",
+                edges.iter().flatten().join(", ")
+            )?;
+            writeln!(
+                file,
+                "    int[] creaseLengths = [{}]",
+                repeated(2, edges.len())
+            )?;
+            writeln!(
+                file,
+                "    float[] creaseSharpnesses = [{}]",
+                repeated(crease_hardness, edges.len())
+            )?;
+        }
+
+        if let Some(hardness) = corner_hardness {
+            if 0.0 < hardness {
+                writeln!(
+                    file,
+                    "    int[] cornerIndices = [{}]",
+                    (0..self.points.len()).join(", ")
+                )?;
+                writeln!(
+                    file,
+                    "    float[] cornerSharpnesses = [{}]",
+                    repeated(hardness, self.points.len())
+                )?;
+            }
+        }
+
+        writeln!(file, "}}")?;
+
+        file.flush()?;
+
+        Ok(path)
+    }
+}
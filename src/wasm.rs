@@ -0,0 +1,113 @@
+//! [WebAssembly](https://webassembly.org/) bindings via
+//! [`wasm-bindgen`](https://rustwasm.github.io/wasm-bindgen/).
+//!
+//! Build with the `parallel` feature disabled when targeting
+//! `wasm32-unknown-unknown`. The crate does not declare the `cdylib` crate
+//! type in its manifest, so pass it to `cargo rustc` and run the
+//! [`wasm-bindgen`](https://rustwasm.github.io/wasm-bindgen/reference/cli.html)
+//! CLI on the result:
+//!
+//! ```shell
+//! cargo rustc --lib --release --target wasm32-unknown-unknown \
+//!     --crate-type cdylib --no-default-features --features wasm
+//! wasm-bindgen --target bundler --out-dir pkg \
+//!     target/wasm32-unknown-unknown/release/polyhedron_ops.wasm
+//! ```
+//!
+//! ```javascript
+//! import { Polyhedron } from "polyhedron-ops";
+//!
+//! const polyhedron = new Polyhedron("gapcD");
+//! const buffers = polyhedron.gpuBuffers(false, false);
+//! // Upload buffers.vertices and buffers.indices.
+//! ```
+use crate::*;
+use wasm_bindgen::prelude::*;
+
+/// A polyhedron, created from its Conway notation.
+#[wasm_bindgen(js_name = Polyhedron)]
+pub struct WasmPolyhedron(Polyhedron);
+
+#[wasm_bindgen(js_class = Polyhedron)]
+impl WasmPolyhedron {
+    /// Parses the notation. See
+    /// [`from_notation()`](Polyhedron::from_notation()).
+    #[wasm_bindgen(constructor)]
+    pub fn new(notation: &str) -> Result<WasmPolyhedron, JsValue> {
+        Polyhedron::from_notation(notation)
+            .map(WasmPolyhedron)
+            .ok_or_else(|| JsValue::from_str(&format!("invalid notation {}", notation)))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.0.name().clone()
+    }
+
+    /// The points as a flat `Float32Array`.
+    #[wasm_bindgen(getter)]
+    pub fn points(&self) -> Vec<f32> {
+        self.0
+            .points_as_arrays()
+            .iter()
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    #[wasm_bindgen(getter, js_name = faceCount)]
+    pub fn face_count(&self) -> usize {
+        self.0.faces().len()
+    }
+
+    /// Returns interleaved, counter-clockwise buffers. See
+    /// [`to_gpu_buffers()`](Polyhedron::to_gpu_buffers()).
+    #[wasm_bindgen(js_name = gpuBuffers)]
+    pub fn gpu_buffers(&self, smooth: bool, uvs: bool) -> GpuBuffers {
+        let options = GpuBufferOptions {
+            normal_type: match smooth {
+                true => NormalType::Smooth,
+                false => NormalType::Flat,
+            },
+            uvs,
+            ..Default::default()
+        };
+        let (vertices, indices) = self.0.to_gpu_buffers(&options);
+
+        GpuBuffers {
+            vertices,
+            indices,
+            vertex_size: options.vertex_size(),
+        }
+    }
+}
+
+/// A vertex and an index buffer, ready to be uploaded to WebGL or WebGPU.
+#[wasm_bindgen]
+pub struct GpuBuffers {
+    vertices: Vec<f32>,
+    indices: Vec<u32>,
+    vertex_size: usize,
+}
+
+#[wasm_bindgen]
+impl GpuBuffers {
+    /// The vertices as a `Float32Array`: a position, a normal and,
+    /// optionally, texture coordinates each.
+    #[wasm_bindgen(getter)]
+    pub fn vertices(&self) -> Vec<f32> {
+        self.vertices.clone()
+    }
+
+    /// The triangle indices as a `Uint32Array`.
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Vec<u32> {
+        self.indices.clone()
+    }
+
+    /// The number of floats per vertex.
+    #[wasm_bindgen(getter, js_name = vertexSize)]
+    pub fn vertex_size(&self) -> usize {
+        self.vertex_size
+    }
+}
@@ -0,0 +1,217 @@
+//! The [Wythoff construction](https://en.wikipedia.org/wiki/Wythoff_construction)
+//! of the convex uniform polyhedra.
+//!
+//! The three mirrors bounding a spherical Schwarz triangle *(p q r)* generate
+//! a finite reflection group. The position of the bar in the Wythoff symbol
+//! determines where in the triangle the generator point is placed. The points
+//! of the polyhedron are the orbit of this point under the group – or, for
+//! snubs, under its rotation subgroup – and the faces are their convex hull.
+//!
+//! The computation is done in `f64` and rounded once at the end.
+use crate::*;
+use ultraviolet::DVec3;
+
+/// Tolerance for telling points of an orbit apart.
+const TOLERANCE: f64 = 1e-6;
+
+fn reflect(point: DVec3, normal: DVec3) -> DVec3 {
+    point - 2.0 * point.dot(normal) * normal
+}
+
+/// Returns all distinct points reachable from `point` by applying
+/// `generators`.
+fn orbit(point: DVec3, generators: &[&dyn Fn(DVec3) -> DVec3]) -> Vec<DVec3> {
+    let mut points = vec![point];
+    let mut index = 0;
+    while index < points.len() {
+        let point = points[index];
+        generators.iter().for_each(|generator| {
+            let image = generator(point);
+            if points
+                .iter()
+                .all(|other| TOLERANCE < (*other - image).mag())
+            {
+                points.push(image);
+            }
+        });
+        index += 1;
+    }
+    points
+}
+
+/// Returns the generator of a snub, the point on the unit sphere that has
+/// the same distance to its images under the three `rotations`.
+///
+/// Uses Newton's method in the barycentric coordinates of the triangle
+/// `corners`, starting at its center.
+fn snub_generator(corners: &[DVec3; 3], rotations: &[&dyn Fn(DVec3) -> DVec3; 3]) -> DVec3 {
+    let point = |uv: [f64; 2]| {
+        (uv[0] * corners[0] + uv[1] * corners[1] + (1.0 - uv[0] - uv[1]) * corners[2]).normalized()
+    };
+    let residuals = |uv: [f64; 2]| {
+        let x = point(uv);
+        let d: Vec<f64> = rotations
+            .iter()
+            .map(|rotation| (rotation(x) - x).mag())
+            .collect();
+        [d[0] - d[1], d[1] - d[2]]
+    };
+
+    let mut uv = [1.0 / 3.0, 1.0 / 3.0];
+    let h = 1e-7;
+    for _ in 0..64 {
+        let r = residuals(uv);
+        if r[0].abs() + r[1].abs() < 1e-14 {
+            break;
+        }
+        let ru = residuals([uv[0] + h, uv[1]]);
+        let rv = residuals([uv[0], uv[1] + h]);
+        let jacobian = [
+            [(ru[0] - r[0]) / h, (rv[0] - r[0]) / h],
+            [(ru[1] - r[1]) / h, (rv[1] - r[1]) / h],
+        ];
+        let determinant = jacobian[0][0] * jacobian[1][1] - jacobian[0][1] * jacobian[1][0];
+        if determinant.abs() < 1e-30 {
+            break;
+        }
+        uv = [
+            uv[0] - (jacobian[1][1] * r[0] - jacobian[0][1] * r[1]) / determinant,
+            uv[1] - (jacobian[0][0] * r[1] - jacobian[1][0] * r[0]) / determinant,
+        ];
+    }
+
+    point(uv)
+}
+
+impl Polyhedron {
+    /// Creates the convex uniform polyhedron with the given [Wythoff
+    /// symbol](https://en.wikipedia.org/wiki/Wythoff_symbol), with a
+    /// circumradius of one.
+    ///
+    /// The symbol is three integers of at least two, *p*, *q* and *r*, and a
+    /// bar, e.g. `"3 | 2 4"` (cube), `"2 3 | 4"` (truncated cube),
+    /// `"2 3 4 |"` (truncated cuboctahedron) or `"| 2 3 5"` (snub
+    /// dodecahedron). Prisms are `"2 n | 2"` and antiprisms `"| 2 2 n"`.
+    ///
+    /// The faces are put into face sets by their number of vertices.
+    ///
+    /// Returns an error if the symbol can't be parsed, describes a
+    /// nonconvex or non-spherical tiling or yields a degenerate polyhedron.
+    pub fn wythoff(symbol: &str) -> Result<Self, String> {
+        let error = |reason: &str| format!("{}: {}", reason, symbol);
+
+        let tokens: Vec<String> = symbol
+            .replace('|', " | ")
+            .split_whitespace()
+            .map(String::from)
+            .collect();
+        let bar = match tokens
+            .iter()
+            .positions(|token| "|" == token.as_str())
+            .collect::<Vec<_>>()[..]
+        {
+            [bar] if 4 == tokens.len() => bar,
+            _ => return Err(error("expected three numbers and one bar")),
+        };
+        let numbers = tokens
+            .iter()
+            .filter(|token| "|" != token.as_str())
+            .map(|token| token.parse::<usize>().ok().filter(|n| 2 <= *n))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| error("expected integers of at least two"))?;
+
+        // The dihedral angles between the mirrors, by the triangle corner
+        // they meet at.
+        let angle = |n: usize| -(std::f64::consts::PI / n as f64).cos();
+        let (p, q, r) = (angle(numbers[0]), angle(numbers[1]), angle(numbers[2]));
+
+        // The normals of the mirrors opposite the corners P, Q and R, from
+        // the Cholesky decomposition of their Gram matrix.
+        let normal_p = DVec3::new(1.0, 0.0, 0.0);
+        let normal_q = DVec3::new(r, (1.0 - r * r).sqrt(), 0.0);
+        let y = (p - q * r) / normal_q.y;
+        let z_sq = 1.0 - q * q - y * y;
+        if z_sq < TOLERANCE {
+            return Err(error("the Schwarz triangle is not spherical"));
+        }
+        let normal_r = DVec3::new(q, y, z_sq.sqrt());
+        let normals = [normal_p, normal_q, normal_r];
+
+        // Each corner lies on the two mirrors meeting there.
+        let corner = |a: DVec3, b: DVec3, opposite: DVec3| {
+            let corner = a.cross(b).normalized();
+            if corner.dot(opposite) < 0.0 {
+                -corner
+            } else {
+                corner
+            }
+        };
+        let corners = [
+            corner(normal_q, normal_r, normal_p),
+            corner(normal_r, normal_p, normal_q),
+            corner(normal_p, normal_q, normal_r),
+        ];
+
+        // The point with the given distances to the three mirrors.
+        let with_distances = |distances: [f64; 3]| {
+            (0..3)
+                .fold(DVec3::zero(), |point, i| {
+                    point + distances[i] / corners[i].dot(normals[i]) * corners[i]
+                })
+                .normalized()
+        };
+
+        let reflections: Vec<Box<dyn Fn(DVec3) -> DVec3>> = normals
+            .iter()
+            .map(|normal| {
+                let normal = *normal;
+                Box::new(move |point| reflect(point, normal)) as Box<dyn Fn(DVec3) -> DVec3>
+            })
+            .collect();
+        // The rotations around each corner by twice the angle there.
+        let rotations: Vec<Box<dyn Fn(DVec3) -> DVec3>> = (0..3)
+            .map(|i| {
+                let (a, b) = (normals[(i + 1) % 3], normals[(i + 2) % 3]);
+                Box::new(move |point| reflect(reflect(point, a), b)) as Box<dyn Fn(DVec3) -> DVec3>
+            })
+            .collect();
+
+        let points = match bar {
+            0 => {
+                let rotations = [&*rotations[0], &*rotations[1], &*rotations[2]];
+                let generator = snub_generator(&corners, &rotations);
+                orbit(generator, &rotations)
+            }
+            _ => {
+                let generator = with_distances(match bar {
+                    1 => [1.0, 0.0, 0.0],
+                    2 => [1.0, 1.0, 0.0],
+                    _ => [1.0, 1.0, 1.0],
+                });
+                orbit(
+                    generator,
+                    &[&*reflections[0], &*reflections[1], &*reflections[2]],
+                )
+            }
+        };
+
+        let name = format!(
+            "Wy{}|{}",
+            numbers[..bar].iter().join(","),
+            numbers[bar..].iter().join(",")
+        );
+
+        let polyhedron = Self::from_hull(
+            &name,
+            points
+                .iter()
+                .map(|point| rounded_point(point.x, point.y, point.z))
+                .collect(),
+        );
+        if polyhedron.face_index.is_empty() {
+            Err(error("the polyhedron is degenerate"))
+        } else {
+            Ok(polyhedron)
+        }
+    }
+}
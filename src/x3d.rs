@@ -0,0 +1,146 @@
+//! Support for the [X3D](https://www.web3d.org/x3d/what-x3d) file format and
+//! its predecessor, [VRML](https://en.wikipedia.org/wiki/VRML)97.
+use crate::*;
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufWriter, Write as IoWrite},
+    path::{Path, PathBuf},
+};
+
+impl Polyhedron {
+    /// Returns the points, the face index with faces terminated by `-1` and
+    /// the per-face colors of an `IndexedFaceSet`.
+    fn indexed_face_set(&self, palette: &[[u8; 3]]) -> (String, String, Option<String>) {
+        let points = self
+            .points
+            .iter()
+            .map(|point| format!("{} {} {}", point.x, point.y, point.z))
+            .join(", ");
+        let face_index = self
+            .face_index
+            .iter()
+            .map(|face| {
+                face.iter()
+                    .map(|v| v.to_string())
+                    .chain(once("-1".to_string()))
+                    .join(" ")
+            })
+            .join(", ");
+        let colors = match palette.is_empty() {
+            true => None,
+            false => Some(
+                self.face_set_colors(palette)
+                    .iter()
+                    .map(|color| {
+                        color
+                            .iter()
+                            .map(|channel| *channel as f32 / 255.0)
+                            .join(" ")
+                    })
+                    .join(", "),
+            ),
+        };
+        (points, face_index, colors)
+    }
+
+    /// Write the polyhedron to an [X3D](https://www.web3d.org/x3d/what-x3d)
+    /// file as an `IndexedFaceSet`.
+    ///
+    /// The [`name`](Polyhedron::name()) of the polyhedron is appended
+    /// to the given `destination` and postfixed with the extension
+    /// `.x3d`.
+    ///
+    /// If the `palette` is not empty, each face gets the color of the first
+    /// face set it is in, cycling through the `palette`. Faces that are in
+    /// no face set are white.
+    ///
+    /// The return value, on success, is the final, complete path of
+    /// the X3D file.
+    pub fn write_to_x3d(
+        &self,
+        destination: &Path,
+        palette: &[[u8; 3]],
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let path = destination.join(format!("polyhedron-{}.x3d", self.name));
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        let (points, face_index, colors) = self.indexed_face_set(palette);
+
+        writeln!(file, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(file, r#"<X3D profile="Interchange" version="3.3">"#)?;
+        writeln!(file, "<head>")?;
+        writeln!(
+            file,
+            r#"<meta name="title" content="{}"/>"#,
+            xml_escape(&self.name)
+        )?;
+        writeln!(file, "</head>")?;
+        writeln!(file, "<Scene>")?;
+        writeln!(file, "<Shape>")?;
+        writeln!(file, "<Appearance><Material/></Appearance>")?;
+        // Our faces are clockwise.
+        writeln!(
+            file,
+            r#"<IndexedFaceSet ccw="false" convex="false" colorPerVertex="false" coordIndex="{}">"#,
+            face_index
+        )?;
+        writeln!(file, r#"<Coordinate point="{}"/>"#, points)?;
+        if let Some(colors) = colors {
+            writeln!(file, r#"<Color color="{}"/>"#, colors)?;
+        }
+        writeln!(file, "</IndexedFaceSet>")?;
+        writeln!(file, "</Shape>")?;
+        writeln!(file, "</Scene>")?;
+        writeln!(file, "</X3D>")?;
+
+        file.flush()?;
+
+        Ok(path)
+    }
+
+    /// Write the polyhedron to a [VRML](https://en.wikipedia.org/wiki/VRML)97
+    /// file as an `IndexedFaceSet`.
+    ///
+    /// The [`name`](Polyhedron::name()) of the polyhedron is appended
+    /// to the given `destination` and postfixed with the extension
+    /// `.wrl`.
+    ///
+    /// If the `palette` is not empty, each face gets the color of the first
+    /// face set it is in, cycling through the `palette`. Faces that are in
+    /// no face set are white.
+    ///
+    /// The return value, on success, is the final, complete path of
+    /// the VRML file.
+    pub fn write_to_vrml(
+        &self,
+        destination: &Path,
+        palette: &[[u8; 3]],
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let path = destination.join(format!("polyhedron-{}.wrl", self.name));
+        let mut file = BufWriter::new(File::create(path.clone())?);
+
+        let (points, face_index, colors) = self.indexed_face_set(palette);
+
+        writeln!(file, "#VRML V2.0 utf8")?;
+        writeln!(file, "# {}", self.name)?;
+        writeln!(file, "Shape {{")?;
+        writeln!(file, "  appearance Appearance {{ material Material {{}} }}")?;
+        writeln!(file, "  geometry IndexedFaceSet {{")?;
+        // Our faces are clockwise.
+        writeln!(file, "    ccw FALSE")?;
+        writeln!(file, "    convex FALSE")?;
+        writeln!(file, "    coord Coordinate {{ point [ {} ] }}", points)?;
+        writeln!(file, "    coordIndex [ {} ]", face_index)?;
+        if let Some(colors) = colors {
+            writeln!(file, "    colorPerVertex FALSE")?;
+            writeln!(file, "    color Color {{ color [ {} ] }}", colors)?;
+        }
+        writeln!(file, "  }}")?;
+        writeln!(file, "}}")?;
+
+        file.flush()?;
+
+        Ok(path)
+    }
+}